@@ -1,8 +1,13 @@
 use asn1rs_model::ast;
 use proc_macro::TokenStream;
+use std::path::PathBuf;
+use syn::parse::Parser;
 use syn::parse_macro_input;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 use syn::DeriveInput;
 use syn::LitStr;
+use syn::Token;
 
 mod derive_protobuf_eq;
 
@@ -12,6 +17,29 @@ pub fn asn_to_rust(item: TokenStream) -> TokenStream {
     asn1rs_model::ast::asn_to_rust(&input).parse().unwrap()
 }
 
+/// Generates the Rust code for the ASN.1 modules in the given files, which are relative to
+/// `CARGO_MANIFEST_DIR`, and for the modules they import from the same directories:
+/// `asn_include!("asn/schema.asn1", "asn/other.asn1")`
+#[proc_macro]
+pub fn asn_include(item: TokenStream) -> TokenStream {
+    let files = match Punctuated::<LitStr, Token![,]>::parse_terminated.parse(item) {
+        Ok(files) => files,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+    let paths = files
+        .iter()
+        .map(|file| manifest_dir.join(file.value()))
+        .collect::<Vec<_>>();
+
+    match ast::asn_include(&paths) {
+        Ok(output) => output.parse().unwrap(),
+        Err(e) => syn::Error::new(files.span(), e.trim_start_matches("error: "))
+            .to_compile_error()
+            .into(),
+    }
+}
+
 #[proc_macro_attribute]
 pub fn asn(attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(ast::parse(attr.into(), item.into()))
@@ -23,6 +23,9 @@ pub(crate) struct AsnAttribute<C: Context> {
     pub(crate) consts: Vec<ConstLit>,
     pub(crate) extensible_after: Option<String>,
     pub(crate) default_value: Option<LiteralValue>,
+    /// Whether the Rust type is a user type (`mapped`) that is converted with the given
+    /// conversion (`mapped(Conversion)`), see [`crate::model::rust::TypeOverride`]
+    pub(crate) mapped: Option<Option<String>>,
     _c: PhantomData<C>,
 }
 
@@ -34,6 +37,7 @@ impl<C: Context> AsnAttribute<C> {
             consts: Vec::default(),
             extensible_after: None,
             default_value: None,
+            mapped: None,
             _c: Default::default(),
         }
     }
@@ -75,6 +79,16 @@ impl<C: Context> Parse for AsnAttribute<C> {
                         let _ = content.parse::<token::Comma>()?;
                     }
                 }
+                "mapped" if C::MAPPED && asn.mapped.is_none() => {
+                    if input.peek(token::Paren) {
+                        let content;
+                        parenthesized!(content in input);
+                        let conversion = content.parse::<syn::Type>()?;
+                        asn.mapped = Some(Some(quote::quote!(#conversion).to_string()));
+                    } else {
+                        asn.mapped = Some(None);
+                    }
+                }
                 attribute => {
                     return Err(
                         input.error(format!("Unexpected or repeated attribute: `{}`", attribute))
@@ -118,7 +132,8 @@ fn parse_type_pre_stepped<'a>(
             let charset = &string[..len - "string".chars().count()];
             let charset = Charset::from_str(charset)
                 .map_err(|_| input.error(format!("Unexpected charset '{}'", charset)))?;
-            parse_opt_size_or_any(input).map(|size| Type::String(size, charset))
+            parse_opt_size_and_alphabet(input)
+                .map(|(size, alphabet)| Type::String(size, charset, alphabet))
         }
         "integer" => {
             if input.is_empty() {
@@ -143,14 +158,21 @@ fn parse_type_pre_stepped<'a>(
         "complex" => {
             let content;
             parenthesized!(content in input);
-            let ident: syn::Ident = content.parse()?;
+            // a path refers to an imported type whose name collides with another type
+            let path: syn::Path = content.parse()?;
+            let name = path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("::");
             let _ = content.parse::<Token![,]>()?;
             let tag_ident: syn::Ident = content.parse()?;
             if !"tag".eq_ignore_ascii_case(&tag_ident.to_string()) {
                 return Err(input.error("Expected identifier 'tag'"));
             }
             let tag = AttrTag::parse(&content)?;
-            Ok(Type::TypeReference(ident.to_string(), Some(tag.0)))
+            Ok(Type::TypeReference(name, Some(tag.0)))
         }
         "option" | "optional" => {
             let content;
@@ -191,7 +213,9 @@ fn parse_type_pre_stepped<'a>(
                     })
                     .or_else(|| {
                         content.parse::<syn::Path>().ok().and_then(|path| {
-                            if path.segments.len() == 2 {
+                            if path.segments.len() == 1 {
+                                Some(LiteralValue::Reference(path.segments[0].ident.to_string()))
+                            } else if path.segments.len() == 2 {
                                 let mut iter = path.segments.iter();
                                 Some(LiteralValue::EnumeratedVariant(
                                     iter.next().unwrap().ident.to_string(),
@@ -266,6 +290,34 @@ fn parse_opt_size_or_any(input: ParseStream) -> syn::Result<Size> {
     }
 }
 
+/// Parses the optional size and permitted alphabet of a string, such as
+/// `(size(1..8), from("ABC"))`
+fn parse_opt_size_and_alphabet(input: ParseStream) -> syn::Result<(Size, Option<String>)> {
+    let mut size = Size::Any;
+    let mut alphabet = None;
+    if !input.is_empty() && input.peek(token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        while !content.is_empty() {
+            let ident = parse_ident(&content, "Expected size or from")?.to_lowercase();
+            let inner;
+            parenthesized!(inner in content);
+            match ident.as_str() {
+                "size" => size = Size::parse(&inner)?,
+                "from" => alphabet = Some(inner.parse::<syn::LitStr>()?.value()),
+                _ => {
+                    return Err(input.error(format!(
+                        "Invalid identifier, expected size or from but got: {}",
+                        ident
+                    )))
+                }
+            }
+            eof_or_comma(&content, "Constraints must be separated by comma")?;
+        }
+    }
+    Ok((size, alphabet))
+}
+
 fn eof_or_comma<T: Display>(input: &ParseBuffer, msg: T) -> syn::Result<()> {
     if !input.cursor().eof() && !input.peek(syn::token::Comma) {
         Err(input.error(msg))
@@ -323,6 +375,7 @@ pub trait Context: Debug {
     const EXTENSIBLE_AFTER: bool;
     const TAGGABLE: bool;
     const CONSTS: bool;
+    const MAPPED: bool;
 }
 
 impl Context for Choice {
@@ -330,6 +383,7 @@ impl Context for Choice {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const MAPPED: bool = false;
 }
 
 impl Context for ChoiceVariant {
@@ -337,6 +391,7 @@ impl Context for ChoiceVariant {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const MAPPED: bool = true;
 }
 
 impl Context for Enumerated {
@@ -344,6 +399,7 @@ impl Context for Enumerated {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const MAPPED: bool = false;
 }
 
 impl Context for EnumeratedVariant {
@@ -351,6 +407,7 @@ impl Context for EnumeratedVariant {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = false;
     const CONSTS: bool = false;
+    const MAPPED: bool = false;
 }
 
 #[derive(Debug)]
@@ -360,6 +417,7 @@ impl Context for Transparent {
     const EXTENSIBLE_AFTER: bool = false;
     const TAGGABLE: bool = true;
     const CONSTS: bool = true;
+    const MAPPED: bool = true;
 }
 
 #[derive(Debug)]
@@ -369,6 +427,7 @@ impl Context for DefinitionHeader {
     const EXTENSIBLE_AFTER: bool = true;
     const TAGGABLE: bool = true;
     const CONSTS: bool = false;
+    const MAPPED: bool = false;
 }
 
 impl Deref for DefinitionHeader {
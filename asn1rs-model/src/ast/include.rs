@@ -0,0 +1,153 @@
+use crate::gen::rust::RustCodeGenerator as RustGenerator;
+use crate::gen::Generator;
+use crate::model::lor::Unresolved;
+use crate::model::rust::rust_module_name;
+use crate::model::{Asn, Diagnostic, Model, MultiModuleResolver, SearchError, Source};
+use crate::parser::Tokenizer;
+use std::path::{Path, PathBuf};
+
+/// Generates the Rust code for the ASN.1 modules in the given files and for the modules they
+/// (transitively) import, which are searched in the directories of the given files, see
+/// [`MultiModuleResolver::push_imports_from`]. Each module is placed in its own Rust module, so
+/// that the generated `super::` imports between them are valid, and the items of the modules in
+/// the given files are re-exported. To let rustc track the files, so that changes trigger a
+/// recompilation, every loaded file is passed to `include_bytes!`.
+///
+/// On failure, the error is returned in a human readable form, see [`Diagnostic::render`].
+pub fn asn_include(files: &[PathBuf]) -> Result<String, String> {
+    let mut resolver = MultiModuleResolver::default();
+    let mut loaded = Vec::with_capacity(files.len());
+    let mut included = Vec::with_capacity(files.len());
+
+    for file in files {
+        let model = read_file(file)?;
+        included.push(module_name(&model.name));
+        resolver.push(model);
+        loaded.push(file.clone());
+    }
+
+    let mut directories = files
+        .iter()
+        .filter_map(|file| file.parent())
+        .collect::<Vec<_>>();
+    directories.sort();
+    directories.dedup();
+    let imported = resolver
+        .push_imports_from(&directories, &loaded)
+        .map_err(|e| {
+            let error = match e {
+            SearchError::Io(e) => format!("error: Failed to search for imported modules: {}", e),
+            SearchError::ModuleNotFound(import) => format!(
+                "error: No module in the directories of the included files satisfies the import of {} from {}",
+                import.what.join(", "),
+                import.from
+            ),
+            SearchError::ConflictingModules(import, files) => format!(
+                "error: More than one module satisfies the import from {}: {}",
+                import.from,
+                display_files(&files)
+            ),
+            SearchError::ModuleVersionMismatch(import, files) => format!(
+                "error: The OBJECT IDENTIFIER of the import from {} does not match the modules with its name: {}",
+                import.from,
+                display_files(&files)
+            ),
+            };
+            // an unrelated broken file does not matter, unless the search failed
+            resolver
+                .skipped_files()
+                .iter()
+                .fold(error, |error, (file, errors)| {
+                    format!(
+                        "{}\n  = note: skipped {}, it cannot be parsed: {}",
+                        error,
+                        file.to_string_lossy(),
+                        errors
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })
+        })?;
+    loaded.extend(imported);
+
+    let models = resolver.try_resolve_all().map_err(|e| {
+        let diagnostic = resolver.diagnostic(&e);
+        let file = e
+            .origin()
+            .and_then(Source::file)
+            .and_then(|name| loaded.iter().find(|file| file.to_string_lossy() == name));
+        match (file, diagnostic.help()) {
+            (Some(file), _) => diagnostic.render(
+                &file.to_string_lossy(),
+                &std::fs::read_to_string(file).unwrap_or_default(),
+            ),
+            (None, Some(help)) => format!("error: {}\n  = help: {}", diagnostic.message(), help),
+            (None, None) => format!("error: {}", diagnostic.message()),
+        }
+    })?;
+    let scope = models.iter().collect::<Vec<_>>();
+
+    let mut output = String::new();
+    for file in &loaded {
+        output.push_str(&format!(
+            "const _: &[u8] = include_bytes!({:?});\n",
+            file.to_string_lossy()
+        ));
+    }
+    for model in &models {
+        let content = RustGenerator::from(model.to_rust_with_scope(&scope[..]))
+            .to_string()
+            .map_err(|e| format!("error: {}", e))?
+            .into_iter()
+            .map(|(_file, content)| content)
+            .collect::<Vec<_>>()
+            .join("\n");
+        output.push_str(&format!(
+            "pub mod {} {{\n{}\n}}\n",
+            module_name(&model.name),
+            content
+        ));
+    }
+    for module in included {
+        output.push_str(&format!("pub use self::{}::*;\n", module));
+    }
+
+    if cfg!(feature = "debug-proc-macro") {
+        println!("-------- output start");
+        println!("{}", output);
+        println!("-------- output end");
+    }
+
+    Ok(output)
+}
+
+fn read_file(file: &Path) -> Result<Model<Asn<Unresolved>>, String> {
+    let input = std::fs::read_to_string(file)
+        .map_err(|e| format!("error: Failed to read {}: {}", file.to_string_lossy(), e))?;
+    let tokens = Tokenizer.parse_with_comments(&input);
+    let mut model = Model::try_from_recovering(tokens).map_err(|errors| {
+        errors
+            .iter()
+            .map(|e| Diagnostic::from(e).render(&file.to_string_lossy(), &input))
+            .collect::<Vec<_>>()
+            .join("\n")
+    })?;
+    model.set_source_file(&file.to_string_lossy());
+    Ok(model)
+}
+
+fn display_files(files: &[PathBuf]) -> String {
+    files
+        .iter()
+        .map(|file| file.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The name of the Rust module of the ASN.1 module, which is derived from the name of its Rust
+/// model, as are the paths of the generated imports that refer to it
+fn module_name(name: &str) -> String {
+    RustGenerator::rust_module_name(&rust_module_name(name, false))
+}
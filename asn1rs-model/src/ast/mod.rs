@@ -1,5 +1,6 @@
 mod attribute;
 mod constants;
+mod include;
 mod inline;
 mod range;
 mod size;
@@ -7,9 +8,16 @@ mod tag;
 
 use crate::ast::attribute::{Context, DefinitionHeader, Transparent};
 use crate::ast::constants::ConstLit;
+use crate::gen::asn::AsnDefGenerator;
+use crate::gen::RustCodeGenerator;
 use crate::model::lor::Resolved;
-use crate::model::{Choice, ChoiceVariant, Definition, Enumerated, Field, Model, Type};
-use crate::model::{ComponentTypeList, EnumeratedVariant, TagProperty, TagResolver};
+use crate::model::rust::TypeOverride;
+use crate::model::visit::{self, Visit, VisitMut};
+use crate::model::RustType;
+use crate::model::{Asn, Choice, ChoiceVariant, Definition, Enumerated, Field, Model, Type};
+use crate::model::{
+    ComponentTypeList, EnumeratedVariant, IgnoredInComparison, Tag, TagProperty, TagResolver,
+};
 use attribute::AsnAttribute;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -18,6 +26,7 @@ use std::str::FromStr;
 use syn::spanned::Spanned;
 use syn::{Attribute, Item};
 
+pub use include::asn_include;
 pub use inline::asn_to_rust;
 pub type AsnModelType = crate::model::Asn<crate::model::lor::Resolved>;
 
@@ -30,13 +39,15 @@ pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
         println!();
     }
 
-    let (definition, item) = match parse_asn_definition(attr, item) {
-        Ok(v) => v,
-        Err(e) => {
-            println!("Errör: {}", e);
-            return e;
-        }
-    };
+    let mut declared = DeclaredTypes::default();
+    let (definition, item) =
+        match parse_asn_definition_with_declared_types(attr, item, &mut declared) {
+            Ok(v) => v,
+            Err(e) => {
+                println!("Errör: {}", e);
+                return e;
+            }
+        };
 
     if cfg!(feature = "debug-proc-macro") {
         println!("---------- parsed definition begin ----------");
@@ -50,7 +61,7 @@ pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
         println!();
     }
 
-    let additional_impl = expand(definition);
+    let additional_impl = expand_with_declared_types(definition, &declared);
 
     let result = quote! {
         #item
@@ -67,6 +78,35 @@ pub fn parse(attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 pub fn expand(definition: Option<Definition<AsnModelType>>) -> Vec<TokenStream> {
+    expand_with_declared_types(definition, &DeclaredTypes::default())
+}
+
+/// The parts of the Rust types of fields, variants or the inner type (`"0"`) that are taken from
+/// their declared types instead of their `#[asn(..)]` attributes
+#[derive(Default)]
+struct DeclaredTypes {
+    /// The user types of `mapped` members, see [`RustCodeGenerator::add_field_type_override`]
+    overrides: Vec<(String, TypeOverride)>,
+    /// The declared types of members that are or contain fixed-size arrays, see
+    /// [`RustCodeGenerator::set_fixed_size_arrays`]
+    arrays: Vec<(String, syn::Type)>,
+}
+
+impl DeclaredTypes {
+    fn push(&mut self, name: String, ty: &syn::Type, type_override: Option<TypeOverride>) {
+        if let Some(type_override) = type_override {
+            self.overrides.push((name, type_override));
+        } else if contains_array(ty) {
+            self.arrays.push((name, ty.clone()));
+        }
+    }
+}
+
+/// Like [`expand`], but applies the [`DeclaredTypes`] to the Rust model
+fn expand_with_declared_types(
+    definition: Option<Definition<AsnModelType>>,
+    declared: &DeclaredTypes,
+) -> Vec<TokenStream> {
     let mut additional_impl: Vec<TokenStream> = Vec::default();
     let mut model: Model<AsnModelType> = Model {
         name: "__proc_macro".to_string(),
@@ -74,6 +114,7 @@ pub fn expand(definition: Option<Definition<AsnModelType>>) -> Vec<TokenStream>
     };
 
     if let Some(definition) = definition {
+        additional_impl.push(impl_asn_definition(&definition));
         model.definitions.push(definition);
         use crate::gen::rust::walker::AsnDefWriter;
 
@@ -83,17 +124,168 @@ pub fn expand(definition: Option<Definition<AsnModelType>>) -> Vec<TokenStream>
             println!("---------- parsed definition to rust end ----------");
             println!();
         }
-        additional_impl.push(
-            TokenStream::from_str(&AsnDefWriter::stringify(&model.to_rust_keep_names())).unwrap(),
-        );
+        let mut rust = model.to_rust_keep_names();
+        for (field, type_override) in &declared.overrides {
+            RustCodeGenerator::override_field_type(
+                &mut rust,
+                &model.definitions[0].0,
+                field,
+                type_override,
+            );
+        }
+        for (field, ty) in &declared.arrays {
+            RustCodeGenerator::map_field_type(&mut rust, &model.definitions[0].0, field, |r| {
+                into_declared_arrays(r, ty)
+            });
+        }
+        additional_impl.push(TokenStream::from_str(&AsnDefWriter::stringify(&rust)).unwrap());
     }
 
     additional_impl
 }
 
+/// Implements `AsnDefinition` with the ASN.1 notation of the definition, see [`AsnDefGenerator`]
+fn impl_asn_definition(definition: &Definition<AsnModelType>) -> TokenStream {
+    let mut asn_definition = definition.clone();
+    AsnNames.visit_definition_mut(&mut asn_definition);
+
+    let mut notation = String::new();
+    AsnDefGenerator::<Resolved>::append_definition(&mut notation, &asn_definition)
+        .expect("Writing to a String cannot fail");
+    let notation = notation.trim_end();
+
+    let mut references = TypeReferences::default();
+    references.visit_definition(definition);
+    let references = references.0;
+
+    let name = &definition.0;
+    let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+    quote! {
+        impl ::asn1rs::syn::definition::AsnDefinition for #ident {
+            const ASN_NAME: &'static str = #name;
+            const ASN_DEFINITION: &'static str = #notation;
+
+            fn add_referenced_definitions(module: &mut ::asn1rs::syn::definition::AsnModule) {
+                #(module.add::<#references>();)*
+            }
+        }
+    }
+}
+
+/// Collects the Rust types of the type references of a parsed definition, without the `Box` of
+/// recursive references (`Box<crate::Foo>` becomes `crate::Foo`)
+#[derive(Default)]
+struct TypeReferences(Vec<syn::Type>);
+
+impl TypeReferences {
+    fn unboxed(ty: syn::Type) -> syn::Type {
+        if let syn::Type::Path(path) = &ty {
+            if let Some(segment) = path.path.segments.last() {
+                if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                    if let (true, Some(syn::GenericArgument::Type(inner))) =
+                        (segment.ident == "Box", arguments.args.first())
+                    {
+                        return inner.clone();
+                    }
+                }
+            }
+        }
+        ty
+    }
+}
+
+impl Visit for TypeReferences {
+    fn visit_type(&mut self, r#type: &Type) {
+        if let Type::TypeReference(name, _) = r#type {
+            if let Ok(ty) = syn::parse_str::<syn::Type>(name) {
+                let ty = Self::unboxed(ty);
+                if !self.0.contains(&ty) {
+                    self.0.push(ty);
+                }
+            }
+        }
+        visit::visit_type(self, r#type)
+    }
+}
+
+/// Replaces the Rust names of a parsed definition by valid ASN.1 names: type references by the
+/// name of the referenced type (`Box<crate::Foo>` becomes `Foo`) and field and variant names by
+/// identifiers starting with a lowercase letter and using single hyphens (`some_field` and
+/// `some__field` become `some-field` and `SomeVariant` becomes `someVariant`). Tags of the `UNIVERSAL` class, which
+/// are only known to the attribute for encoding but cannot be written in a module, are removed.
+struct AsnNames;
+
+impl AsnNames {
+    fn remove_universal_tag(tag: &mut Option<Tag>) {
+        if let Some(Tag::Universal(_)) = tag {
+            *tag = None;
+        }
+    }
+
+    fn type_reference(name: &str) -> String {
+        name.split(|c: char| !c.is_alphanumeric() && c != '_')
+            .rfind(|segment| !segment.is_empty() && *segment != "Box")
+            .unwrap_or(name)
+            .to_string()
+    }
+
+    fn identifier(name: &str) -> String {
+        let name = name
+            .trim_start_matches("r#")
+            .split('_')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        let mut chars = name.chars();
+        chars
+            .next()
+            .map(|c| c.to_lowercase().collect::<String>())
+            .unwrap_or_default()
+            + chars.as_str()
+    }
+}
+
+impl VisitMut for AsnNames {
+    fn visit_asn_mut(&mut self, asn: &mut Asn) {
+        Self::remove_universal_tag(&mut asn.tag);
+        visit::visit_asn_mut(self, asn)
+    }
+
+    fn visit_type_mut(&mut self, r#type: &mut Type) {
+        if let Type::TypeReference(name, _) = r#type {
+            *name = Self::type_reference(name);
+        }
+        visit::visit_type_mut(self, r#type)
+    }
+
+    fn visit_field_mut(&mut self, field: &mut Field<Asn>) {
+        field.name = Self::identifier(&field.name);
+        visit::visit_field_mut(self, field)
+    }
+
+    fn visit_choice_variant_mut(&mut self, variant: &mut ChoiceVariant) {
+        variant.name = Self::identifier(&variant.name);
+        Self::remove_universal_tag(&mut variant.tag);
+        visit::visit_choice_variant_mut(self, variant)
+    }
+
+    fn visit_enumerated_variant_mut(&mut self, variant: &mut EnumeratedVariant) {
+        variant.name = Self::identifier(&variant.name);
+    }
+}
+
 pub fn parse_asn_definition(
     attr: TokenStream,
     item: TokenStream,
+) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
+    parse_asn_definition_with_declared_types(attr, item, &mut DeclaredTypes::default())
+}
+
+/// Like [`parse_asn_definition`], but also collects the [`DeclaredTypes`]
+fn parse_asn_definition_with_declared_types(
+    attr: TokenStream,
+    item: TokenStream,
+    declared: &mut DeclaredTypes,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
     let item_span = item.span();
     let attr_span = attr.span();
@@ -119,19 +311,19 @@ pub fn parse_asn_definition(
 
     match item {
         Item::Struct(strct) if asn.primary.eq_ignore_ascii_case("sequence") => {
-            parse_sequence_or_set(strct, &asn, attr_span, Type::Sequence)
+            parse_sequence_or_set(strct, &asn, attr_span, Type::Sequence, declared)
         }
         Item::Struct(strct) if asn.primary.eq_ignore_ascii_case("set") => {
-            parse_sequence_or_set(strct, &asn, attr_span, Type::Set)
+            parse_sequence_or_set(strct, &asn, attr_span, Type::Set, declared)
         }
         Item::Struct(strct) if asn.primary.eq_ignore_ascii_case("transparent") => {
-            parse_transparent(strct, &asn, attr_span)
+            parse_transparent(strct, &asn, attr_span, declared)
         }
         Item::Enum(enm) if asn.primary.eq_ignore_ascii_case("enumerated") => {
             parse_enumerated(enm, &asn, attr_span)
         }
         Item::Enum(enm) if asn.primary.eq_ignore_ascii_case("choice") => {
-            parse_choice(enm, &asn, attr_span)
+            parse_choice(enm, &asn, attr_span, declared)
         }
         item => Ok((None, item)),
     }
@@ -142,6 +334,7 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
     asn: &AsnAttribute<DefinitionHeader>,
     asn_span: proc_macro2::Span,
     mapper: F,
+    declared: &mut DeclaredTypes,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
     let fields = strct
         .fields
@@ -154,14 +347,15 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
                 )?;
             }
 
+            let name = field.ident.as_ref().unwrap().to_string();
             parse_and_remove_first_asn_attribute_type::<Transparent>(
                 field.span(),
                 &field.ty,
                 &mut field.attrs,
             )
-            .map(|asn| Field {
-                name: field.ident.as_ref().unwrap().to_string(),
-                role: asn,
+            .map(|(asn, type_override)| {
+                declared.push(name.clone(), &field.ty, type_override);
+                Field { name, role: asn }
             })
         })
         .vec_result()?;
@@ -176,6 +370,7 @@ fn parse_sequence_or_set<F: Fn(ComponentTypeList<Resolved>) -> Type>(
                     fields.iter().map(|v| &v.name),
                 )?,
                 fields,
+                components_of: Vec::default(),
             })
             .opt_tagged(asn.tag),
         )),
@@ -187,6 +382,7 @@ fn parse_transparent(
     mut strct: syn::ItemStruct,
     asn: &AsnAttribute<DefinitionHeader>,
     _asn_span: proc_macro2::Span,
+    declared: &mut DeclaredTypes,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
     if strct.fields.len() != 1 || strct.fields.iter().next().unwrap().ident.is_some() {
         compile_err_ts(
@@ -196,20 +392,19 @@ fn parse_transparent(
     }
 
     let field = strct.fields.iter_mut().next().unwrap();
-    parse_and_remove_first_asn_attribute_type::<Transparent>(
+    let (parsed, type_override) = parse_and_remove_first_asn_attribute_type::<Transparent>(
         field.span(),
         &field.ty,
         &mut field.attrs,
-    )
-    .map(|parsed| {
-        (
-            Some(Definition(
-                strct.ident.to_string(),
-                parsed.with_tag_opt(asn.tag),
-            )),
-            Item::Struct(strct),
-        )
-    })
+    )?;
+    declared.push("0".to_string(), &field.ty, type_override);
+    Ok((
+        Some(Definition(
+            strct.ident.to_string(),
+            parsed.with_tag_opt(asn.tag),
+        )),
+        Item::Struct(strct),
+    ))
 }
 
 fn parse_enumerated(
@@ -267,6 +462,7 @@ fn parse_choice(
     mut enm: syn::ItemEnum,
     asn: &AsnAttribute<DefinitionHeader>,
     asn_span: proc_macro2::Span,
+    declared: &mut DeclaredTypes,
 ) -> Result<(Option<Definition<AsnModelType>>, Item), TokenStream> {
     enm.variants
         .iter()
@@ -290,20 +486,20 @@ fn parse_choice(
                 )?;
             }
 
-            parse_and_remove_first_asn_attribute_type::<ChoiceVariant>(
-                v.span(),
-                &v.fields.iter().next().unwrap().ty,
-                &mut v.attrs,
-            )
-            .map(|asn| {
-                // TODO extensible
-                // TODO tags
-                ChoiceVariant {
-                    name: v.ident.to_string(),
-                    tag: asn.tag,
-                    r#type: asn.r#type,
-                }
-            })
+            let ty = &v.fields.iter().next().unwrap().ty;
+            parse_and_remove_first_asn_attribute_type::<ChoiceVariant>(v.span(), ty, &mut v.attrs)
+                .map(|(asn, type_override)| {
+                    declared.push(v.ident.to_string(), ty, type_override);
+                    // TODO extensible
+                    // TODO tags
+                    ChoiceVariant {
+                        name: v.ident.to_string(),
+                        tag: asn.tag,
+                        r#type: asn.r#type,
+                        comment: None,
+                        source: IgnoredInComparison::default(),
+                    }
+                })
         })
         .vec_result()?;
 
@@ -349,8 +545,18 @@ fn parse_and_remove_first_asn_attribute_type<C: Context<Primary = Type>>(
     span: proc_macro2::Span,
     ty: &syn::Type,
     attrs: &mut Vec<Attribute>,
-) -> Result<AsnModelType, TokenStream> {
-    parse_and_remove_first_asn_attribute::<C>(span, attrs).map(|asn| into_asn(ty, asn))
+) -> Result<(AsnModelType, Option<TypeOverride>), TokenStream> {
+    parse_and_remove_first_asn_attribute::<C>(span, attrs).map(|mut asn| {
+        let type_override = asn.mapped.take().map(|conversion| {
+            let ty = overridden_type(ty);
+            let type_override = TypeOverride::new(quote! { #ty }.to_string());
+            match conversion {
+                Some(conversion) => type_override.with_conversion(conversion),
+                None => type_override,
+            }
+        });
+        (into_asn(ty, asn, type_override.is_some()), type_override)
+    })
 }
 
 fn parse_and_remove_first_asn_attribute<C: Context>(
@@ -366,11 +572,93 @@ fn parse_and_remove_first_asn_attribute<C: Context>(
     })
 }
 
-fn into_asn<C: Context<Primary = Type>>(ty: &syn::Type, mut asn: AsnAttribute<C>) -> AsnModelType {
+/// The generic argument of `Option<T>`, if the given type is such
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_inner_type(ty, "Option")
+}
+
+/// The user type of a `mapped` field, which is the type of the elements for `Option<T>` and
+/// `Vec<T>`, see [`crate::model::RustType::override_with`]
+fn overridden_type(ty: &syn::Type) -> &syn::Type {
+    generic_inner_type(ty, "Option")
+        .or_else(|| generic_inner_type(ty, "Vec"))
+        .map(overridden_type)
+        .unwrap_or(ty)
+}
+
+/// Whether the type is an array or an `Option` or `Vec` of one
+fn contains_array(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Array(_) => true,
+        ty => generic_inner_type(ty, "Option")
+            .or_else(|| generic_inner_type(ty, "Vec"))
+            .map(contains_array)
+            .unwrap_or(false),
+    }
+}
+
+/// Replaces the `Vec`s of the Rust type by fixed-size arrays where the declared type is an array
+fn into_declared_arrays(r#type: &mut RustType, ty: &syn::Type) {
+    match (r#type, ty) {
+        (r#type, syn::Type::Array(array)) => {
+            let current = std::mem::replace(r#type, RustType::Null);
+            *r#type = current.into_fixed_size_array();
+            if let RustType::Array(inner, _) = r#type {
+                into_declared_arrays(inner, &array.elem);
+            }
+        }
+        (RustType::Option(inner), ty) => {
+            if let Some(ty) = generic_inner_type(ty, "Option") {
+                into_declared_arrays(inner, ty);
+            }
+        }
+        (RustType::Vec(inner, ..), ty) => {
+            if let Some(ty) = generic_inner_type(ty, "Vec") {
+                into_declared_arrays(inner, ty);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The generic argument of the given type with a single generic argument, such as `Option<T>`
+fn generic_inner_type<'a>(ty: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
+    if let syn::Type::Path(path) = ty {
+        let segment = path.path.segments.last()?;
+        if segment.ident == name {
+            if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                if let Some(syn::GenericArgument::Type(inner)) = arguments.args.first() {
+                    return Some(inner);
+                }
+            }
+        }
+    }
+    None
+}
+
+fn into_asn<C: Context<Primary = Type>>(
+    ty: &syn::Type,
+    mut asn: AsnAttribute<C>,
+    mapped: bool,
+) -> AsnModelType {
+    // the actual type might differ from the referenced definition, e.g. `Box<T>`, unless it is a
+    // user type that the referenced definition is converted into
+    let actual = |ty: &syn::Type| {
+        if mapped {
+            None
+        } else {
+            Some(quote! { #ty }.to_string())
+        }
+    };
+    if let (Type::Optional(inner), Some(ty)) = (&mut asn.primary, option_inner_type(ty)) {
+        if let (Type::TypeReference(name, _), Some(actual)) = (inner.as_mut(), actual(ty)) {
+            *name = actual;
+        }
+    }
     AsnModelType {
         tag: asn.tag,
-        r#type: if let Type::TypeReference(_, empty_tag) = asn.primary {
-            Type::TypeReference(quote! { #ty }.to_string(), empty_tag.or(asn.tag))
+        r#type: if let Type::TypeReference(name, empty_tag) = asn.primary {
+            Type::TypeReference(actual(ty).unwrap_or(name), empty_tag.or(asn.tag))
         } else {
             if let Type::Integer(int) = asn.primary.no_optional_mut() {
                 asn.consts
@@ -383,6 +671,8 @@ fn into_asn<C: Context<Primary = Type>>(ty: &syn::Type, mut asn: AsnAttribute<C>
             asn.primary
         },
         default: asn.default_value,
+        comment: None,
+        source: IgnoredInComparison::default(),
     }
 }
 
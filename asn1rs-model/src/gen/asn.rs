@@ -0,0 +1,558 @@
+use crate::gen::Generator;
+use crate::model::lor::{ResolveState, Unresolved};
+use crate::model::{
+    Asn, ComponentTypeList, Definition, Exports, Model, Range, Size, Tag, Type, ValueReference,
+};
+use std::fmt::Error as FmtError;
+use std::fmt::{Display, Write};
+
+const INDENT: &str = "    ";
+
+#[derive(Debug)]
+pub enum Error {
+    Fmt(FmtError),
+}
+
+impl From<FmtError> for Error {
+    fn from(e: FmtError) -> Self {
+        Error::Fmt(e)
+    }
+}
+
+/// Prints ASN.1 modules in a canonical form: one member per line, indented by four spaces per
+/// nesting level, with the comments of definitions, fields and variants above them. Parsing the
+/// printed module results in a model equal to the printed one. Value references are printed
+/// after all type definitions.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct AsnDefGenerator<RS: ResolveState = Unresolved> {
+    models: Vec<Model<Asn<RS>>>,
+}
+
+impl<RS: ResolveState> Default for AsnDefGenerator<RS> {
+    fn default() -> Self {
+        Self { models: Vec::new() }
+    }
+}
+
+impl<RS: ResolveState> Generator<Asn<RS>> for AsnDefGenerator<RS> {
+    type Error = Error;
+
+    fn add_model(&mut self, model: Model<Asn<RS>>) {
+        self.models.push(model);
+    }
+
+    fn models(&self) -> &[Model<Asn<RS>>] {
+        &self.models[..]
+    }
+
+    fn models_mut(&mut self) -> &mut [Model<Asn<RS>>] {
+        &mut self.models[..]
+    }
+
+    fn to_string(&self) -> Result<Vec<(String, String)>, <Self as Generator<Asn<RS>>>::Error> {
+        let mut files = Vec::new();
+        for model in &self.models {
+            files.push(Self::generate_file(model)?);
+        }
+        Ok(files)
+    }
+}
+
+impl<RS: ResolveState> AsnDefGenerator<RS> {
+    pub fn generate_file(model: &Model<Asn<RS>>) -> Result<(String, String), Error> {
+        let file_name = format!("{}.asn1", model.name);
+        let mut content = String::new();
+        Self::append_header(&mut content, model)?;
+        Self::append_exports(&mut content, &model.exports)?;
+        Self::append_imports(&mut content, model)?;
+        for definition in &model.definitions {
+            writeln!(content)?;
+            Self::append_definition(&mut content, definition)?;
+        }
+        if !model.value_references.is_empty() {
+            writeln!(content)?;
+            for value_reference in &model.value_references {
+                Self::append_value_reference(&mut content, value_reference)?;
+            }
+        }
+        writeln!(content)?;
+        writeln!(content, "END")?;
+        Ok((file_name, content))
+    }
+
+    pub fn append_header(target: &mut dyn Write, model: &Model<Asn<RS>>) -> Result<(), Error> {
+        write!(target, "{}", model.name)?;
+        if let Some(oid) = &model.oid {
+            write!(target, " {}", oid)?;
+        }
+        write!(target, " DEFINITIONS {} TAGS", model.tag_default.keyword())?;
+        if model.extensibility_implied {
+            write!(target, " EXTENSIBILITY IMPLIED")?;
+        }
+        writeln!(target, " ::=")?;
+        writeln!(target, "BEGIN")?;
+        Ok(())
+    }
+
+    pub fn append_exports(target: &mut dyn Write, exports: &Exports) -> Result<(), Error> {
+        if let Exports::Symbols(symbols) = exports {
+            writeln!(target)?;
+            writeln!(target, "EXPORTS {};", symbols.join(", "))?;
+        }
+        Ok(())
+    }
+
+    pub fn append_imports(target: &mut dyn Write, model: &Model<Asn<RS>>) -> Result<(), Error> {
+        if model.imports.is_empty() {
+            return Ok(());
+        }
+        writeln!(target)?;
+        writeln!(target, "IMPORTS")?;
+        for (index, import) in model.imports.iter().enumerate() {
+            write!(
+                target,
+                "{}{} FROM {}",
+                INDENT,
+                import.what.join(", "),
+                import.from
+            )?;
+            if let Some(oid) = &import.from_oid {
+                write!(target, " {}", oid)?;
+            }
+            if index + 1 == model.imports.len() {
+                writeln!(target, ";")?;
+            } else {
+                writeln!(target)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn append_definition(
+        target: &mut dyn Write,
+        Definition(name, asn): &Definition<Asn<RS>>,
+    ) -> Result<(), Error> {
+        Self::append_comment(target, 0, asn.comment.as_deref())?;
+        write!(target, "{} ::= ", name)?;
+        Self::append_tag(target, asn.tag)?;
+        Self::append_type(target, 0, &asn.r#type)?;
+        writeln!(target)?;
+        Ok(())
+    }
+
+    pub fn append_value_reference(
+        target: &mut dyn Write,
+        value_reference: &ValueReference<Asn<RS>>,
+    ) -> Result<(), Error> {
+        write!(target, "{} ", value_reference.name)?;
+        Self::append_type(target, 0, &value_reference.role.r#type)?;
+        writeln!(target, " ::= {}", value_reference.value)?;
+        Ok(())
+    }
+
+    /// Writes each line of the comment as a separate `--` comment
+    fn append_comment(
+        target: &mut dyn Write,
+        level: usize,
+        comment: Option<&str>,
+    ) -> Result<(), Error> {
+        for line in comment.iter().flat_map(|comment| comment.lines()) {
+            if line.is_empty() {
+                writeln!(target, "{}--", INDENT.repeat(level))?;
+            } else {
+                writeln!(target, "{}-- {}", INDENT.repeat(level), line)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn append_tag(target: &mut dyn Write, tag: Option<Tag>) -> Result<(), Error> {
+        if let Some(tag) = tag {
+            write!(target, "{} ", tag)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the type, whose nested members are indented by one level more than the given one
+    fn append_type(target: &mut dyn Write, level: usize, r#type: &Type<RS>) -> Result<(), Error> {
+        match r#type {
+            Type::Boolean => write!(target, "BOOLEAN")?,
+            Type::Integer(integer) => {
+                write!(target, "INTEGER")?;
+                Self::append_constants(target, &integer.constants)?;
+                if let Some(range) = range_constraint(&integer.range) {
+                    write!(target, " ({})", range)?;
+                }
+            }
+            Type::String(size, charset, alphabet) => {
+                write!(target, "{}", charset.asn_name())?;
+                Self::append_size(target, size)?;
+                if let Some(alphabet) = alphabet {
+                    write!(target, " ({})", permitted_alphabet_constraint(alphabet))?;
+                }
+            }
+            Type::OctetString(size) => {
+                write!(target, "OCTET STRING")?;
+                Self::append_size(target, size)?;
+            }
+            Type::BitString(bit_string) => {
+                write!(target, "BIT STRING")?;
+                Self::append_constants(target, &bit_string.constants)?;
+                Self::append_size(target, &bit_string.size)?;
+            }
+            Type::Null => write!(target, "NULL")?,
+            Type::Optional(inner) => {
+                Self::append_type(target, level, inner)?;
+                write!(target, " OPTIONAL")?;
+            }
+            Type::Default(inner, value) => {
+                Self::append_type(target, level, inner)?;
+                write!(target, " DEFAULT {}", value)?;
+            }
+            Type::Sequence(list) => {
+                write!(target, "SEQUENCE ")?;
+                Self::append_components(target, level, list)?;
+            }
+            Type::SequenceOf(inner, size) => {
+                write!(target, "SEQUENCE")?;
+                Self::append_size(target, size)?;
+                write!(target, " OF ")?;
+                Self::append_type(target, level, inner)?;
+            }
+            Type::Set(list) => {
+                write!(target, "SET ")?;
+                Self::append_components(target, level, list)?;
+            }
+            Type::SetOf(inner, size) => {
+                write!(target, "SET")?;
+                Self::append_size(target, size)?;
+                write!(target, " OF ")?;
+                Self::append_type(target, level, inner)?;
+            }
+            Type::Enumerated(enumerated) => {
+                let mut members = enumerated
+                    .variants()
+                    .map(|variant| {
+                        let member = match variant.number() {
+                            Some(number) => format!("{}({})", variant.name(), number),
+                            None => variant.name().to_string(),
+                        };
+                        (variant.comment(), member)
+                    })
+                    .collect::<Vec<_>>();
+                insert_extension_marker(&mut members, enumerated.extension_after_index());
+                write!(target, "ENUMERATED ")?;
+                Self::append_members(target, level, members)?;
+            }
+            Type::Choice(choice) => {
+                let mut members = Vec::with_capacity(choice.len() + 1);
+                for variant in choice.variants() {
+                    let mut member = format!("{} ", variant.name);
+                    Self::append_tag(&mut member, variant.tag)?;
+                    Self::append_type(&mut member, level + 1, &variant.r#type)?;
+                    members.push((variant.comment.as_deref(), member));
+                }
+                insert_extension_marker(&mut members, choice.extension_after_index());
+                write!(target, "CHOICE ")?;
+                Self::append_members(target, level, members)?;
+            }
+            Type::ObjectIdentifier => write!(target, "OBJECT IDENTIFIER")?,
+            Type::TypeReference(name, _) => write!(target, "{}", name)?,
+            Type::ConstrainedTypeReference(name, constraint) => {
+                write!(target, "{} {}", name, constraint)?
+            }
+        }
+        Ok(())
+    }
+
+    fn append_components(
+        target: &mut dyn Write,
+        level: usize,
+        list: &ComponentTypeList<RS>,
+    ) -> Result<(), Error> {
+        let mut members = Vec::with_capacity(list.fields.len() + 1);
+        for (index, field) in list.fields.iter().enumerate() {
+            if list.components_of.contains(&index) {
+                members.push((None, format!("COMPONENTS OF {}", field.name)));
+            } else {
+                let mut member = format!("{} ", field.name);
+                Self::append_tag(&mut member, field.role.tag)?;
+                Self::append_type(&mut member, level + 1, &field.role.r#type)?;
+                if let Some(default) = &field.role.default {
+                    write!(member, " DEFAULT {}", default)?;
+                }
+                members.push((field.role.comment.as_deref(), member));
+            }
+        }
+        insert_extension_marker(&mut members, list.extension_after);
+        Self::append_members(target, level, members)
+    }
+
+    /// Writes the members in braces, each on its own line and preceded by its comment
+    fn append_members(
+        target: &mut dyn Write,
+        level: usize,
+        members: Vec<(Option<&str>, String)>,
+    ) -> Result<(), Error> {
+        if members.is_empty() {
+            write!(target, "{{}}")?;
+            return Ok(());
+        }
+        writeln!(target, "{{")?;
+        let len = members.len();
+        for (index, (comment, member)) in members.into_iter().enumerate() {
+            Self::append_comment(target, level + 1, comment)?;
+            write!(target, "{}{}", INDENT.repeat(level + 1), member)?;
+            if index + 1 < len {
+                write!(target, ",")?;
+            }
+            writeln!(target)?;
+        }
+        write!(target, "{}}}", INDENT.repeat(level))?;
+        Ok(())
+    }
+
+    fn append_constants<T: Display>(
+        target: &mut dyn Write,
+        constants: &[(String, T)],
+    ) -> Result<(), Error> {
+        if !constants.is_empty() {
+            let constants = constants
+                .iter()
+                .map(|(name, value)| format!("{}({})", name, value))
+                .collect::<Vec<_>>();
+            write!(target, " {{ {} }}", constants.join(", "))?;
+        }
+        Ok(())
+    }
+
+    fn append_size(target: &mut dyn Write, size: &Size<RS::SizeType>) -> Result<(), Error> {
+        if let Some(size) = size_constraint(size) {
+            write!(target, " ({})", size)?;
+        }
+        Ok(())
+    }
+}
+
+fn insert_extension_marker(
+    members: &mut Vec<(Option<&str>, String)>,
+    extension_after: Option<usize>,
+) {
+    if let Some(index) = extension_after {
+        let index = (index + 1).min(members.len());
+        members.insert(index, (None, "...".to_string()));
+    }
+}
+
+/// The upper bound of a size without a maximum, see [`Size`]
+fn size_bound<T: Display>(bound: &T) -> String {
+    let bound = bound.to_string();
+    if bound == i64::MAX.to_string() {
+        "MAX".to_string()
+    } else {
+        bound
+    }
+}
+
+pub(crate) fn size_constraint<T: Display + std::fmt::Debug + Clone>(
+    size: &Size<T>,
+) -> Option<String> {
+    let extensible = if size.extensible() { ", ..." } else { "" };
+    match size {
+        Size::Any => None,
+        Size::Fix(len, _) => Some(format!("SIZE({}{})", len, extensible)),
+        Size::Range(min, max, _) => {
+            Some(format!("SIZE({}..{}{})", min, size_bound(max), extensible))
+        }
+    }
+}
+
+pub(crate) fn permitted_alphabet_constraint(alphabet: &str) -> String {
+    format!("FROM(\"{}\")", alphabet.replace('"', "\"\""))
+}
+
+pub(crate) fn range_constraint<T: Display>(range: &Range<Option<T>>) -> Option<String> {
+    match range {
+        Range(None, None, false) => None,
+        Range(min, max, extensible) => Some(format!(
+            "{}..{}{}",
+            min.as_ref()
+                .map_or_else(|| "MIN".to_string(), ToString::to_string),
+            max.as_ref()
+                .map_or_else(|| "MAX".to_string(), ToString::to_string),
+            if *extensible { ", ..." } else { "" }
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{LiteralValue, TagDefault};
+    use crate::parser::Tokenizer;
+
+    const CANONICAL: &str = r#"Schema { iso(1) identified-organization(3) 42 } DEFINITIONS AUTOMATIC TAGS ::=
+BEGIN
+
+EXPORTS Position, Speed;
+
+IMPORTS
+    Other, Another FROM Others { 1 2 3 }
+    Third FROM Thirds;
+
+-- The position of a vehicle
+Position ::= [APPLICATION 7] SEQUENCE {
+    -- Latitude in 1/10 micro degrees
+    lat INTEGER (-900000000..900000001),
+    lon INTEGER (MIN..1800000001, ...),
+    name UTF8String (SIZE(1..max-name-length)) OPTIONAL,
+    mode Mode DEFAULT automatic,
+    heading [5] INTEGER { north(0), south(180) } (0..359) DEFAULT 0,
+    ...,
+    flags BIT STRING { first(0), second(1) } (SIZE(8)),
+    nested SEQUENCE OF SEQUENCE {
+        id OCTET STRING (SIZE(4, ...)),
+        payload CHOICE {
+            text IA5String,
+            -- absent
+            nothing [3] NULL,
+            ...
+        }
+    }
+}
+
+Mode ::= ENUMERATED {
+    automatic,
+    manual(5),
+    ...,
+    -- added later
+    remote
+}
+
+Speed ::= SET (SIZE(1..MAX)) OF INTEGER (0..65535)
+
+Limited ::= Other (SIZE(2))
+
+Code ::= IA5String (SIZE(2)) (FROM("""AB"))
+
+Upper ::= Other (SIZE(1..8))(FROM("AZ"))
+
+Options ::= SEQUENCE {
+    COMPONENTS OF Base,
+    flag BOOLEAN DEFAULT TRUE,
+    origin Position DEFAULT { lat 0, lon -1, name "zero", mode manual }
+}
+
+Empty ::= SEQUENCE {}
+
+max-name-length INTEGER ::= 32
+magic OCTET STRING ::= '0AFF'H
+root OBJECT IDENTIFIER ::= { iso(1) 3 }
+
+END
+"#;
+
+    fn parse(asn: &str) -> Model<Asn<Unresolved>> {
+        Model::try_from(Tokenizer::default().parse_with_comments(asn)).unwrap()
+    }
+
+    fn print(model: Model<Asn<Unresolved>>) -> String {
+        let mut generator = AsnDefGenerator::default();
+        generator.add_model(model);
+        let mut files = generator.to_string().unwrap();
+        assert_eq!(1, files.len());
+        files.remove(0).1
+    }
+
+    #[test]
+    fn test_canonical_module_is_printed_unchanged() {
+        assert_eq!(CANONICAL, print(parse(CANONICAL)));
+    }
+
+    #[test]
+    fn test_round_trip_yields_equal_model() {
+        let model = parse(CANONICAL);
+        let printed = print(model.clone());
+        assert_eq!(model, parse(&printed));
+    }
+
+    #[test]
+    fn test_module_header_and_escaped_strings_are_kept() {
+        const MODULE: &str = r#"Header DEFINITIONS IMPLICIT TAGS EXTENSIBILITY IMPLIED ::=
+BEGIN
+
+Limited ::= Other (SIZE(2))(1..5)
+
+greeting UTF8String ::= "say ""hi"""
+
+END
+"#;
+        let model = parse(MODULE);
+        assert_eq!(TagDefault::Implicit, model.tag_default);
+        assert!(model.extensibility_implied);
+        assert_eq!(
+            LiteralValue::String("say \"hi\"".to_string()),
+            model.value_references[0].value
+        );
+        assert_eq!(MODULE, print(model));
+    }
+
+    #[test]
+    fn test_missing_tag_default_is_explicit() {
+        let printed = print(parse("NoTags DEFINITIONS ::= BEGIN END"));
+        assert!(
+            printed.starts_with("NoTags DEFINITIONS EXPLICIT TAGS ::=\n"),
+            "{}",
+            printed
+        );
+    }
+
+    #[test]
+    fn test_messy_module_is_normalized() {
+        let model = parse(
+            r"Messy DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Simple::=SEQUENCE{a INTEGER(0..10),b SEQUENCE(SIZE(1..2))OF UTF8String(SIZE(3)),
+            c ENUMERATED{x,y,...}OPTIONAL}
+            END",
+        );
+        let printed = print(model.clone());
+        assert_eq!(
+            r"Messy DEFINITIONS AUTOMATIC TAGS ::=
+BEGIN
+
+Simple ::= SEQUENCE {
+    a INTEGER (0..10),
+    b SEQUENCE (SIZE(1..2)) OF UTF8String (SIZE(3)),
+    c ENUMERATED {
+        x,
+        y,
+        ...
+    } OPTIONAL
+}
+
+END
+",
+            printed
+        );
+        assert_eq!(model, parse(&printed));
+    }
+
+    #[test]
+    fn test_resolved_model_is_printed_with_resolved_values() {
+        let model = parse(
+            r"Resolved DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Name ::= UTF8String (SIZE(1..max-len))
+            max-len INTEGER ::= 8
+            END",
+        )
+        .try_resolve()
+        .unwrap();
+        let mut generator = AsnDefGenerator::default();
+        generator.add_model(model);
+        let (file, content) = generator.to_string().unwrap().remove(0);
+        assert_eq!("Resolved.asn1", file);
+        assert!(content.contains("Name ::= UTF8String (SIZE(1..8))\n"));
+        assert!(content.contains("max-len INTEGER ::= 8\n"));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod asn;
 pub mod protobuf;
 pub mod rust;
 pub mod sql;
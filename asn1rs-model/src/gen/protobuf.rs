@@ -1,5 +1,6 @@
 use crate::gen::Generator;
-use crate::model::rust::rust_module_name;
+use crate::model::rust::{rust_module_name, unqualified_type_name};
+use crate::model::Comments;
 use crate::model::Protobuf;
 use crate::model::ProtobufType;
 use crate::model::{Definition, ObjectIdentifierComponent};
@@ -85,17 +86,30 @@ impl ProtobufDefGenerator {
         Definition(name, protobuf): &Definition<Protobuf>,
     ) -> Result<(), Error> {
         match protobuf {
-            Protobuf::Enum(variants) => {
+            Protobuf::Enum(variants, comments) => {
+                Self::append_comment(target, "", comments.definition())?;
                 writeln!(target, "enum {} {{", name)?;
                 for (tag, variant) in variants.iter().enumerate() {
+                    Self::append_comment(target, "    ", comments.member(tag))?;
                     Self::append_variant(target, name, variant, tag)?;
                 }
                 writeln!(target, "}}")?;
             }
-            Protobuf::Message(fields) => {
+            Protobuf::Message(fields, comments) => {
+                Self::append_comment(target, "", comments.definition())?;
                 writeln!(target, "message {} {{", name)?;
                 for (prev_tag, (field_name, field_type)) in fields.iter().enumerate() {
-                    Self::append_field(target, model, field_name, field_type, prev_tag + 1)?;
+                    if !matches!(field_type, ProtobufType::OneOf(_)) {
+                        Self::append_comment(target, "    ", comments.member(prev_tag))?;
+                    }
+                    Self::append_field(
+                        target,
+                        model,
+                        field_name,
+                        field_type,
+                        prev_tag + 1,
+                        comments,
+                    )?;
                 }
                 writeln!(target, "}}")?;
             }
@@ -103,12 +117,30 @@ impl ProtobufDefGenerator {
         Ok(())
     }
 
+    /// Writes the given comment line by line as `//` comments with the given indentation
+    pub fn append_comment(
+        target: &mut dyn Write,
+        indent: &str,
+        comment: Option<&str>,
+    ) -> Result<(), Error> {
+        for line in comment.iter().flat_map(|comment| comment.lines()) {
+            if line.is_empty() {
+                writeln!(target, "{}//", indent)?;
+            } else {
+                writeln!(target, "{}// {}", indent, line)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The member comments are only used for the fields of a `oneof`
     pub fn append_field(
         target: &mut dyn Write,
         model: &Model<Protobuf>,
         name: &str,
         role: &ProtobufType,
         tag: usize,
+        comments: &Comments,
     ) -> Result<(), Error> {
         writeln!(
             target,
@@ -119,6 +151,7 @@ impl ProtobufDefGenerator {
                 let mut inner = String::new();
                 writeln!(&mut inner, " {{")?;
                 for (index, (variant_name, variant_type)) in variants.iter().enumerate() {
+                    Self::append_comment(&mut inner, "      ", comments.member(index))?;
                     writeln!(
                         &mut inner,
                         "      {} {} = {};",
@@ -169,7 +202,7 @@ impl ProtobufDefGenerator {
                         }
                     }
                 }
-                prefixed.push_str(name);
+                prefixed.push_str(unqualified_type_name(name));
                 prefixed
             }
             ProtobufType::Repeated(inner) => {
@@ -262,6 +295,51 @@ impl ProtobufDefGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::protobuf::ToProtobufModel;
+    use crate::parser::Tokenizer;
+
+    #[test]
+    fn test_comments() {
+        let model = Model::try_from(Tokenizer::default().parse_with_comments(
+            r#"Comments DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            -- The kind
+            Kind ::= ENUMERATED { car, -- a car
+              bus }
+            Vehicle ::= CHOICE {
+                -- by wheels
+                wheels INTEGER (0..255),
+                kind Kind -- the kind
+            }
+            END"#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust()
+        .to_protobuf();
+
+        let mut content = String::new();
+        for definition in &model.definitions {
+            ProtobufDefGenerator::append_definition(&mut content, &model, definition).unwrap();
+        }
+        assert_eq!(
+            "// The kind\n\
+             enum Kind {\n    \
+                 // a car\n    \
+                 KIND_CAR = 0;\n    \
+                 KIND_BUS = 1;\n\
+             }\n\
+             message Vehicle {\n    \
+                 oneof value {\n      \
+                     // by wheels\n      \
+                     uint32 wheels = 1;\n      \
+                     // the kind\n      \
+                     Kind kind = 2;\n    \
+                 };\n\
+             }\n",
+            content
+        );
+    }
 
     #[test]
     fn test_protobuf_variant_name() {
@@ -0,0 +1,610 @@
+use crate::gen::rust::walker::DEFAULT_CONVERSION;
+use crate::gen::rust::GeneratorSupplement;
+use crate::gen::rust::RustCodeGenerator;
+use crate::model::rust::{DataEnum, Field};
+use crate::model::Charset;
+use crate::model::Definition;
+use crate::model::Rust;
+use crate::model::RustType;
+use crate::model::Size;
+use codegen::Scope;
+
+/// `SIZE`s without an upper bound (or with `MAX`) are generated with up to this many elements
+/// more than their lower bound, extensible `SIZE`s with up to this many elements more than their
+/// upper bound
+pub const UNBOUNDED_SIZE_EXTRA: usize = 16;
+
+/// Beyond this nesting depth, the proptest strategies generate optional fields as `None`,
+/// collections with their minimum size and `CHOICE`s with variants that do not refer to other
+/// definitions (if there are any), so that strategies of recursive definitions are finite
+pub const MAX_PROPTEST_DEPTH: u32 = 8;
+
+/// The fields of a struct are combined in tuples of at most this many strategies, because
+/// proptest implements `Strategy` for tuples of up to 12 elements only
+const MAX_TUPLE_LEN: usize = 10;
+
+/// Implements `proptest::arbitrary::Arbitrary` and/or `arbitrary::Arbitrary` for every
+/// definition, so that UPER round-trips can be tested against the whole constraint space. Only
+/// valid values are generated: integers within their range (and outside of it if the range is
+/// extensible), strings, octet strings, bit strings and collections within their `SIZE` (and
+/// outside of it if the `SIZE` is extensible) and with characters of their charset and all variants of `ENUMERATED`s and `CHOICE`s.
+///
+/// The parameter of the proptest strategy is the nesting depth, see [`MAX_PROPTEST_DEPTH`].
+/// The generated code requires the `proptest` and / or `arbitrary` crate as dependency.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ArbitrarySupplement {
+    proptest: bool,
+    arbitrary: bool,
+}
+
+impl ArbitrarySupplement {
+    /// Implements `proptest::arbitrary::Arbitrary`
+    pub const fn proptest() -> Self {
+        Self {
+            proptest: true,
+            arbitrary: false,
+        }
+    }
+
+    /// Implements `arbitrary::Arbitrary`
+    pub const fn arbitrary() -> Self {
+        Self {
+            proptest: false,
+            arbitrary: true,
+        }
+    }
+
+    pub const fn with_proptest(mut self) -> Self {
+        self.proptest = true;
+        self
+    }
+
+    pub const fn with_arbitrary(mut self) -> Self {
+        self.arbitrary = true;
+        self
+    }
+}
+
+impl GeneratorSupplement<Rust> for ArbitrarySupplement {
+    fn add_imports(&self, _scope: &mut Scope) {}
+
+    fn impl_supplement(&self, scope: &mut Scope, definition: &Definition<Rust>) {
+        if self.proptest {
+            Self::impl_proptest_arbitrary(scope, definition);
+        }
+        if self.arbitrary {
+            Self::impl_arbitrary(scope, definition);
+        }
+    }
+}
+
+impl ArbitrarySupplement {
+    fn impl_proptest_arbitrary(scope: &mut Scope, Definition(name, rust): &Definition<Rust>) {
+        let strategy = match rust {
+            Rust::Struct { fields, .. } => Self::proptest_struct_strategy(fields),
+            Rust::Enum(_) => "::proptest::sample::select(Self::variants().to_vec())".to_string(),
+            Rust::DataEnum(enumeration) => Self::proptest_data_enum_strategy(enumeration),
+            Rust::TupleStruct { r#type, .. } => {
+                format!("{}.prop_map(Self)", Self::proptest_strategy(r#type))
+            }
+        };
+
+        let function = scope
+            .new_impl(name)
+            .impl_trait("::proptest::arbitrary::Arbitrary")
+            .associate_type("Parameters", "u32")
+            .associate_type("Strategy", "::proptest::strategy::BoxedStrategy<Self>")
+            .new_fn("arbitrary_with")
+            .arg(
+                if strategy.contains("depth") {
+                    "depth"
+                } else {
+                    "_depth"
+                },
+                "Self::Parameters",
+            )
+            .ret("Self::Strategy")
+            .line("use ::proptest::strategy::Strategy;");
+        if strategy.contains("nested") {
+            function.line(format!("let nested = depth < {};", MAX_PROPTEST_DEPTH));
+        }
+        function.line(format!("{}.boxed()", strategy));
+    }
+
+    fn proptest_struct_strategy(fields: &[Field]) -> String {
+        if fields.is_empty() {
+            return "::proptest::strategy::Just(Self {})".to_string();
+        }
+        let chunks = fields.chunks(MAX_TUPLE_LEN).collect::<Vec<_>>();
+        let tuple = |chunk: &[Field], f: &dyn Fn(&Field) -> String| {
+            format!("({},)", chunk.iter().map(f).collect::<Vec<_>>().join(", "))
+        };
+        let strategies = chunks
+            .iter()
+            .map(|chunk| tuple(chunk, &|field| Self::proptest_strategy(field.r#type())))
+            .collect::<Vec<_>>();
+        let patterns = chunks
+            .iter()
+            .map(|chunk| tuple(chunk, &|field| Self::field_name(field)))
+            .collect::<Vec<_>>();
+        let (strategies, patterns) = if chunks.len() == 1 {
+            (strategies[0].clone(), patterns[0].clone())
+        } else {
+            (
+                format!("({},)", strategies.join(", ")),
+                format!("({},)", patterns.join(", ")),
+            )
+        };
+        format!(
+            "{}.prop_map(|{}| Self {{ {} }})",
+            strategies,
+            patterns,
+            fields
+                .iter()
+                .map(Self::field_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn proptest_data_enum_strategy(enumeration: &DataEnum) -> String {
+        let variant_strategy = |name: &str, r#type: &RustType| {
+            format!(
+                "{}.prop_map(Self::{}).boxed()",
+                Self::proptest_strategy(r#type),
+                RustCodeGenerator::rust_variant_name(name)
+            )
+        };
+        let all = enumeration
+            .variants()
+            .map(|variant| variant_strategy(variant.name(), variant.r#type()))
+            .collect::<Vec<_>>();
+        let simple = enumeration
+            .variants()
+            .filter(|variant| !Self::refers_to_definition(variant.r#type()))
+            .map(|variant| variant_strategy(variant.name(), variant.r#type()))
+            .collect::<Vec<_>>();
+        if simple.is_empty() || simple.len() == all.len() {
+            format!("::proptest::strategy::Union::new(vec![{}])", all.join(", "))
+        } else {
+            format!(
+                "::proptest::strategy::Union::new(if nested {{ vec![{}] }} else {{ vec![{}] }})",
+                all.join(", "),
+                simple.join(", ")
+            )
+        }
+    }
+
+    fn proptest_strategy(r#type: &RustType) -> String {
+        match r#type {
+            RustType::Bool => "::proptest::arbitrary::any::<bool>()".to_string(),
+            RustType::I8(_)
+            | RustType::U8(_)
+            | RustType::I16(_)
+            | RustType::U16(_)
+            | RustType::I32(_)
+            | RustType::U32(_)
+            | RustType::I64(_)
+            | RustType::U64(_) => match Self::integer_ranges(r#type) {
+                (root, Some(full)) => {
+                    format!("::proptest::strategy::Union::new(vec![{}, {}])", root, full)
+                }
+                (root, None) => root,
+            },
+            RustType::String(size, charset, alphabet) => {
+                Self::proptest_sizes(size, |(min, max)| {
+                    format!(
+                        "::proptest::string::string_regex({:?}).unwrap()",
+                        format!(
+                            "{}{{{},{}}}",
+                            Self::charset_regex(*charset, alphabet.as_deref()),
+                            min,
+                            max
+                        )
+                    )
+                })
+            }
+            RustType::VecU8(size) => Self::proptest_sizes(size, |(min, max)| {
+                format!(
+                    "::proptest::collection::vec(::proptest::arbitrary::any::<u8>(), {}..={})",
+                    min, max
+                )
+            }),
+            RustType::BitVec(size) => format!(
+                "{}.prop_map(|bits| bits.into_iter().collect::<BitVec>())",
+                Self::proptest_sizes(size, |(min, max)| format!(
+                    "::proptest::collection::vec(::proptest::arbitrary::any::<bool>(), {}..={})",
+                    min, max
+                ))
+            ),
+            RustType::Vec(inner, size, _) => match Self::size_bounds(size) {
+                ((0, _), _) => format!(
+                    "if nested {{ {}.boxed() }} \
+                        else {{ ::proptest::strategy::Just(Vec::new()).boxed() }}",
+                    Self::proptest_sizes(size, |(min, max)| format!(
+                        "::proptest::collection::vec({}, {}..={})",
+                        Self::proptest_strategy(inner),
+                        min,
+                        max
+                    ))
+                ),
+                _ => Self::proptest_sizes(size, |(min, max)| {
+                    format!(
+                        "::proptest::collection::vec({}, {}..=if nested {{ {} }} else {{ {} }})",
+                        Self::proptest_strategy(inner),
+                        min,
+                        max,
+                        min
+                    )
+                }),
+            },
+            RustType::ByteArray(size) => {
+                format!("::proptest::arbitrary::any::<[u8; {}]>()", size)
+            }
+            RustType::Array(inner, size) => format!(
+                "::proptest::collection::vec({}, {}).prop_map(|values| {})",
+                Self::proptest_strategy(inner),
+                size,
+                Self::array_from_vec(inner, *size)
+            ),
+            RustType::Null => "::proptest::strategy::Just(Null)".to_string(),
+            RustType::Option(inner) => format!(
+                "if nested {{ ::proptest::option::of({}).boxed() }} \
+                    else {{ ::proptest::strategy::Just(None).boxed() }}",
+                Self::proptest_strategy(inner)
+            ),
+            RustType::Default(inner, _) => Self::proptest_strategy(inner),
+            RustType::Box(inner) => {
+                format!("{}.prop_map(Box::new)", Self::proptest_strategy(inner))
+            }
+            RustType::Override(inner, type_override) => format!(
+                "{}.prop_filter_map(\"not convertible\", |v| <{} as ::asn1rs::syn::mapped::Conversion<_, {}>>::from_asn(v).ok())",
+                Self::proptest_strategy(inner),
+                type_override.conversion().unwrap_or(DEFAULT_CONVERSION),
+                type_override.rust_type()
+            ),
+            RustType::Complex(name, _) => {
+                format!("::proptest::arbitrary::any_with::<{}>(depth + 1)", name)
+            }
+        }
+    }
+
+    fn impl_arbitrary(scope: &mut Scope, Definition(name, rust): &Definition<Rust>) {
+        let function = scope
+            .new_impl(name)
+            .generic("'a")
+            .impl_trait("::arbitrary::Arbitrary<'a>")
+            .new_fn("arbitrary")
+            .arg("u", "&mut ::arbitrary::Unstructured<'a>")
+            .ret("::arbitrary::Result<Self>");
+        match rust {
+            Rust::Struct { fields, .. } => {
+                function.line("Ok(Self {");
+                for field in fields {
+                    function.line(format!(
+                        "    {}: {},",
+                        Self::field_name(field),
+                        Self::arbitrary_value(field.r#type())
+                    ));
+                }
+                function.line("})");
+            }
+            Rust::Enum(_) => {
+                function.line("Ok(*u.choose(&Self::variants())?)");
+            }
+            Rust::DataEnum(enumeration) if enumeration.len() == 1 => {
+                let variant = enumeration.variants().next().unwrap();
+                function.line(format!(
+                    "Ok(Self::{}({}))",
+                    RustCodeGenerator::rust_variant_name(variant.name()),
+                    Self::arbitrary_value(variant.r#type())
+                ));
+            }
+            Rust::DataEnum(enumeration) => {
+                function.line(format!(
+                    "match u.int_in_range(0..={})? {{",
+                    enumeration.len() - 1
+                ));
+                for (index, variant) in enumeration.variants().enumerate() {
+                    function.line(format!(
+                        "    {} => Ok(Self::{}({})),",
+                        if index + 1 == enumeration.len() {
+                            "_".to_string()
+                        } else {
+                            index.to_string()
+                        },
+                        RustCodeGenerator::rust_variant_name(variant.name()),
+                        Self::arbitrary_value(variant.r#type())
+                    ));
+                }
+                function.line("}");
+            }
+            Rust::TupleStruct { r#type, .. } => {
+                function.line(format!("Ok(Self({}))", Self::arbitrary_value(r#type)));
+            }
+        }
+    }
+
+    fn arbitrary_value(r#type: &RustType) -> String {
+        match r#type {
+            RustType::Bool => "u.arbitrary::<bool>()?".to_string(),
+            RustType::I8(_)
+            | RustType::U8(_)
+            | RustType::I16(_)
+            | RustType::U16(_)
+            | RustType::I32(_)
+            | RustType::U32(_)
+            | RustType::I64(_)
+            | RustType::U64(_) => match Self::integer_ranges(r#type) {
+                (root, Some(full)) => format!(
+                    "{{ let range = if u.arbitrary::<bool>()? {{ {} }} else {{ {} }}; \
+                        u.int_in_range(range)? }}",
+                    root, full
+                ),
+                (root, None) => format!("u.int_in_range({})?", root),
+            },
+            RustType::String(size, charset, alphabet) => format!(
+                "{{ let len = {}; \
+                    (0..len).map(|_| {}).collect::<::arbitrary::Result<String>>()? }}",
+                Self::arbitrary_len(size),
+                Self::arbitrary_char(*charset, alphabet.as_deref())
+            ),
+            RustType::VecU8(size) => format!(
+                "{{ let len = {}; (0..len).map(|_| u.arbitrary::<u8>()).collect::<::arbitrary::Result<Vec<u8>>>()? }}",
+                Self::arbitrary_len(size)
+            ),
+            RustType::BitVec(size) => format!(
+                "{{ let len = {}; \
+                    (0..len).map(|_| u.arbitrary::<bool>()).collect::<::arbitrary::Result<BitVec>>()? }}",
+                Self::arbitrary_len(size)
+            ),
+            RustType::Vec(inner, size, _) => format!(
+                "{{ let len = {}; {} }}",
+                Self::arbitrary_len(size),
+                Self::arbitrary_values("len", inner)
+            ),
+            RustType::ByteArray(size) => format!("u.arbitrary::<[u8; {}]>()?", size),
+            RustType::Array(inner, size) => format!(
+                "{{ let values = {}; {} }}",
+                Self::arbitrary_values(&size.to_string(), inner),
+                Self::array_from_vec(inner, *size)
+            ),
+            RustType::Null => "Null".to_string(),
+            RustType::Option(inner) => format!(
+                "if u.arbitrary::<bool>()? {{ Some({}) }} else {{ None }}",
+                Self::arbitrary_value(inner)
+            ),
+            RustType::Default(inner, _) => Self::arbitrary_value(inner),
+            RustType::Box(inner) => format!("Box::new({})", Self::arbitrary_value(inner)),
+            RustType::Override(inner, type_override) => format!(
+                "<{} as ::asn1rs::syn::mapped::Conversion<_, {}>>::from_asn({}).map_err(|_| ::arbitrary::Error::IncorrectFormat)?",
+                type_override.conversion().unwrap_or(DEFAULT_CONVERSION),
+                type_override.rust_type(),
+                Self::arbitrary_value(inner)
+            ),
+            RustType::Complex(name, _) => format!("u.arbitrary::<{}>()?", name),
+        }
+    }
+
+    /// A `Vec` of `len` values of the given type
+    fn arbitrary_values(len: &str, r#type: &RustType) -> String {
+        let value = match Self::arbitrary_result(r#type) {
+            Some(result) => result,
+            None => format!(
+                "-> ::arbitrary::Result<_> {{ Ok({}) }}",
+                Self::arbitrary_value(r#type)
+            ),
+        };
+        format!(
+            "(0..{}).map(|_| {}).collect::<::arbitrary::Result<Vec<_>>>()?",
+            len, value
+        )
+    }
+
+    /// The `::arbitrary::Result` of a value of the given type, if it is a single call
+    fn arbitrary_result(r#type: &RustType) -> Option<String> {
+        match r#type {
+            RustType::Bool => Some("u.arbitrary::<bool>()".to_string()),
+            RustType::ByteArray(size) => Some(format!("u.arbitrary::<[u8; {}]>()", size)),
+            RustType::Default(inner, _) => Self::arbitrary_result(inner),
+            RustType::Complex(name, _) => Some(format!("u.arbitrary::<{}>()", name)),
+            _ => None,
+        }
+    }
+
+    fn arbitrary_char(charset: Charset, alphabet: Option<&str>) -> String {
+        if let Some(alphabet) = alphabet {
+            return format!(
+                "u.choose(&{:?}).copied()",
+                alphabet.chars().collect::<Vec<_>>()
+            );
+        }
+        match charset {
+            Charset::Utf8 => "u.arbitrary::<char>()".to_string(),
+            Charset::Ia5 => "u.int_in_range(0x00..=0x7F_u8).map(char::from)".to_string(),
+            Charset::Visible => "u.int_in_range(0x20..=0x7E_u8).map(char::from)".to_string(),
+            Charset::Numeric => format!(
+                "u.choose(b\"{}\").map(|c| char::from(*c))",
+                Charset::NUMERIC_STRING_CHARACTERS
+            ),
+            Charset::Printable => format!(
+                "u.choose(b\"{}\").map(|c| char::from(*c))",
+                Charset::PRINTABLE_STRING_CHARACTERS
+            ),
+        }
+    }
+
+    fn charset_regex(charset: Charset, alphabet: Option<&str>) -> String {
+        if let Some(alphabet) = alphabet {
+            return format!(
+                "[{}]",
+                alphabet
+                    .chars()
+                    .map(|c| format!("\\x{{{:X}}}", c as u32))
+                    .collect::<String>()
+            );
+        }
+        match charset {
+            Charset::Utf8 => ".",
+            Charset::Ia5 => "[\\x00-\\x7F]",
+            Charset::Visible => "[\\x20-\\x7E]",
+            Charset::Numeric => "[ 0-9]",
+            Charset::Printable => "[ '()+,\\-./0-9:=?A-Za-z]",
+        }
+        .to_string()
+    }
+
+    /// The range of the root and, if extensible, of the whole Rust type (limited to `i64::MAX`)
+    fn integer_ranges(r#type: &RustType) -> (String, Option<String>) {
+        let rust = r#type.to_string();
+        let range = r#type.integer_range_str().unwrap();
+        let root = format!("{}_{}..={}_{}", range.min(), rust, range.max(), rust);
+        let full = if !range.extensible() {
+            None
+        } else if let RustType::U64(_) = r#type {
+            Some(format!("0_u64..={}_u64", i64::MAX))
+        } else {
+            Some(format!("{}::MIN..={}::MAX", rust, rust))
+        };
+        (root, full)
+    }
+
+    /// The lower and upper bound of the root of the size and, if extensible, of the sizes that
+    /// are generated beyond the root, see [`UNBOUNDED_SIZE_EXTRA`]
+    fn size_bounds(size: &Size) -> ((usize, usize), Option<(usize, usize)>) {
+        let min = size.min().copied().unwrap_or_default();
+        let max = size
+            .max()
+            .copied()
+            .filter(|max| *max < i64::MAX as usize)
+            .unwrap_or(min + UNBOUNDED_SIZE_EXTRA);
+        let extension = if size.extensible() {
+            Some((0, max + UNBOUNDED_SIZE_EXTRA))
+        } else {
+            None
+        };
+        ((min, max), extension)
+    }
+
+    /// The strategy of the root of the size or, if extensible, the union with the strategy of
+    /// the sizes beyond the root
+    fn proptest_sizes(size: &Size, strategy: impl Fn((usize, usize)) -> String) -> String {
+        match Self::size_bounds(size) {
+            (root, Some(extension)) => format!(
+                "::proptest::strategy::Union::new(vec![{}, {}])",
+                strategy(root),
+                strategy(extension)
+            ),
+            (root, None) => strategy(root),
+        }
+    }
+
+    /// The expression of the length of a value of the given size
+    fn arbitrary_len(size: &Size) -> String {
+        match Self::size_bounds(size) {
+            ((min, max), Some((ext_min, ext_max))) => format!(
+                "{{ let range = if u.arbitrary::<bool>()? {{ {}_usize..={} }} else {{ {}_usize..={} }}; \
+                    u.int_in_range(range)? }}",
+                min, max, ext_min, ext_max
+            ),
+            ((min, max), None) => format!("u.int_in_range({}_usize..={})?", min, max),
+        }
+    }
+
+    fn array_from_vec(inner: &RustType, size: usize) -> String {
+        let rust = inner.to_string();
+        format!(
+            "<[{}; {}] as ::core::convert::TryFrom<Vec<{}>>>::try_from(values).unwrap()",
+            rust, size, rust
+        )
+    }
+
+    fn refers_to_definition(r#type: &RustType) -> bool {
+        matches!(r#type.as_inner_type(), RustType::Complex(..))
+    }
+
+    fn field_name(field: &Field) -> String {
+        RustCodeGenerator::rust_field_name(field.name(), true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::Generator;
+    use crate::model::Model;
+    use crate::parser::Tokenizer;
+
+    #[test]
+    pub fn test_arbitrary_impls() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"Fuzzing DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Kind ::= ENUMERATED { car, truck, ... }
+
+            Node ::= SEQUENCE {
+                speed INTEGER (-10..250),
+                load INTEGER (0..100, ...),
+                any INTEGER,
+                kind Kind DEFAULT truck,
+                plate NumericString (SIZE(2..8)),
+                label PrintableString (SIZE(0..4, ...)),
+                note UTF8String OPTIONAL,
+                raw OCTET STRING (SIZE(1..MAX)),
+                flags BIT STRING (SIZE(3)),
+                valid BOOLEAN,
+                children SEQUENCE (SIZE(0..3)) OF Node,
+                next Node OPTIONAL
+            }
+
+            Payload ::= CHOICE {
+                node Node,
+                text IA5String,
+                ...,
+                nothing NULL
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+        generator.add_supplement(ArbitrarySupplement::proptest().with_arbitrary());
+        let (_file_name, file_content) = generator.to_string().unwrap().into_iter().next().unwrap();
+
+        assert!(file_content.contains("impl ::proptest::arbitrary::Arbitrary for Kind {"));
+        assert!(file_content
+            .contains("fn arbitrary_with(_depth: Self::Parameters) -> Self::Strategy {"));
+        assert!(
+            file_content.contains("::proptest::sample::select(Self::variants().to_vec()).boxed()")
+        );
+        assert!(file_content.contains("impl<'a> ::arbitrary::Arbitrary<'a> for Kind {"));
+
+        assert!(file_content.contains("impl ::proptest::arbitrary::Arbitrary for Node {"));
+        assert!(file_content.contains("let nested = depth < 8;"));
+        assert!(file_content.contains("(-10_i16..=250_i16, "));
+        assert!(file_content.contains(
+            "::proptest::strategy::Union::new(vec![0_u64..=100_u64, 0_u64..=9223372036854775807_u64])"
+        ));
+        assert!(file_content.contains("::proptest::string::string_regex(\"[ 0-9]{2,8}\").unwrap()"));
+        assert!(file_content.contains(
+            "::proptest::collection::vec(::proptest::arbitrary::any_with::<Node>(depth + 1), 0..=3)"
+        ));
+        assert!(file_content.contains(
+            "::proptest::strategy::Union::new(vec![::proptest::string::string_regex(\"[ '()+,\\\\-./0-9:=?A-Za-z]{0,4}\").unwrap(), ::proptest::string::string_regex(\"[ '()+,\\\\-./0-9:=?A-Za-z]{0,20}\").unwrap()])"
+        ));
+        assert!(file_content.contains("speed: u.int_in_range(-10_i16..=250_i16)?,"));
+        assert!(file_content.contains(
+            "let len = { let range = if u.arbitrary::<bool>()? { 0_usize..=4 } else { 0_usize..=20 }; u.int_in_range(range)? };"
+        ));
+
+        assert!(file_content.contains("impl ::proptest::arbitrary::Arbitrary for Payload {"));
+        assert!(file_content.contains("impl<'a> ::arbitrary::Arbitrary<'a> for Payload {"));
+        assert!(file_content.contains("_ => Ok(Self::Nothing(Null)),"));
+    }
+}
@@ -462,7 +462,7 @@ fn insert_vec_field(
         ));
     } else {
         many_insert.line(&format!(
-            "let inserted = {}::try_join_all({}{}.iter().map(|v| v.{}(context)));",
+            "let inserted = {}::try_join_all({}{}.iter().map(|v| Box::pin(v.{}(context))));",
             MODULE_NAME,
             if on_self { "self." } else { "" },
             field_name,
@@ -538,8 +538,9 @@ fn insert_complex_field(
     field_name_as_variable: Option<&str>,
 ) -> FieldInsert {
     let variable_name = field_name_as_variable.unwrap_or(field_name).to_string();
+    // the future is boxed, because recursive types require recursive async fns
     container.line(&format!(
-        "let {} = {}{}.{}(context);",
+        "let {} = Box::pin({}{}.{}(context));",
         variable_name,
         if on_self { "self." } else { "" },
         field_name,
@@ -777,7 +778,7 @@ impl AsyncPsqlInserter {
             ));
 
             container.line(format!(
-                "for row in rows {{ {}.push({}::{}(context, &row).await?); }}",
+                "for row in rows {{ {}.push(Box::pin({}::{}(context, &row)).await?); }}",
                 RustCodeGenerator::rust_field_name(field, true),
                 inner.to_inner_type_string(),
                 load_fn_name(),
@@ -834,12 +835,21 @@ impl AsyncPsqlInserter {
     }
 
     fn append_load_complex_field(container: &mut impl Container, field: &str, f_type: &RustType) {
-        container.line(format!(
-            "let {} = {}::{}(context, {}).await?;",
-            RustCodeGenerator::rust_field_name(field, true),
+        // the future is boxed, because recursive types require recursive async fns
+        let retrieve = format!(
+            "Box::pin({}::{}(context, {})).await?",
             f_type.to_inner_type_string(),
             retrieve_fn_name(),
             RustCodeGenerator::rust_field_name(field, true),
+        );
+        container.line(format!(
+            "let {} = {};",
+            RustCodeGenerator::rust_field_name(field, true),
+            if let RustType::Box(_) = f_type {
+                format!("Box::new({})", retrieve)
+            } else {
+                retrieve
+            }
         ));
     }
 }
@@ -1,3 +1,5 @@
+pub mod arbitrary;
+pub mod serde;
 pub mod walker;
 
 #[cfg(feature = "psql")]
@@ -10,21 +12,25 @@ pub mod async_psql;
 pub(crate) mod shared_psql;
 
 use crate::gen::Generator;
-use crate::model::rust::{DataEnum, Field};
+use crate::model::rust::visit::{self as rust_visit, VisitMut as RustVisitMut};
+use crate::model::rust::{DataEnum, Field, TypeOverride};
 use crate::model::rust::{EncodingOrdering, PlainEnum};
+use crate::model::Comments;
 use crate::model::Model;
 use crate::model::Rust;
 use crate::model::RustType;
 use crate::model::TagProperty;
-use crate::model::{Definition, Tag, Type as AsnType, Type};
+use crate::model::{Definition, LiteralValue, Tag, Type as AsnType, Type, ValueReference};
+use crate::model::{ObjectIdentifier, ObjectIdentifierComponent};
+use crate::model::{Range, Size};
 use codegen::Block;
 use codegen::Enum;
 use codegen::Impl;
 use codegen::Scope;
 use codegen::Struct;
 use std::borrow::Cow;
-use std::convert::Infallible;
-use std::fmt::Display;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
 
 #[cfg(feature = "psql")]
 use self::psql::PsqlInserter;
@@ -36,6 +42,43 @@ const KEYWORDS: [&str; 9] = [
     "use", "mod", "const", "type", "pub", "enum", "struct", "impl", "trait",
 ];
 
+/// The size of the largest array that implements `Default`, see
+/// [`RustCodeGenerator::set_fixed_size_arrays`]
+pub const MAX_FIXED_SIZE_ARRAY_LEN: usize = 32;
+
+/// The trait that converts values from and to an overridden type, see [`TypeOverride`]
+const CONVERSION_TRAIT: &str = "::asn1rs::syn::mapped::Conversion";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The modules (first and second) would be written to the same file (third), because their
+    /// Rust module names are the same and not both of them have an `OBJECT IDENTIFIER` to nest
+    /// them by
+    CollidingModules(String, String, String),
+    /// The value (second) of the value reference (first) cannot be expressed as the Rust type
+    /// (third), for example because a mandatory component is missing
+    InexpressibleValue(String, String, String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Error::CollidingModules(first, second, file) => write!(
+                f,
+                "The modules {} and {} would both be written to {}",
+                first, second, file
+            ),
+            Error::InexpressibleValue(name, value, r#type) => write!(
+                f,
+                "The value {} of {} cannot be expressed as {}",
+                value, name, r#type
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
 pub trait GeneratorSupplement<T> {
     fn add_imports(&self, scope: &mut Scope);
     fn impl_supplement(&self, scope: &mut Scope, definition: &Definition<T>);
@@ -49,6 +92,11 @@ pub trait GeneratorSupplement<T> {
     ) {
     }
     fn extend_impl_of_tuple(&self, _name: &str, _impl_scope: &mut Impl, _definition: &RustType) {}
+    /// Additional attributes of the field or variant at the given index, where the inner type of
+    /// a tuple struct is at index zero
+    fn attributes_of_member(&self, _definition: &Definition<T>, _index: usize) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -58,6 +106,16 @@ pub struct RustCodeGenerator {
     global_derives: Vec<String>,
     direct_field_access: bool,
     getter_and_setter: bool,
+    type_overrides: Vec<(String, TypeOverride)>,
+    field_type_overrides: Vec<(String, String, TypeOverride)>,
+    fixed_size_arrays: bool,
+    checked_integers: bool,
+    builders: bool,
+    module_root: Option<String>,
+    module_paths: Vec<(String, String)>,
+    nest_modules_by_oid: bool,
+    root_file: Option<String>,
+    supplements: Supplements,
 }
 
 impl From<Model<Rust>> for RustCodeGenerator {
@@ -75,14 +133,33 @@ impl Default for RustCodeGenerator {
             global_derives: Vec::default(),
             direct_field_access: true,
             getter_and_setter: false,
+            type_overrides: Vec::default(),
+            field_type_overrides: Vec::default(),
+            fixed_size_arrays: false,
+            checked_integers: false,
+            builders: false,
+            module_root: None,
+            module_paths: Vec::default(),
+            nest_modules_by_oid: false,
+            root_file: None,
+            supplements: Supplements::default(),
         }
     }
 }
 
 impl Generator<Rust> for RustCodeGenerator {
-    type Error = Infallible;
+    type Error = Error;
 
-    fn add_model(&mut self, model: Model<Rust>) {
+    fn add_model(&mut self, mut model: Model<Rust>) {
+        if self.fixed_size_arrays {
+            FixedSizeArrays(true).visit_model_mut(&mut model);
+        }
+        for (definition, type_override) in &self.type_overrides {
+            Self::override_definition_type(&mut model, definition, type_override);
+        }
+        for (definition, field, type_override) in &self.field_type_overrides {
+            Self::override_field_type(&mut model, definition, field, type_override);
+        }
         self.models.push(model);
     }
 
@@ -95,12 +172,16 @@ impl Generator<Rust> for RustCodeGenerator {
     }
 
     fn to_string(&self) -> Result<Vec<(String, String)>, Self::Error> {
-        Ok(self.to_string_with_generators(&[
+        let mut generators: Vec<&dyn GeneratorSupplement<Rust>> = vec![
             #[cfg(feature = "psql")]
             &PsqlInserter,
             #[cfg(feature = "async-psql")]
             &AsyncPsqlInserter,
-        ]))
+        ];
+        generators.extend(self.supplements.0.iter().map(AsRef::as_ref));
+        self.check_module_files()?;
+        self.check_values()?;
+        Ok(self.to_string_with_generators(&generators))
     }
 }
 
@@ -130,6 +211,175 @@ impl RustCodeGenerator {
         self.getter_and_setter = allow;
     }
 
+    pub const fn fixed_size_arrays(&self) -> bool {
+        self.fixed_size_arrays
+    }
+
+    /// Generates `[u8; N]` and `[T; N]` instead of `Vec<u8>` and `Vec<T>` for `OCTET STRING`s
+    /// and `SEQUENCE OF`s with a non-extensible fixed size of up to
+    /// [`MAX_FIXED_SIZE_ARRAY_LEN`], so that values of a wrong size cannot be constructed.
+    /// Values with a `DEFAULT` keep their `Vec`. Applies to the models that were added before
+    /// as well. The psql generators do not support fixed-size arrays.
+    pub fn set_fixed_size_arrays(&mut self, allow: bool) {
+        self.fixed_size_arrays = allow;
+        for model in &mut self.models {
+            FixedSizeArrays(allow).visit_model_mut(model);
+        }
+    }
+
+    pub const fn checked_integers(&self) -> bool {
+        self.checked_integers
+    }
+
+    /// Generates definitions of `INTEGER`s with a non-extensible range that is narrower than the
+    /// Rust type, such as `SpeedValue ::= INTEGER (0..16383)`, as newtypes that cannot hold a
+    /// value outside of the range: the value is private and a `new` and `TryFrom` constructor
+    /// validate it, `MIN`, `MAX` and the named numbers are associated constants of the newtype
+    /// (named numbers `min` and `max` become `MIN_NUMBER` and `MAX_NUMBER`) and arithmetic is
+    /// checked or saturating. `new_unchecked` skips the validation.
+    pub fn set_checked_integers(&mut self, allow: bool) {
+        self.checked_integers = allow;
+    }
+
+    /// Adds a supplement that is applied to every definition when generating with
+    /// [`Generator::to_string`], after the supplements of the enabled features (such as psql),
+    /// for example [`arbitrary::ArbitrarySupplement`]
+    pub fn add_supplement<S: GeneratorSupplement<Rust> + 'static>(&mut self, supplement: S) {
+        self.supplements.0.push(Box::new(supplement));
+    }
+
+    pub const fn builders(&self) -> bool {
+        self.builders
+    }
+
+    /// Generates a `FooBuilder` for each `SEQUENCE` and `SET` `Foo`, with a setter per field and
+    /// `Foo::builder()` to create it. Optional fields start as `None` and fields with a `DEFAULT`
+    /// with their default value. `build()` fails with the names of the mandatory fields that
+    /// were not set.
+    pub fn set_builders(&mut self, allow: bool) {
+        self.builders = allow;
+    }
+
+    pub fn module_root(&self) -> Option<&str> {
+        self.module_root.as_deref()
+    }
+
+    /// Refers to other modules by absolute paths below the given module, such as `crate::asn`,
+    /// instead of relative to the current module with `super::`. Either way, the modules are
+    /// expected to be declared as in the root file, see [`RustCodeGenerator::set_root_file`].
+    pub fn set_module_root<S: Into<String>>(&mut self, path: S) {
+        self.module_root = Some(path.into());
+    }
+
+    /// Places the module with the given ASN.1 name at the path below the module root, such as
+    /// `etsi::its_container`, instead of deriving the path from its name (and OID)
+    pub fn add_module_path<M: Into<String>, P: Into<String>>(&mut self, module: M, path: P) {
+        self.module_paths.push((module.into(), path.into()));
+    }
+
+    pub const fn nest_modules_by_oid(&self) -> bool {
+        self.nest_modules_by_oid
+    }
+
+    /// Nests the modules below a module per component of their `OBJECT IDENTIFIER`, such as
+    /// `itu_t_0::identified_organization_4::...::its_container`. Modules whose names would
+    /// collide otherwise, such as two versions of a module, are always nested by their OID,
+    /// [`Generator::to_string`] fails with [`Error::CollidingModules`] if they do not have one.
+    pub fn set_nest_modules_by_oid(&mut self, allow: bool) {
+        self.nest_modules_by_oid = allow;
+    }
+
+    pub fn root_file(&self) -> Option<&str> {
+        self.root_file.as_deref()
+    }
+
+    /// Additionally generates a root file with the given name, such as `mod.rs` or `lib.rs`,
+    /// which declares the (nested) modules of all models. The files of nested modules are
+    /// placed in the corresponding subdirectories.
+    pub fn set_root_file<S: Into<String>>(&mut self, name: S) {
+        self.root_file = Some(name.into());
+    }
+
+    /// Replaces the type of all fields, variants and elements that refer to the definition by
+    /// the user type, while the definition itself is still generated (to convert from and to).
+    /// Overrides of single fields take precedence.
+    pub fn add_type_override<S: Into<String>>(
+        &mut self,
+        definition: S,
+        type_override: TypeOverride,
+    ) {
+        let definition = definition.into();
+        for model in &mut self.models {
+            Self::override_definition_type(model, &definition, &type_override);
+        }
+        self.type_overrides.push((definition, type_override));
+    }
+
+    /// Replaces the type of the field or variant of the definition by the user type, `"0"`
+    /// names the inner type of a definition that is generated as tuple struct. The names are
+    /// those of the Rust model, see [`Model::to_rust`].
+    pub fn add_field_type_override<D: Into<String>, F: Into<String>>(
+        &mut self,
+        definition: D,
+        field: F,
+        type_override: TypeOverride,
+    ) {
+        let definition = definition.into();
+        let field = field.into();
+        for model in &mut self.models {
+            Self::override_field_type(model, &definition, &field, &type_override);
+        }
+        self.field_type_overrides
+            .push((definition, field, type_override));
+    }
+
+    fn override_definition_type(
+        model: &mut Model<Rust>,
+        definition: &str,
+        type_override: &TypeOverride,
+    ) {
+        OverrideDefinitionType(definition, type_override).visit_model_mut(model);
+    }
+
+    pub(crate) fn override_field_type(
+        model: &mut Model<Rust>,
+        definition: &str,
+        field: &str,
+        type_override: &TypeOverride,
+    ) {
+        Self::map_field_type(model, definition, field, |r#type| {
+            r#type.override_with(type_override.clone())
+        });
+    }
+
+    /// Calls `f` with the type of the field or variant of the definition, `"0"` names the inner
+    /// type of a tuple struct
+    pub(crate) fn map_field_type<F: FnMut(&mut RustType)>(
+        model: &mut Model<Rust>,
+        definition: &str,
+        field: &str,
+        mut f: F,
+    ) {
+        for Definition(_, rust) in model
+            .definitions
+            .iter_mut()
+            .filter(|Definition(name, _)| name == definition)
+        {
+            match rust {
+                Rust::Struct { fields, .. } => fields
+                    .iter_mut()
+                    .filter(|member| member.name() == field)
+                    .for_each(|member| f(member.r#type_mut())),
+                Rust::DataEnum(enumeration) => enumeration
+                    .variants_mut()
+                    .filter(|v| v.name() == field)
+                    .for_each(|v| f(v.r#type_mut())),
+                Rust::TupleStruct { r#type, .. } if field == "0" => f(r#type),
+                Rust::Enum(_) | Rust::TupleStruct { .. } => {}
+            }
+        }
+    }
+
     pub fn to_string_without_generators(&self) -> Vec<(String, String)> {
         self.to_string_with_generators(&[])
     }
@@ -142,43 +392,238 @@ impl RustCodeGenerator {
         for model in &self.models {
             files.push(self.model_to_file(model, generators));
         }
+        if let Some(root_file) = &self.root_file {
+            files.push((root_file.clone(), self.root_module()));
+        }
         files
     }
 
+    /// Fails if two models would be written to the same file, which
+    /// [`RustCodeGenerator::to_string_with_generators`] does not check
+    pub fn check_module_files(&self) -> Result<(), Error> {
+        for (index, model) in self.models.iter().enumerate() {
+            let segments = self.module_segments(&model.name, model.oid.as_ref());
+            if let Some(other) = self.models[..index]
+                .iter()
+                .find(|other| self.module_segments(&other.name, other.oid.as_ref()) == segments)
+            {
+                return Err(Error::CollidingModules(
+                    other.name.clone(),
+                    model.name.clone(),
+                    format!("{}.rs", segments.join("/")),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fails if a structured value cannot be expressed as its Rust type, which
+    /// [`RustCodeGenerator::to_string_with_generators`] writes as `compile_error!` instead
+    pub fn check_values(&self) -> Result<(), Error> {
+        for model in &self.models {
+            for vref in &model.value_references {
+                if vref.value.as_rust_const_literal(true).is_none()
+                    && self
+                        .value_expression(
+                            &vref.name,
+                            &model.name,
+                            &model.name,
+                            &vref.role,
+                            &vref.value,
+                        )
+                        .is_none()
+                {
+                    return Err(Error::InexpressibleValue(
+                        vref.name.clone(),
+                        vref.value.to_string(),
+                        vref.role.to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Declares the modules of all models, nested as described by their paths
+    pub fn root_module(&self) -> String {
+        let modules = self
+            .models
+            .iter()
+            .map(|model| self.module_segments(&model.name, model.oid.as_ref()))
+            .collect::<Vec<_>>();
+        Self::declare_modules(&modules, |_| Vec::new())
+    }
+
+    /// Declares the modules with the given paths in a tree of (inline) parent modules, the
+    /// modules themselves are declared with the `attributes` for their path, such as `#[path]`
+    pub fn declare_modules<F: Fn(&[String]) -> Vec<String>>(
+        modules: &[Vec<String>],
+        attributes: F,
+    ) -> String {
+        #[derive(Default)]
+        struct Node(BTreeMap<String, Node>);
+
+        fn write<F: Fn(&[String]) -> Vec<String>>(
+            out: &mut String,
+            node: &Node,
+            path: &mut Vec<String>,
+            attributes: &F,
+        ) {
+            for (name, child) in &node.0 {
+                let indent = "    ".repeat(path.len());
+                path.push(name.clone());
+                if child.0.is_empty() {
+                    for attribute in attributes(path) {
+                        out.push_str(&format!("{}{}\n", indent, attribute));
+                    }
+                    out.push_str(&format!("{}pub mod {};\n", indent, name));
+                } else {
+                    out.push_str(&format!("{}pub mod {} {{\n", indent, name));
+                    write(out, child, path, attributes);
+                    out.push_str(&format!("{}}}\n", indent));
+                }
+                path.pop();
+            }
+        }
+
+        let mut root = Node::default();
+        for module in modules {
+            let mut node = &mut root;
+            for segment in module {
+                node = node.0.entry(segment.clone()).or_default();
+            }
+        }
+        let mut out = String::new();
+        write(&mut out, &root, &mut Vec::new(), &attributes);
+        out
+    }
+
+    /// The path of the module below the module root, see [`RustCodeGenerator::add_module_path`]
+    /// and [`RustCodeGenerator::set_nest_modules_by_oid`]
+    pub fn module_segments(&self, module: &str, oid: Option<&ObjectIdentifier>) -> Vec<String> {
+        if let Some((_, path)) = self.module_paths.iter().find(|(name, _)| {
+            // the names of the modules in the Rust model might have been made nice already
+            crate::model::rust::rust_module_name(name, false)
+                == crate::model::rust::rust_module_name(module, false)
+        }) {
+            return path.split("::").map(str::to_string).collect();
+        }
+        let name = Self::rust_module_name(module);
+        let collides = self.models.iter().any(|model| {
+            Self::rust_module_name(&model.name) == name
+                && (model.name != module
+                    || match (&model.oid, oid) {
+                        (Some(a), Some(b)) => !a.matches(b),
+                        _ => false,
+                    })
+        });
+        let mut segments = match oid {
+            Some(oid) if self.nest_modules_by_oid || collides => {
+                oid.iter().map(Self::rust_oid_module_name).collect()
+            }
+            _ => Vec::new(),
+        };
+        segments.push(name);
+        segments
+    }
+
+    fn rust_oid_module_name(component: &ObjectIdentifierComponent) -> String {
+        match component {
+            ObjectIdentifierComponent::NameForm(name) => Self::rust_module_name(name),
+            ObjectIdentifierComponent::NumberForm(number) => format!("oid_{}", number),
+            ObjectIdentifierComponent::NameAndNumberForm(name, number) => {
+                format!("{}_{}", Self::rust_module_name(name), number)
+            }
+        }
+    }
+
+    /// The segments of the module with the given name, which is looked up in the models to find
+    /// its OID
+    fn module_segments_by_name(&self, module: &str) -> Vec<String> {
+        let oid = self
+            .models
+            .iter()
+            .find(|model| model.name == module)
+            .and_then(|model| model.oid.as_ref());
+        self.module_segments(module, oid)
+    }
+
+    /// The path to refer to the module with the given segments from the module with the
+    /// segments `from`, see [`RustCodeGenerator::set_module_root`]
+    fn module_path(&self, from: &[String], to: &[String]) -> String {
+        match &self.module_root {
+            Some(root) => format!("{}::{}", root, to.join("::")),
+            None => format!("{}{}", "super::".repeat(from.len()), to.join("::")),
+        }
+    }
+
     pub fn model_to_file(
         &self,
         model: &Model<Rust>,
         generators: &[&dyn GeneratorSupplement<Rust>],
     ) -> (String, String) {
-        let file = {
-            let mut string = Self::rust_module_name(&model.name);
-            string.push_str(".rs");
-            string
-        };
+        let segments = self.module_segments(&model.name, model.oid.as_ref());
+        let file = format!("{}.rs", segments.join("/"));
 
         let mut scope = Scope::new();
         generators.iter().for_each(|g| g.add_imports(&mut scope));
 
         scope.import("asn1rs::prelude", "*");
         for import in &model.imports {
-            let from = format!("super::{}", &Self::rust_module_name(&import.from));
+            let imported = match self
+                .models
+                .iter()
+                .find(|model| import.is_satisfied_by(model))
+            {
+                Some(model) => self.module_segments(&model.name, model.oid.as_ref()),
+                None => self.module_segments(&import.from, import.from_oid.as_ref()),
+            };
+            let from = self.module_path(&segments, &imported);
             for what in &import.what {
-                scope.import(&from, what);
+                match what.split_once("::") {
+                    // the name of the type collides with another type, so it is referred to by a
+                    // path that is qualified with the name of its module, see
+                    // `Model::<Rust>::convert_asn_to_rust`
+                    Some((qualifier, _)) => {
+                        let (parent, module) = from.rsplit_once("::").unwrap_or(("self", &from));
+                        if module == qualifier {
+                            scope.import(parent, module);
+                        } else {
+                            scope.import(parent, &format!("{} as {}", module, qualifier));
+                        }
+                    }
+                    None => {
+                        scope.import(&from, what);
+                    }
+                }
             }
         }
 
         for vref in &model.value_references {
-            scope.raw(&Self::fmt_const(
-                &vref.name,
-                &vref.role,
-                &vref.value.as_rust_const_literal(true),
-                0,
-            ));
+            match vref.value.as_rust_const_literal(true) {
+                Some(literal) => scope.raw(&Self::fmt_const(
+                    &vref.name,
+                    &vref.role,
+                    &literal,
+                    0,
+                    self.const_constructor(&model.name, &vref.role),
+                )),
+                None => scope.raw(&self.fmt_structured_value(
+                    model,
+                    &vref.name,
+                    &vref.role,
+                    &vref.value,
+                )),
+            };
         }
 
         for definition in &model.definitions {
-            self.add_definition(&mut scope, definition);
-            Self::impl_definition(&mut scope, definition, generators, self.getter_and_setter);
+            self.add_definition_with_generators(&mut scope, definition, generators);
+            self.impl_definition(&mut scope, definition, generators);
+            self.impl_default_and_example(&mut scope, model, definition);
+            if self.builders {
+                self.add_builder(&mut scope, model, definition);
+            }
 
             generators
                 .iter()
@@ -188,27 +633,371 @@ impl RustCodeGenerator {
         (file, scope.to_string())
     }
 
-    fn fmt_const(name: &str, r#type: &RustType, value: &impl Display, indent: usize) -> String {
+    fn fmt_const(
+        name: &str,
+        r#type: &RustType,
+        value: &impl Display,
+        indent: usize,
+        constructor: &str,
+    ) -> String {
         format!(
             "{}pub const {}: {} = {};",
             "    ".repeat(indent),
             name,
             r#type.to_const_lit_string(),
             if let RustType::Complex(..) = r#type {
-                format!("{}::new({})", r#type.to_const_lit_string(), value)
+                format!(
+                    "{}::{}({})",
+                    r#type.to_const_lit_string(),
+                    constructor,
+                    value
+                )
             } else {
                 value.to_string()
             }
         )
     }
 
-    pub fn add_definition(&self, scope: &mut Scope, Definition(name, rust): &Definition<Rust>) {
+    /// The name of the `const fn` that constructs a value of the referenced tuple struct
+    fn const_constructor(&self, module: &str, r#type: &RustType) -> &'static str {
+        let checked = match r#type {
+            RustType::Complex(name, _) => self
+                .find_definition(module, name)
+                .and_then(|(_, rust)| self.checked_integer_range(rust))
+                .is_some(),
+            _ => false,
+        };
+        if checked {
+            "new_unchecked"
+        } else {
+            "new"
+        }
+    }
+
+    /// The inner type and range of a tuple struct that is generated as checked newtype, see
+    /// [`RustCodeGenerator::set_checked_integers`]
+    fn checked_integer_range<'a>(&self, rust: &'a Rust) -> Option<(&'a RustType, Range<String>)> {
+        let r#type = match rust {
+            Rust::TupleStruct { r#type, .. } if self.checked_integers => r#type,
+            _ => return None,
+        };
+        let (type_min, type_max) = Self::integer_type_bounds(r#type)?;
+        r#type
+            .integer_range_str()
+            .filter(|range| !range.extensible())
+            .filter(|range| *range.min() != type_min || *range.max() != type_max)
+            .map(|range| (r#type, range))
+    }
+
+    /// The smallest and largest value of the Rust type of an integer
+    fn integer_type_bounds(r#type: &RustType) -> Option<(String, String)> {
+        Some(match r#type {
+            RustType::I8(_) => (i8::MIN.to_string(), i8::MAX.to_string()),
+            RustType::U8(_) => (u8::MIN.to_string(), u8::MAX.to_string()),
+            RustType::I16(_) => (i16::MIN.to_string(), i16::MAX.to_string()),
+            RustType::U16(_) => (u16::MIN.to_string(), u16::MAX.to_string()),
+            RustType::I32(_) => (i32::MIN.to_string(), i32::MAX.to_string()),
+            RustType::U32(_) => (u32::MIN.to_string(), u32::MAX.to_string()),
+            RustType::I64(_) => (i64::MIN.to_string(), i64::MAX.to_string()),
+            RustType::U64(_) => (u64::MIN.to_string(), u64::MAX.to_string()),
+            _ => return None,
+        })
+    }
+
+    /// Structured values (SEQUENCE, SET and CHOICE values) are emitted as `pub const` if the
+    /// resulting expression can be evaluated at compile time and as lazily initialized `static`
+    /// otherwise (e.g. because a non-empty `String` needs to be allocated). A value that cannot
+    /// be expressed is written as `compile_error!`, see [`RustCodeGenerator::check_values`].
+    fn fmt_structured_value(
+        &self,
+        model: &Model<Rust>,
+        name: &str,
+        r#type: &RustType,
+        value: &LiteralValue,
+    ) -> String {
+        match self.value_expression(name, &model.name, &model.name, r#type, value) {
+            Some((expr, true)) => format!("pub const {}: {} = {};", name, r#type.to_string(), expr),
+            Some((expr, false)) => format!(
+                "{}pub static {}: std::sync::LazyLock<{}> = std::sync::LazyLock::new(|| {});",
+                if expr.contains(CONVERSION_TRAIT) {
+                    "/// # Panics\n\
+                     /// On first access, if a component cannot be converted to its overridden type\n"
+                } else {
+                    ""
+                },
+                name,
+                r#type.to_string(),
+                expr
+            ),
+            None => format!(
+                "compile_error!({:?});",
+                Error::InexpressibleValue(name.to_string(), value.to_string(), r#type.to_string())
+                    .to_string()
+            ),
+        }
+    }
+
+    /// Builds the rust expression for the given value. The boolean indicates whether the
+    /// expression is a valid constant expression. `current_module` is the module the expression
+    /// is written into, `scope_module` the module in which `r#type` has to be looked up. `origin`
+    /// names the value in the panic message of a failed conversion to an overridden type.
+    fn value_expression(
+        &self,
+        origin: &str,
+        current_module: &str,
+        scope_module: &str,
+        r#type: &RustType,
+        value: &LiteralValue,
+    ) -> Option<(String, bool)> {
+        match (r#type, value) {
+            (RustType::Option(inner), value) => self
+                .value_expression(origin, current_module, scope_module, inner, value)
+                .map(|(expr, is_const)| (format!("Some({})", expr), is_const)),
+            (RustType::Default(inner, ..), value) => {
+                self.value_expression(origin, current_module, scope_module, inner, value)
+            }
+            (RustType::Box(inner), value) => self
+                .value_expression(origin, current_module, scope_module, inner, value)
+                .map(|(expr, _)| (format!("Box::new({})", expr), false)),
+            (RustType::Override(inner, type_override), value) => self
+                .value_expression(origin, current_module, scope_module, inner, value)
+                .map(|(expr, _)| {
+                    (
+                        format!(
+                            "<{} as {}<_, {}>>::from_asn({}).unwrap_or_else(|e| panic!(\"Failed to convert {}: {{}}\", e))",
+                            type_override
+                                .conversion()
+                                .unwrap_or(walker::DEFAULT_CONVERSION),
+                            CONVERSION_TRAIT,
+                            type_override.rust_type(),
+                            expr,
+                            origin
+                        ),
+                        false,
+                    )
+                }),
+            (_, LiteralValue::Reference(name)) => Some((
+                self.qualified_path(current_module, scope_module, name),
+                true,
+            )),
+            (RustType::Bool, LiteralValue::Boolean(_))
+            | (RustType::I8(_), LiteralValue::Integer(_))
+            | (RustType::U8(_), LiteralValue::Integer(_))
+            | (RustType::I16(_), LiteralValue::Integer(_))
+            | (RustType::U16(_), LiteralValue::Integer(_))
+            | (RustType::I32(_), LiteralValue::Integer(_))
+            | (RustType::U32(_), LiteralValue::Integer(_))
+            | (RustType::I64(_), LiteralValue::Integer(_))
+            | (RustType::U64(_), LiteralValue::Integer(_)) => {
+                Some((value.as_rust_const_literal(true)?.to_string(), true))
+            }
+            (RustType::String(..), LiteralValue::String(string)) if string.is_empty() => {
+                Some(("String::new()".to_string(), true))
+            }
+            (RustType::String(..), LiteralValue::String(string)) => {
+                Some((format!("String::from({:?})", string), false))
+            }
+            (RustType::VecU8(_), LiteralValue::OctetString(bytes)) if bytes.is_empty() => {
+                Some(("Vec::new()".to_string(), true))
+            }
+            (RustType::VecU8(_), value @ LiteralValue::OctetString(_)) => {
+                Some((format!("vec!{}", value.as_rust_const_literal(true)?), false))
+            }
+            (RustType::ByteArray(size), value @ LiteralValue::OctetString(bytes))
+                if bytes.len() == *size =>
+            {
+                Some((value.as_rust_const_literal(true)?.to_string(), true))
+            }
+            (RustType::BitVec(_), value @ LiteralValue::OctetString(_)) => Some((
+                format!(
+                    "BitVec::from_all_bytes(vec!{})",
+                    value.as_rust_const_literal(true)?
+                ),
+                false,
+            )),
+            // an empty SEQUENCE OF or SET OF value is written as `{}`, like an empty SEQUENCE
+            (RustType::Vec(..), LiteralValue::Sequence(values)) if values.is_empty() => {
+                Some(("Vec::new()".to_string(), true))
+            }
+            (RustType::Array(_, 0), LiteralValue::Sequence(values)) if values.is_empty() => {
+                Some(("[]".to_string(), true))
+            }
+            (RustType::Null, _) => Some(("Null".to_string(), true)),
+            (RustType::Complex(name, _), value) => {
+                let (model, definition) = self.find_definition(scope_module, name)?;
+                let path = self.qualified_path(
+                    current_module,
+                    &model.name,
+                    crate::model::rust::unqualified_type_name(name),
+                );
+                match (definition, value) {
+                    (Rust::Struct { fields, .. }, LiteralValue::Sequence(values)) => {
+                        let mut is_const = true;
+                        let mut exprs = Vec::with_capacity(fields.len());
+                        for field in fields {
+                            let value = values.iter().find_map(|(name, value)| {
+                                if name == field.name()
+                                    || crate::model::rust::rust_field_name(name) == field.name()
+                                {
+                                    Some(value)
+                                } else {
+                                    None
+                                }
+                            });
+                            let (expr, expr_const) = match (value, field.r#type()) {
+                                (Some(value), r#type) => self.value_expression(
+                                    origin,
+                                    current_module,
+                                    &model.name,
+                                    r#type,
+                                    value,
+                                )?,
+                                (None, RustType::Option(_)) => ("None".to_string(), true),
+                                (None, RustType::Default(inner, default)) => self
+                                    .value_expression(
+                                        origin,
+                                        current_module,
+                                        &model.name,
+                                        inner,
+                                        default,
+                                    )?,
+                                (None, r#type @ RustType::Override(inner, _)) => {
+                                    if let RustType::Default(_, default) = inner.as_ref() {
+                                        self.value_expression(
+                                            origin,
+                                            current_module,
+                                            &model.name,
+                                            r#type,
+                                            default,
+                                        )?
+                                    } else {
+                                        return None;
+                                    }
+                                }
+                                (None, _) => return None,
+                            };
+                            is_const &= expr_const;
+                            exprs.push(format!(
+                                "{}: {}",
+                                Self::rust_field_name(field.name(), true),
+                                expr
+                            ));
+                        }
+                        Some((format!("{} {{ {} }}", path, exprs.join(", ")), is_const))
+                    }
+                    (Rust::DataEnum(enumeration), LiteralValue::Choice(variant, value)) => {
+                        let data_variant = enumeration.variants().find(|v| {
+                            v.name() == variant
+                                || v.name() == crate::model::rust::rust_variant_name(variant)
+                        })?;
+                        self.value_expression(
+                            origin,
+                            current_module,
+                            &model.name,
+                            data_variant.r#type(),
+                            value,
+                        )
+                        .map(|(expr, is_const)| {
+                            (
+                                format!(
+                                    "{}::{}({})",
+                                    path,
+                                    Self::rust_variant_name(data_variant.name()),
+                                    expr
+                                ),
+                                is_const,
+                            )
+                        })
+                    }
+                    (Rust::Enum(enumeration), LiteralValue::EnumeratedVariant(_, variant)) => {
+                        let plain_variant = enumeration.variants().find(|v| {
+                            v.name() == variant || v.name() == crate::model::rust::rust_variant_name(variant)
+                        })?;
+                        Some((
+                            format!("{}::{}", path, Self::rust_variant_name(plain_variant.name())),
+                            true,
+                        ))
+                    }
+                    (Rust::TupleStruct { r#type, .. }, value)
+                        if self.checked_integer_range(definition).is_some() =>
+                    {
+                        self.value_expression(origin, current_module, &model.name, r#type, value)
+                            .map(|(expr, is_const)| {
+                                (format!("{}::new_unchecked({})", path, expr), is_const)
+                            })
+                    }
+                    (Rust::TupleStruct { r#type, .. }, value) => self
+                        .value_expression(origin, current_module, &model.name, r#type, value)
+                        .map(|(expr, is_const)| (format!("{}({})", path, expr), is_const)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Searches the definition in the given module first and in all other modules afterwards
+    fn find_definition(&self, module: &str, name: &str) -> Option<(&Model<Rust>, &Rust)> {
+        // a qualified name refers to the definition in the named module
+        let (module, name) = name.split_once("::").unwrap_or((module, name));
+        self.models
+            .iter()
+            .filter(|model| model.name == module)
+            .chain(self.models.iter().filter(|model| model.name != module))
+            .find_map(|model| {
+                model
+                    .definitions
+                    .iter()
+                    .find(|definition| definition.0 == name)
+                    .map(|definition| (model, &definition.1))
+            })
+    }
+
+    fn qualified_path(&self, current_module: &str, module: &str, name: &str) -> String {
+        if current_module == module {
+            name.to_string()
+        } else {
+            format!(
+                "{}::{}",
+                self.module_path(
+                    &self.module_segments_by_name(current_module),
+                    &self.module_segments_by_name(module)
+                ),
+                name
+            )
+        }
+    }
+
+    pub fn add_definition(&self, scope: &mut Scope, definition: &Definition<Rust>) {
+        self.add_definition_with_generators(scope, definition, &[])
+    }
+
+    pub fn add_definition_with_generators(
+        &self,
+        scope: &mut Scope,
+        definition: &Definition<Rust>,
+        generators: &[&dyn GeneratorSupplement<Rust>],
+    ) {
+        let Definition(name, rust) = definition;
+        let attributes = |index: usize| {
+            generators
+                .iter()
+                .flat_map(|g| g.attributes_of_member(definition, index))
+                .map(|attribute| format!("{} ", attribute))
+                .collect::<String>()
+        };
+        if let Some(comment) = rust.comments().definition() {
+            scope.raw(&Self::doc_comment(comment));
+        }
         match rust {
             Rust::Struct {
                 fields,
                 tag,
                 extension_after,
                 ordering,
+                comments,
+                sources: _,
+                asn_name: _,
             } => {
                 scope.raw(&Self::asn_attribute(
                     match ordering {
@@ -223,6 +1012,8 @@ impl RustCodeGenerator {
                     self.new_struct(scope, name),
                     name,
                     fields,
+                    comments,
+                    &attributes,
                     self.direct_field_access,
                 )
             }
@@ -230,13 +1021,16 @@ impl RustCodeGenerator {
                 scope.raw(&Self::asn_attribute(
                     "enumerated",
                     plain.tag(),
-                    plain.extension_after_variant().cloned(),
+                    plain
+                        .extension_after_variant()
+                        .map(|v| v.name().to_string()),
                     &[],
                 ));
                 Self::add_enum(
                     self.new_enum(scope, name, true).derive("Default"),
                     name,
                     plain,
+                    &attributes,
                 )
             }
             Rust::DataEnum(data) => {
@@ -246,37 +1040,50 @@ impl RustCodeGenerator {
                     data.extension_after_variant().map(|v| v.name().to_string()),
                     &[],
                 ));
-                Self::add_data_enum(self.new_enum(scope, name, false), name, data)
+                Self::add_data_enum(self.new_enum(scope, name, false), name, data, &attributes)
             }
             Rust::TupleStruct {
                 r#type,
                 tag,
                 constants,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => {
                 scope.raw(&Self::asn_attribute("transparent", *tag, None, &[]));
                 Self::add_tuple_struct(
                     self.new_struct(scope, name),
                     name,
                     r#type,
-                    self.direct_field_access,
+                    self.direct_field_access && self.checked_integer_range(rust).is_none(),
                     None,
                     &constants[..],
+                    &attributes(0),
                 )
             }
         }
     }
 
-    fn add_struct(str_ct: &mut Struct, _name: &str, fields: &[Field], pub_access: bool) {
-        for field in fields {
+    fn add_struct(
+        str_ct: &mut Struct,
+        _name: &str,
+        fields: &[Field],
+        comments: &Comments,
+        attributes: &dyn Fn(usize) -> String,
+        pub_access: bool,
+    ) {
+        for (index, field) in fields.iter().enumerate() {
             str_ct.field(
                 &format!(
-                    "{} {}{}",
+                    "{}{} {}{}{}",
+                    Self::doc_comment_prefix(comments.member(index)),
                     Self::asn_attribute(
-                        Self::asn_attribute_type(&field.r#type().clone().into_asn()),
+                        Self::asn_attribute_member_type(field.r#type()),
                         field.tag(),
                         None,
                         field.constants(),
                     ),
+                    attributes(index),
                     if pub_access { "pub " } else { "" },
                     Self::rust_field_name(field.name(), true),
                 ),
@@ -285,28 +1092,48 @@ impl RustCodeGenerator {
         }
     }
 
-    fn add_enum(en_m: &mut Enum, _name: &str, rust_enum: &PlainEnum) {
+    fn add_enum(
+        en_m: &mut Enum,
+        _name: &str,
+        rust_enum: &PlainEnum,
+        attributes: &dyn Fn(usize) -> String,
+    ) {
         for (index, variant) in rust_enum.variants().enumerate() {
-            let name = Self::rust_variant_name(variant);
+            let name = format!(
+                "{}{}",
+                attributes(index),
+                Self::rust_variant_name(variant.name())
+            );
             let name = if index == 0 {
                 format!("#[default] {name}")
             } else {
                 name
             };
-            en_m.new_variant(&name);
+            en_m.new_variant(&format!(
+                "{}{}",
+                Self::doc_comment_prefix(rust_enum.comments().member(index)),
+                name
+            ));
         }
     }
 
-    fn add_data_enum(en_m: &mut Enum, _name: &str, enumeration: &DataEnum) {
-        for variant in enumeration.variants() {
+    fn add_data_enum(
+        en_m: &mut Enum,
+        _name: &str,
+        enumeration: &DataEnum,
+        attributes: &dyn Fn(usize) -> String,
+    ) {
+        for (index, variant) in enumeration.variants().enumerate() {
             en_m.new_variant(&format!(
-                "{} {}({})",
+                "{}{} {}{}({})",
+                Self::doc_comment_prefix(enumeration.comments().member(index)),
                 Self::asn_attribute(
-                    Self::asn_attribute_type(&variant.r#type().clone().into_asn()),
+                    Self::asn_attribute_member_type(variant.r#type()),
                     variant.tag(),
                     None,
                     &[],
                 ),
+                attributes(index),
                 Self::rust_variant_name(variant.name()),
                 variant.r#type().to_string(),
             ));
@@ -320,20 +1147,37 @@ impl RustCodeGenerator {
         pub_access: bool,
         tag: Option<Tag>,
         constants: &[(String, String)],
+        attributes: &str,
     ) {
         str_ct.tuple_field(format!(
-            "{} {}{}",
-            Self::asn_attribute(
-                Self::asn_attribute_type(&inner.clone().into_asn()),
-                tag,
-                None,
-                constants,
-            ),
+            "{} {}{}{}",
+            Self::asn_attribute(Self::asn_attribute_member_type(inner), tag, None, constants),
+            attributes,
             if pub_access { "pub " } else { "" },
             inner.to_string(),
         ));
     }
 
+    fn doc_comment(comment: &str) -> String {
+        comment
+            .lines()
+            .map(|line| {
+                if line.is_empty() {
+                    "///".to_string()
+                } else {
+                    format!("/// {}", line)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn doc_comment_prefix(comment: Option<&str>) -> String {
+        comment
+            .map(|comment| format!("{}\n", Self::doc_comment(comment)))
+            .unwrap_or_default()
+    }
+
     fn asn_attribute<T: ToString>(
         r#type: T,
         tag: Option<Tag>,
@@ -366,6 +1210,27 @@ impl RustCodeGenerator {
         )
     }
 
+    /// The type of a field, variant or inner type for the attribute, followed by `mapped` if
+    /// the type is overridden, see [`TypeOverride`]
+    fn asn_attribute_member_type(r#type: &RustType) -> String {
+        fn find_override(r#type: &RustType) -> Option<&TypeOverride> {
+            match r#type {
+                RustType::Option(inner) | RustType::Vec(inner, ..) | RustType::Array(inner, _) => {
+                    find_override(inner)
+                }
+                RustType::Override(_, type_override) => Some(type_override),
+                _ => None,
+            }
+        }
+
+        let asn_type = Self::asn_attribute_type(&r#type.clone().into_asn());
+        match find_override(r#type).map(TypeOverride::conversion) {
+            Some(Some(conversion)) => format!("{}, mapped({})", asn_type, conversion),
+            Some(None) => format!("{}, mapped", asn_type),
+            None => asn_type,
+        }
+    }
+
     fn asn_attribute_type(r#type: &AsnType) -> String {
         let (name, parameters) = match r#type {
             Type::Boolean => (Cow::Borrowed("boolean"), Vec::default()),
@@ -392,12 +1257,17 @@ impl RustCodeGenerator {
                     }
                 )],
             ),
-            Type::String(size, charset) => (
+            Type::String(size, charset, alphabet) => (
                 Cow::Owned(format!("{:?}string", charset).to_lowercase()),
-                vec![size.to_constraint_string()]
-                    .into_iter()
-                    .flatten()
-                    .collect(),
+                vec![
+                    size.to_constraint_string(),
+                    alphabet
+                        .as_ref()
+                        .map(|alphabet| format!("from({:?})", alphabet)),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
             ),
             Type::OctetString(size) => (
                 Cow::Borrowed("octet_string"),
@@ -414,6 +1284,7 @@ impl RustCodeGenerator {
                     .collect()],
             ),
             Type::Null => (Cow::Borrowed("null"), Vec::default()),
+            Type::ObjectIdentifier => unreachable!("OBJECT IDENTIFIER has no rust field type"),
             Type::Optional(inner) => (
                 Cow::Borrowed("optional"),
                 vec![Self::asn_attribute_type(inner)],
@@ -422,7 +1293,7 @@ impl RustCodeGenerator {
                 Cow::Borrowed("default"),
                 vec![
                     Self::asn_attribute_type(inner),
-                    default.as_rust_const_literal(true).to_string(),
+                    default.to_rust_const_literal_string(true),
                 ],
             ),
             Type::SequenceOf(inner, size) => (
@@ -457,6 +1328,7 @@ impl RustCodeGenerator {
                     .flatten()
                     .collect(),
             ),
+            Type::ConstrainedTypeReference(_, applied) => match *applied {},
         };
         if parameters.is_empty() {
             name.into_owned()
@@ -479,10 +1351,10 @@ impl RustCodeGenerator {
     }
 
     fn impl_definition(
+        &self,
         scope: &mut Scope,
         Definition(name, rust): &Definition<Rust>,
         generators: &[&dyn GeneratorSupplement<Rust>],
-        getter_and_setter: bool,
     ) {
         match rust {
             Rust::Struct {
@@ -490,6 +1362,9 @@ impl RustCodeGenerator {
                 tag: _,
                 extension_after: _,
                 ordering: _,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => {
                 Self::impl_consts(
                     scope,
@@ -498,7 +1373,7 @@ impl RustCodeGenerator {
                         .iter()
                         .map(|f| (f.name_type.0.as_str(), &f.name_type.1, &f.constants[..])),
                 );
-                let implementation = Self::impl_struct(scope, name, fields, getter_and_setter);
+                let implementation = Self::impl_struct(scope, name, fields, self.getter_and_setter);
                 for g in generators {
                     g.extend_impl_of_struct(name, implementation, fields);
                 }
@@ -514,12 +1389,29 @@ impl RustCodeGenerator {
                 for g in generators {
                     g.extend_impl_of_data_enum(name, implementation, enumeration);
                 }
-                Self::impl_data_enum_default(scope, name, enumeration);
+            }
+            Rust::TupleStruct {
+                r#type: inner,
+                constants,
+                ..
+            } if self.checked_integer_range(rust).is_some() => {
+                let (_, range) = self.checked_integer_range(rust).unwrap();
+                Self::impl_checked_integer_consts(scope, name, &range, constants);
+                let implementation = Self::impl_tuple_struct(scope, name, inner);
+                for g in generators {
+                    g.extend_impl_of_tuple(name, implementation, inner);
+                }
+                Self::impl_checked_integer(scope, name, inner, &range);
+                Self::impl_tuple_struct_deref(scope, name, inner);
+                Self::impl_checked_integer_try_from(scope, name, inner);
             }
             Rust::TupleStruct {
                 r#type: inner,
                 tag: _,
                 constants,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => {
                 Self::impl_consts(scope, name, Some(("", inner, &constants[..])).into_iter());
                 let implementation = Self::impl_tuple_struct(scope, name, inner);
@@ -534,14 +1426,462 @@ impl RustCodeGenerator {
         }
     }
 
-    fn impl_tuple_struct_const_new(scope: &mut Scope, name: &str, rust: &RustType) {
+    /// Implements `Default` with the `DEFAULT` value of each field or otherwise the smallest
+    /// valid value (lower bound of the range, minimum size and first variant) and `example()`,
+    /// which additionally sets all optional fields (that are not recursive)
+    fn impl_default_and_example(
+        &self,
+        scope: &mut Scope,
+        model: &Model<Rust>,
+        Definition(name, rust): &Definition<Rust>,
+    ) {
+        let value = |example: bool| match rust {
+            Rust::Struct { fields, .. } => format!(
+                "Self {{ {} }}",
+                fields
+                    .iter()
+                    .map(|field| format!(
+                        "{}: {}",
+                        Self::rust_field_name(field.name(), true),
+                        self.default_expression(model, field.r#type(), example)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Rust::Enum(plain) => format!(
+                "Self::{}",
+                Self::rust_variant_name(plain.variants().next().unwrap().name())
+            ),
+            Rust::DataEnum(enumeration) => {
+                let variant = enumeration.variants().next().unwrap();
+                format!(
+                    "Self::{}({})",
+                    Self::rust_variant_name(variant.name()),
+                    self.default_expression(model, variant.r#type(), example)
+                )
+            }
+            Rust::TupleStruct { r#type, .. } => {
+                format!("Self({})", self.default_expression(model, r#type, example))
+            }
+        };
+
+        if !matches!(rust, Rust::Enum(_)) {
+            scope
+                .new_impl(name)
+                .impl_trait("Default")
+                .new_fn("default")
+                .ret("Self")
+                .line(value(false));
+        }
         scope
             .new_impl(name)
-            .new_fn("new")
-            .vis("pub const")
-            .arg("value", rust.to_string())
+            .new_fn("example")
+            .doc("A valid value with all optional fields set")
+            .vis("pub")
+            .ret("Self")
+            .line(value(true));
+    }
+
+    /// The expression for the default value of the type, see
+    /// [`RustCodeGenerator::impl_default_and_example`]
+    fn default_expression(&self, model: &Model<Rust>, r#type: &RustType, example: bool) -> String {
+        let min_size = |size: &Size| size.min().copied().unwrap_or_default();
+        match r#type {
+            RustType::Bool => "false".to_string(),
+            RustType::I8(_)
+            | RustType::U8(_)
+            | RustType::I16(_)
+            | RustType::U16(_)
+            | RustType::I32(_)
+            | RustType::U32(_)
+            | RustType::I64(_)
+            | RustType::U64(_) => Self::integer_default(r#type),
+            RustType::String(size, ..) if min_size(size) == 0 => "String::new()".to_string(),
+            // '0' is valid for all charsets
+            RustType::String(size, _, None) => {
+                format!("String::from({:?})", "0".repeat(min_size(size)))
+            }
+            RustType::String(size, _, Some(alphabet)) => format!(
+                "String::from({:?})",
+                alphabet
+                    .chars()
+                    .take(1)
+                    .collect::<String>()
+                    .repeat(min_size(size))
+            ),
+            RustType::VecU8(size) if min_size(size) == 0 => "Vec::new()".to_string(),
+            RustType::VecU8(size) => format!("vec![0; {}]", min_size(size)),
+            RustType::BitVec(size) => format!("BitVec::with_len({})", min_size(size)),
+            RustType::Vec(_, size, _) if min_size(size) == 0 => "Vec::new()".to_string(),
+            RustType::Vec(inner, size, _) => format!(
+                "vec![{}; {}]",
+                self.default_expression(model, inner, example),
+                min_size(size)
+            ),
+            RustType::ByteArray(size) => format!("[0; {}]", size),
+            RustType::Array(inner, _) => format!(
+                "::core::array::from_fn(|_| {})",
+                self.default_expression(model, inner, example)
+            ),
+            RustType::Null => "Null".to_string(),
+            RustType::Option(inner) if example && !matches!(inner.as_ref(), RustType::Box(_)) => {
+                format!("Some({})", self.default_expression(model, inner, example))
+            }
+            RustType::Option(_) => "None".to_string(),
+            RustType::Default(inner, _) => self
+                .default_value_expression(model, r#type)
+                .unwrap_or_else(|| self.default_expression(model, inner, example)),
+            RustType::Box(inner) => {
+                format!(
+                    "Box::new({})",
+                    self.default_expression(model, inner, example)
+                )
+            }
+            RustType::Override(..) => self
+                .default_value_expression(model, r#type)
+                .unwrap_or_else(|| "Default::default()".to_string()),
+            RustType::Complex(name, _) if example => format!("{}::example()", name),
+            RustType::Complex(..) => "Default::default()".to_string(),
+        }
+    }
+
+    /// The lower bound of the range of an integer, or `0` if the range is unbounded below and
+    /// includes `0`
+    fn integer_default(r#type: &RustType) -> String {
+        let range = r#type.integer_range_str().unwrap();
+        let unbounded = Self::integer_type_bounds(r#type)
+            .map(|(type_min, _)| type_min == *range.min())
+            .unwrap_or_default();
+        if unbounded && !range.max().starts_with('-') {
+            "0".to_string()
+        } else {
+            Self::format_number_nicely(range.min())
+        }
+    }
+
+    fn add_builder(
+        &self,
+        scope: &mut Scope,
+        model: &Model<Rust>,
+        Definition(name, rust): &Definition<Rust>,
+    ) {
+        let fields = match rust {
+            Rust::Struct { fields, .. } => fields,
+            _ => return,
+        };
+        let builder = format!("{}Builder", name);
+        let members = fields
+            .iter()
+            .map(|field| {
+                let kind = match field.r#type() {
+                    RustType::Option(inner) => BuilderField::Optional(inner.to_string()),
+                    r#type => match self.default_value_expression(model, r#type) {
+                        Some(default) => BuilderField::Default(default),
+                        None => BuilderField::Mandatory,
+                    },
+                };
+                (
+                    Self::rust_field_name(field.name(), true),
+                    field.r#type(),
+                    kind,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let str_ct = scope
+            .new_struct(&builder)
+            .doc(&format!(
+                "Builder for [`{}`], see [`{}::builder`]",
+                name, name
+            ))
+            .vis("pub")
+            .derive("Debug")
+            .derive("Clone")
+            .derive("PartialEq");
+        for (field, r#type, kind) in &members {
+            str_ct.field(
+                field,
+                match kind {
+                    BuilderField::Mandatory => format!("Option<{}>", r#type.to_string()),
+                    _ => r#type.to_string(),
+                },
+            );
+        }
+
+        let implementation = scope.new_impl(&builder);
+        let new = implementation.new_fn("new").vis("pub").ret("Self");
+        new.line("Self {");
+        for (field, _, kind) in &members {
+            match kind {
+                BuilderField::Default(default) => new.line(format!("    {}: {},", field, default)),
+                _ => new.line(format!("    {}: None,", field)),
+            };
+        }
+        new.line("}");
+
+        for (field, r#type, kind) in &members {
+            let (argument, value) = match kind {
+                BuilderField::Default(_) => (r#type.to_string(), "value"),
+                BuilderField::Optional(inner) => (inner.clone(), "Some(value)"),
+                BuilderField::Mandatory => (r#type.to_string(), "Some(value)"),
+            };
+            implementation
+                .new_fn(field)
+                .vis("pub")
+                .arg_self()
+                .arg("value", argument)
+                .ret("Self")
+                .line(if members.len() > 1 {
+                    format!("Self {{ {}: {}, ..self }}", field, value)
+                } else {
+                    format!("Self {{ {}: {} }}", field, value)
+                });
+        }
+
+        let mandatory = members
+            .iter()
+            .filter(|(_, _, kind)| matches!(kind, BuilderField::Mandatory))
+            .map(|(field, _, _)| field.as_str())
+            .collect::<Vec<_>>();
+        let fields = members
+            .iter()
+            .map(|(field, _, kind)| match kind {
+                BuilderField::Mandatory => field.clone(),
+                _ => format!("{}: self.{}", field, field),
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let build = implementation
+            .new_fn("build")
+            .doc("Fails if a mandatory field is not set")
+            .vis("pub")
+            .arg_self()
+            .ret(format!(
+                "Result<{}, ::asn1rs::syn::sequence::MissingFields>",
+                name
+            ));
+        if mandatory.is_empty() {
+            build.line(format!("Ok({} {{ {} }})", name, fields));
+        } else {
+            build.line("let mut missing = Vec::new();");
+            for field in &mandatory {
+                build.line(format!("if self.{}.is_none() {{", field));
+                build.line(format!("    missing.push({:?});", field));
+                build.line("}");
+            }
+            let (pattern, values) = if let [field] = &mandatory[..] {
+                (format!("Some({})", field), format!("self.{}", field))
+            } else {
+                (
+                    format!(
+                        "({})",
+                        mandatory
+                            .iter()
+                            .map(|field| format!("Some({})", field))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    format!(
+                        "({})",
+                        mandatory
+                            .iter()
+                            .map(|field| format!("self.{}", field))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                )
+            };
+            build.line(format!("if let {} = {} {{", pattern, values));
+            build.line(format!("    Ok({} {{ {} }})", name, fields));
+            build.line("} else {");
+            build.line("    Err(::asn1rs::syn::sequence::MissingFields {");
+            build.line(format!("        r#type: {:?},", name));
+            build.line("        fields: missing,");
+            build.line("    })");
+            build.line("}");
+        }
+
+        scope
+            .new_impl(&builder)
+            .impl_trait("Default")
+            .new_fn("default")
+            .ret("Self")
+            .line("Self::new()");
+        scope
+            .new_impl(name)
+            .new_fn("builder")
+            .vis("pub")
+            .ret(&builder)
+            .line(format!("{}::new()", builder));
+    }
+
+    /// The expression for the `DEFAULT` value of a field, if it has one
+    fn default_value_expression(&self, model: &Model<Rust>, r#type: &RustType) -> Option<String> {
+        let default = match r#type {
+            RustType::Default(_, default) => default,
+            RustType::Override(inner, _) => match inner.as_ref() {
+                RustType::Default(_, default) => default,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.value_expression("a DEFAULT value", &model.name, &model.name, r#type, default)
+            .map(|(expr, _)| expr)
+    }
+
+    fn impl_tuple_struct_const_new(scope: &mut Scope, name: &str, rust: &RustType) {
+        scope
+            .new_impl(name)
+            .new_fn("new")
+            .vis("pub const")
+            .arg("value", rust.to_string())
+            .ret("Self")
+            .line("Self(value)");
+    }
+
+    fn impl_checked_integer_consts(
+        scope: &mut Scope,
+        name: &str,
+        range: &Range<String>,
+        constants: &[(String, String)],
+    ) {
+        scope.raw(&format!("impl {} {{", name));
+        scope.raw(&format!(
+            "    pub const MIN: Self = Self({});",
+            Self::format_number_nicely(range.min())
+        ));
+        scope.raw(&format!(
+            "    pub const MAX: Self = Self({});",
+            Self::format_number_nicely(range.max())
+        ));
+        for (name, value) in constants {
+            if !Self::is_within_range(range, value) {
+                scope.raw(&format!(
+                    "    // {} is not generated, the value {} is not within {}..={}",
+                    name,
+                    value,
+                    range.min(),
+                    range.max()
+                ));
+            } else if name == "MIN" || name == "MAX" {
+                // the bounds of the range already occupy these names
+                scope.raw(&format!(
+                    "    /// The named number `{}`, renamed to not collide with `Self::{}`",
+                    name.to_lowercase(),
+                    name
+                ));
+                scope.raw(&format!(
+                    "    pub const {}_NUMBER: Self = Self({});",
+                    name, value
+                ));
+            } else {
+                scope.raw(&format!("    pub const {}: Self = Self({});", name, value));
+            }
+        }
+        scope.raw("}");
+    }
+
+    /// Whether the integer value is within the range, values that are not numbers (such as
+    /// references) are checked by the compiler
+    fn is_within_range(range: &Range<String>, value: &str) -> bool {
+        match (
+            range.min().parse::<i128>(),
+            range.max().parse::<i128>(),
+            value.parse::<i128>(),
+        ) {
+            (Ok(min), Ok(max), Ok(value)) => (min..=max).contains(&value),
+            _ => true,
+        }
+    }
+
+    fn impl_checked_integer(scope: &mut Scope, name: &str, rust: &RustType, range: &Range<String>) {
+        // comparing against the bound of the type itself is always false
+        let (type_min, type_max) = Self::integer_type_bounds(rust).unwrap();
+        let mut out_of_range = Vec::with_capacity(2);
+        if *range.min() != type_min {
+            out_of_range.push("value < Self::MIN.0");
+        }
+        if *range.max() != type_max {
+            out_of_range.push("value > Self::MAX.0");
+        }
+        let rust = rust.to_string();
+        let implementation = scope.new_impl(name);
+        implementation
+            .new_fn("new")
+            .doc("Fails if the value is not within `MIN..=MAX`")
+            .vis("pub const")
+            .arg("value", &rust)
+            .ret(format!(
+                "Result<Self, ::asn1rs::syn::numbers::OutOfRange<{}>>",
+                rust
+            ))
+            .line(format!("if {} {{", out_of_range.join(" || ")))
+            .line("    Err(::asn1rs::syn::numbers::OutOfRange {")
+            .line("        value,")
+            .line("        min: Self::MIN.0,")
+            .line("        max: Self::MAX.0,")
+            .line("    })")
+            .line("} else {")
+            .line("    Ok(Self(value))")
+            .line("}");
+        implementation
+            .new_fn("new_unchecked")
+            .doc("Does not validate the value, a value outside of `MIN..=MAX` fails to be written")
+            .vis("pub const")
+            .arg("value", &rust)
             .ret("Self")
             .line("Self(value)");
+        implementation
+            .new_fn("value")
+            .vis("pub const")
+            .arg_ref_self()
+            .ret(&rust)
+            .line("self.0");
+        for operation in &["add", "sub", "mul"] {
+            implementation
+                .new_fn(&format!("checked_{}", operation))
+                .doc("`None` on overflow or if the result is not within `MIN..=MAX`")
+                .vis("pub")
+                .arg_ref_self()
+                .arg("rhs", &rust)
+                .ret("Option<Self>")
+                .line(format!(
+                    "self.0.checked_{}(rhs).and_then(|value| Self::new(value).ok())",
+                    operation
+                ));
+            implementation
+                .new_fn(&format!("saturating_{}", operation))
+                .doc("Saturates at `MIN` and `MAX`")
+                .vis("pub")
+                .arg_ref_self()
+                .arg("rhs", &rust)
+                .ret("Self")
+                .line(format!(
+                    "Self(self.0.saturating_{}(rhs).clamp(Self::MIN.0, Self::MAX.0))",
+                    operation
+                ));
+        }
+    }
+
+    fn impl_checked_integer_try_from(scope: &mut Scope, name: &str, rust: &RustType) {
+        scope
+            .new_impl(name)
+            .impl_trait(format!("::core::convert::TryFrom<{}>", rust.to_string()))
+            .associate_type(
+                "Error",
+                format!("::asn1rs::syn::numbers::OutOfRange<{}>", rust.to_string()),
+            )
+            .new_fn("try_from")
+            .arg("value", rust.to_string())
+            .ret("Result<Self, Self::Error>")
+            .line("Self::new(value)");
+        scope
+            .new_impl(&rust.to_string())
+            .impl_trait(format!("::core::convert::From<{}>", name))
+            .new_fn("from")
+            .arg("value", name)
+            .ret("Self")
+            .line("value.0");
     }
 
     fn impl_tuple_struct_deref(scope: &mut Scope, name: &str, rust: &RustType) {
@@ -629,6 +1969,7 @@ impl RustCodeGenerator {
                     r#type,
                     value,
                     1,
+                    "new",
                 ));
             }
         }
@@ -697,7 +2038,7 @@ impl RustCodeGenerator {
                 "{} => Some({}::{}),",
                 index,
                 name,
-                Self::rust_variant_name(variant)
+                Self::rust_variant_name(variant.name())
             ));
         }
         block_match.line("_ => None,");
@@ -712,7 +2053,11 @@ impl RustCodeGenerator {
             .line("[");
 
         for variant in r_enum.variants() {
-            values_fn.line(format!("{}::{},", name, Self::rust_variant_name(variant)));
+            values_fn.line(format!(
+                "{}::{},",
+                name,
+                Self::rust_variant_name(variant.name())
+            ));
         }
         values_fn.line("]");
     }
@@ -732,7 +2077,7 @@ impl RustCodeGenerator {
                 block.line(format!(
                     "{}::{} => {},",
                     name,
-                    Self::rust_variant_name(variant),
+                    Self::rust_variant_name(variant.name()),
                     ordinal
                 ));
             });
@@ -802,19 +2147,6 @@ impl RustCodeGenerator {
         ordinal_fn.push_block(block);
     }
 
-    fn impl_data_enum_default(scope: &mut Scope, name: &str, enumeration: &DataEnum) {
-        scope
-            .new_impl(name)
-            .impl_trait("Default")
-            .new_fn("default")
-            .ret(name as &str)
-            .line(format!(
-                "{}::{}(Default::default())",
-                name,
-                Self::rust_variant_name(enumeration.variants().next().unwrap().name())
-            ));
-    }
-
     fn add_min_max_fn_if_applicable(
         implementation: &mut Impl,
         field_name: Option<&str>,
@@ -915,7 +2247,6 @@ impl RustCodeGenerator {
         let str_ct = scope
             .new_struct(name)
             .vis("pub")
-            .derive("Default")
             .derive("Debug")
             .derive("Clone")
             .derive("PartialEq")
@@ -944,6 +2275,72 @@ impl RustCodeGenerator {
     }
 }
 
+/// The supplements added with [`RustCodeGenerator::add_supplement`]
+#[derive(Default)]
+struct Supplements(Vec<Box<dyn GeneratorSupplement<Rust>>>);
+
+impl std::fmt::Debug for Supplements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Supplements({})", self.0.len())
+    }
+}
+
+/// How a field is set on a builder, see [`RustCodeGenerator::set_builders`]
+enum BuilderField {
+    Mandatory,
+    /// The inner type
+    Optional(String),
+    /// The expression of the default value
+    Default(String),
+}
+
+/// Replaces the type of all references to a definition, see
+/// [`RustCodeGenerator::add_type_override`]
+struct OverrideDefinitionType<'a>(&'a str, &'a TypeOverride);
+
+impl RustVisitMut for OverrideDefinitionType<'_> {
+    fn visit_value_reference_mut(&mut self, _value_reference: &mut ValueReference<RustType>) {
+        // values keep the generated type
+    }
+
+    fn visit_rust_type_mut(&mut self, r#type: &mut RustType) {
+        match r#type {
+            RustType::Complex(name, _) if name == self.0 => r#type.override_with(self.1.clone()),
+            // recursive and already overridden types keep their type
+            RustType::Box(_) | RustType::Override(..) => {}
+            _ => rust_visit::visit_rust_type_mut(self, r#type),
+        }
+    }
+}
+
+/// Converts between `Vec`s and arrays of a fixed size, see
+/// [`RustCodeGenerator::set_fixed_size_arrays`]
+struct FixedSizeArrays(bool);
+
+impl RustVisitMut for FixedSizeArrays {
+    fn visit_value_reference_mut(&mut self, _value_reference: &mut ValueReference<RustType>) {
+        // values keep the generated type
+    }
+
+    fn visit_rust_type_mut(&mut self, r#type: &mut RustType) {
+        match r#type {
+            // default values and user types keep their type
+            RustType::Default(..) | RustType::Override(..) => return,
+            _ => rust_visit::visit_rust_type_mut(self, r#type),
+        }
+        let current = std::mem::replace(r#type, RustType::Null);
+        *r#type = match current {
+            RustType::VecU8(Size::Fix(size, _)) | RustType::Vec(_, Size::Fix(size, _), _)
+                if size > MAX_FIXED_SIZE_ARRAY_LEN =>
+            {
+                current
+            }
+            current if self.0 => current.into_fixed_size_array(),
+            current => current.into_vec(),
+        };
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
@@ -980,7 +2377,7 @@ pub(crate) mod tests {
             use asn1rs::prelude::*;
             
             #[asn(sequence)]
-            #[derive(Default, Debug, Clone, PartialEq, Hash)]
+            #[derive(Debug, Clone, PartialEq, Hash)]
             pub struct MyStruct {
                 #[asn(integer(0..255), const(APPLE(8), BANANA(9)))] pub item: u8,
             }
@@ -995,6 +2392,338 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    pub fn test_type_overrides() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"Overrides DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            TimestampIts ::= INTEGER (0..4398046511103)
+
+            Station ::= SEQUENCE {
+                mac OCTET STRING (SIZE(6)),
+                seen TimestampIts OPTIONAL,
+                history SEQUENCE OF TimestampIts
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+        generator.add_type_override("TimestampIts", TypeOverride::new("crate::Time"));
+        generator.add_field_type_override(
+            "Station",
+            "mac",
+            TypeOverride::new("[u8; 6]").with_conversion("crate::MacAddress"),
+        );
+        let (_file_name, file_content) = generator
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert!(file_content
+            .contains("pub struct TimestampIts(#[asn(integer(0..4398046511103))] pub u64);"));
+        assert!(file_content.contains(
+            "#[asn(octet_string(size(6)), mapped(crate::MacAddress))] pub mac: [u8; 6],"
+        ));
+        assert!(file_content.contains(
+            "#[asn(optional(complex(TimestampIts, tag(UNIVERSAL(2)))), mapped)] pub seen: Option<crate::Time>,"
+        ));
+        assert!(file_content.contains(
+            "#[asn(sequence_of(complex(TimestampIts, tag(UNIVERSAL(2)))), mapped)] pub history: Vec<crate::Time>,"
+        ));
+    }
+
+    #[test]
+    pub fn test_overridden_value_constant_names_the_constant_on_panic() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"Overrides DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Station ::= SEQUENCE {
+                mac OCTET STRING (SIZE(6))
+            }
+
+            home Station ::= { mac '001B44113AB7'H }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+        generator.add_field_type_override(
+            "Station",
+            "mac",
+            TypeOverride::new("[u8; 6]").with_conversion("crate::MacAddress"),
+        );
+        let (_file_name, file_content) = generator
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert!(file_content.contains(
+            "/// # Panics\n\
+             /// On first access, if a component cannot be converted to its overridden type\n\
+             pub static HOME: std::sync::LazyLock<Station> = std::sync::LazyLock::new(|| Station { mac: \
+             <crate::MacAddress as ::asn1rs::syn::mapped::Conversion<_, [u8; 6]>>::from_asn(vec![0x00, 0x1b, 0x44, 0x11, 0x3a, 0xb7, ])\
+             .unwrap_or_else(|e| panic!(\"Failed to convert HOME: {}\", e)) });"
+        ), "{}", file_content);
+    }
+
+    #[test]
+    pub fn test_fixed_size_arrays() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"Arrays DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Mac ::= OCTET STRING (SIZE(6))
+
+            Frame ::= SEQUENCE {
+                id OCTET STRING (SIZE(8)),
+                position SEQUENCE (SIZE(3)) OF INTEGER (-100..100),
+                pairs SEQUENCE (SIZE(2)) OF OCTET STRING (SIZE(2)),
+                extensible OCTET STRING (SIZE(4, ...)),
+                ranged OCTET STRING (SIZE(1..4)),
+                large OCTET STRING (SIZE(33)),
+                defaulted OCTET STRING (SIZE(1)) DEFAULT '00'H
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+        generator.set_fixed_size_arrays(true);
+        let (_file_name, file_content) = generator
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert!(file_content.contains("pub struct Mac(#[asn(octet_string(size(6)))] pub [u8; 6]);"));
+        assert!(file_content.contains("#[asn(octet_string(size(8)))] pub id: [u8; 8],"));
+        assert!(file_content
+            .contains("#[asn(sequence_of(size(3), integer(-100..100)))] pub position: [i8; 3],"));
+        assert!(file_content.contains(
+            "#[asn(sequence_of(size(2), octet_string(size(2))))] pub pairs: [[u8; 2]; 2],"
+        ));
+        assert!(file_content.contains("pub extensible: Vec<u8>,"));
+        assert!(file_content.contains("pub ranged: Vec<u8>,"));
+        assert!(file_content.contains("pub large: Vec<u8>,"));
+        assert!(file_content.contains("pub defaulted: Vec<u8>,"));
+
+        generator.set_fixed_size_arrays(false);
+        let (_file_name, file_content) = generator
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+        assert!(file_content.contains("#[asn(octet_string(size(8)))] pub id: Vec<u8>,"));
+        assert!(file_content.contains("pub pairs: Vec<Vec<u8>>,"));
+    }
+
+    #[test]
+    pub fn test_checked_integers() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"Checked DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            SpeedValue ::= INTEGER { standstill(0), unavailable(16383) } (0..16383)
+
+            Beyond ::= INTEGER { within(200), beyond(201) } (0..200)
+
+            Unchecked ::= INTEGER (0..255)
+
+            Extensible ::= INTEGER (1..100, ...)
+
+            slow SpeedValue ::= 5
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+        generator.set_checked_integers(true);
+        let (_file_name, file_content) = generator
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert!(file_content.contains(
+            "pub struct SpeedValue(#[asn(integer(0..16383), const(STANDSTILL(0), UNAVAILABLE(16383)))] u16);"
+        ));
+        assert!(file_content.contains("pub const MIN: Self = Self(0);"));
+        assert!(file_content.contains("pub const MAX: Self = Self(16_383);"));
+        assert!(file_content.contains("pub const STANDSTILL: Self = Self(0);"));
+        assert!(file_content.contains("pub const WITHIN: Self = Self(200);"));
+        assert!(file_content
+            .contains("// BEYOND is not generated, the value 201 is not within 0..=200"));
+        assert!(!file_content.contains("pub const BEYOND"));
+        assert!(file_content.contains("pub const SLOW: SpeedValue = SpeedValue::new_unchecked(5);"));
+        assert!(file_content.contains("if value > Self::MAX.0 {"));
+        assert!(file_content.contains("impl ::core::convert::TryFrom<u16> for SpeedValue {"));
+        assert!(!file_content.contains("impl ::core::ops::DerefMut for SpeedValue {"));
+        assert!(file_content.contains("pub struct Unchecked(#[asn(integer(0..255))] pub u8);"));
+        assert!(file_content.contains("impl ::core::ops::DerefMut for Unchecked {"));
+        assert!(file_content.contains("pub u64);"));
+    }
+
+    #[test]
+    pub fn test_checked_integer_named_numbers_do_not_collide_with_the_bounds() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"CheckedIntegers DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Level ::= INTEGER { min(1), max(10), default(5) } (1..10)
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+        generator.set_checked_integers(true);
+        let (_file_name, file_content) = generator
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert_eq!(1, file_content.matches("pub const MIN: Self").count());
+        assert_eq!(1, file_content.matches("pub const MAX: Self").count());
+        assert!(file_content.contains("pub const MIN: Self = Self(1);"));
+        assert!(file_content.contains("pub const MAX: Self = Self(10);"));
+        assert!(file_content.contains("pub const MIN_NUMBER: Self = Self(1);"));
+        assert!(file_content.contains("pub const MAX_NUMBER: Self = Self(10);"));
+        assert!(file_content.contains("pub const DEFAULT: Self = Self(5);"));
+    }
+
+    #[test]
+    pub fn test_builders() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"Builders DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Station ::= SEQUENCE {
+                id INTEGER (0..255),
+                name UTF8String OPTIONAL,
+                type INTEGER (0..7) DEFAULT 3,
+                label UTF8String DEFAULT "unknown"
+            }
+
+            Single ::= SEQUENCE {
+                value BOOLEAN OPTIONAL
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+        generator.set_builders(true);
+        let (_file_name, file_content) = generator
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert!(file_content.contains("pub struct StationBuilder {"));
+        assert!(file_content.contains("id: Option<u8>,"));
+        assert!(file_content.contains("name: Option<String>,"));
+        assert!(file_content.contains("type_: 3,"));
+        assert!(file_content.contains("label: String::from(\"unknown\"),"));
+        assert!(file_content.contains("Self { name: Some(value), ..self }"));
+        assert!(file_content.contains("Self { type_: value, ..self }"));
+        assert!(file_content
+            .contains("Ok(Station { id, name: self.name, type_: self.type_, label: self.label })"));
+        assert!(file_content.contains("r#type: \"Station\","));
+        assert!(file_content.contains("Self { value: Some(value) }"));
+        assert!(file_content.contains("Ok(Single { value: self.value })"));
+    }
+
+    #[test]
+    pub fn test_default_and_example() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"Defaults DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Percent ::= INTEGER (1..100)
+
+            Kind ::= ENUMERATED { car, truck }
+
+            Vehicle ::= SEQUENCE {
+                speed INTEGER (-10..250),
+                load Percent OPTIONAL,
+                kind Kind DEFAULT truck,
+                plate NumericString (SIZE(2..8)),
+                axles SEQUENCE (SIZE(2..4)) OF Percent,
+                trailer Vehicle OPTIONAL,
+                any INTEGER
+            }
+
+            Payload ::= CHOICE {
+                vehicle Vehicle,
+                raw OCTET STRING
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let (_file_name, file_content) = RustCodeGenerator::from(model)
+            .without_additional_global_derives()
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert!(file_content.contains("pub struct Percent(#[asn(integer(1..100))] pub u8);"));
+        assert!(file_content.contains("impl Default for Percent {"));
+        assert!(file_content.contains("Self(1)"));
+        assert!(file_content.contains("#[default] Car,"));
+        assert!(file_content.contains(
+            "Self { speed: -10, load: None, kind: Kind::Truck, plate: String::from(\"00\"), \
+            axles: vec![Default::default(); 2], trailer: None, any: 0 }"
+        ));
+        assert!(file_content.contains(
+            "Self { speed: -10, load: Some(Percent::example()), kind: Kind::Truck, \
+            plate: String::from(\"00\"), axles: vec![Percent::example(); 2], trailer: None, any: 0 }"
+        ));
+        assert!(file_content.contains("Self::Vehicle(Default::default())"));
+        assert!(file_content.contains("Self::Vehicle(Vehicle::example())"));
+    }
+
     #[test]
     pub fn test_integer_tuple_constants() {
         let model = Model::try_from(Tokenizer::default().parse(
@@ -1023,7 +2752,7 @@ pub(crate) mod tests {
             use asn1rs::prelude::*;
             
             #[asn(transparent)]
-            #[derive(Default, Debug, Clone, PartialEq, Hash)]
+            #[derive(Debug, Clone, PartialEq, Hash)]
             pub struct MyTuple(#[asn(integer(0..255), const(ABC(8), BERND(9)))] pub u8);
             
             impl MyTuple {
@@ -1035,4 +2764,253 @@ pub(crate) mod tests {
             &file_content,
         );
     }
+
+    #[test]
+    pub fn test_comments_as_doc_comments() {
+        let model = Model::try_from(Tokenizer::default().parse_with_comments(
+            r#"Comments DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            -- A position
+            --
+            -- on earth
+            Position ::= SEQUENCE {
+                latitude INTEGER (0..255) -- in degree
+            }
+
+            Kind ::= ENUMERATED {
+                car, -- a car
+                bus
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let (_file_name, file_content) = RustCodeGenerator::from(model)
+            .without_additional_global_derives()
+            .to_string_without_generators()
+            .into_iter()
+            .next()
+            .unwrap();
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            /// A position
+            ///
+            /// on earth
+            #[asn(sequence)]
+            #[derive(Debug, Clone, PartialEq, Hash)]
+            pub struct Position {
+                /// in degree
+                #[asn(integer(0..255))] pub latitude: u8,
+            }
+        "#,
+            &file_content,
+        );
+        assert!(file_content.contains("pub enum Kind {\n    /// a car\n    #[default] Car,\n"));
+    }
+
+    fn module_tree_generator() -> RustCodeGenerator {
+        let rust = |asn: &str, scope: &[&Model<crate::model::Asn>]| {
+            Model::try_from(Tokenizer::default().parse(asn))
+                .unwrap()
+                .try_resolve()
+                .unwrap()
+                .to_rust_with_scope(scope)
+        };
+        let container_v1 = Model::try_from(Tokenizer::default().parse(
+            r#"ITS-Container { itu-t (0) identified-organization (4) etsi (0) version (1) }
+            DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            StationId ::= INTEGER (0..255)
+            END"#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap();
+        let container_v2 = Model::try_from(Tokenizer::default().parse(
+            r#"ITS-Container { itu-t (0) identified-organization (4) etsi (0) version (2) }
+            DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            StationId ::= INTEGER (0..65535)
+            END"#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap();
+        let cam = rust(
+            r#"CAM-PDU-Descriptions { itu-t (0) identified-organization (4) etsi (0) cam (3) }
+            DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS StationId FROM ITS-Container { itu-t (0) identified-organization (4) etsi (0) version (2) };
+            Cam ::= SEQUENCE { station StationId }
+            END"#,
+            &[&container_v2],
+        );
+
+        let mut generator = RustCodeGenerator::default();
+        generator.add_model(container_v1.to_rust());
+        generator.add_model(container_v2.to_rust());
+        generator.add_model(cam);
+        generator
+    }
+
+    #[test]
+    pub fn test_colliding_modules_are_nested_by_oid() {
+        let mut generator = module_tree_generator();
+        generator.set_root_file("mod.rs");
+        let files = generator.to_string_without_generators();
+
+        assert_eq!(
+            vec![
+                "itu_t_0/identified_organization_4/etsi_0/version_1/its_container.rs",
+                "itu_t_0/identified_organization_4/etsi_0/version_2/its_container.rs",
+                "cam_pdu_descriptions.rs",
+                "mod.rs",
+            ],
+            files
+                .iter()
+                .map(|(file, _)| file.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(files[2].1.contains(
+            "use super::itu_t_0::identified_organization_4::etsi_0::version_2::its_container::StationId;"
+        ));
+        assert_eq!(
+            r#"pub mod cam_pdu_descriptions;
+pub mod itu_t_0 {
+    pub mod identified_organization_4 {
+        pub mod etsi_0 {
+            pub mod version_1 {
+                pub mod its_container;
+            }
+            pub mod version_2 {
+                pub mod its_container;
+            }
+        }
+    }
+}
+"#,
+            files[3].1
+        );
+    }
+
+    #[test]
+    pub fn test_module_root_and_paths() {
+        let mut generator = module_tree_generator();
+        generator.set_module_root("crate::asn");
+        generator.set_nest_modules_by_oid(true);
+        generator.add_module_path("CAM-PDU-Descriptions", "etsi::cam");
+        let files = generator.to_string_without_generators();
+
+        assert_eq!("etsi/cam.rs", files[2].0);
+        assert!(files[2].1.contains(
+            "use crate::asn::itu_t_0::identified_organization_4::etsi_0::version_2::its_container::StationId;"
+        ));
+        assert_eq!(
+            vec!["etsi", "cam"],
+            generator.module_segments("CAM-PDU-Descriptions", None)
+        );
+    }
+
+    #[test]
+    pub fn test_modules_are_siblings_by_default() {
+        let generator = module_tree_generator();
+        let mut models = generator.models().to_vec();
+        models.remove(0);
+        let mut generator = RustCodeGenerator::default();
+        models
+            .into_iter()
+            .for_each(|model| generator.add_model(model));
+        generator.set_root_file("lib.rs");
+        let files = generator.to_string_without_generators();
+
+        assert_eq!("its_container.rs", files[0].0);
+        assert_eq!("cam_pdu_descriptions.rs", files[1].0);
+        assert!(files[1].1.contains("use super::its_container::StationId;"));
+        assert_eq!(
+            (
+                "lib.rs",
+                "pub mod cam_pdu_descriptions;\npub mod its_container;\n"
+            ),
+            (files[2].0.as_str(), files[2].1.as_str())
+        );
+    }
+
+    #[test]
+    pub fn test_colliding_modules_without_oid_are_rejected() {
+        let common = |definition: &str| {
+            Model::try_from(Tokenizer::default().parse(&format!(
+                "Common DEFINITIONS AUTOMATIC TAGS ::= BEGIN {} END",
+                definition
+            )))
+            .unwrap()
+            .try_resolve()
+            .unwrap()
+            .to_rust()
+        };
+        let mut generator = RustCodeGenerator::default();
+        generator.add_model(common("Station ::= INTEGER (0..255)"));
+        generator.add_model(common("Station ::= INTEGER (0..65535)"));
+
+        match generator.to_string() {
+            Err(Error::CollidingModules(first, second, file)) => {
+                assert_eq!("common", first);
+                assert_eq!("common", second);
+                assert_eq!("common.rs", file);
+            }
+            r => panic!("Unexpected {:?}", r),
+        }
+    }
+
+    #[test]
+    pub fn test_colliding_imports_are_qualified() {
+        let asn = |asn: &str| {
+            Model::try_from(Tokenizer::default().parse(asn))
+                .unwrap()
+                .try_resolve()
+                .unwrap()
+        };
+        let station = asn(r#"Station-Types DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Station-Id ::= INTEGER (0..255)
+            END"#);
+        let vehicle = asn(r#"Vehicle-Types DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            StationId ::= INTEGER (0..65535)
+            END"#);
+        let report = asn(r#"Report DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            IMPORTS Station-Id FROM Station-Types StationId FROM Vehicle-Types;
+            Report ::= SEQUENCE { station Station-Id, vehicle StationId, other Other }
+            Other ::= BOOLEAN
+            END"#)
+        .to_rust_with_scope(&[&station, &vehicle]);
+
+        assert_eq!(
+            vec!["station_types::StationId".to_string()],
+            report.imports[0].what
+        );
+        assert_eq!(
+            vec!["vehicle_types::StationId".to_string()],
+            report.imports[1].what
+        );
+
+        let mut generator = RustCodeGenerator::default();
+        generator.add_model(station.to_rust());
+        generator.add_model(vehicle.to_rust());
+        generator.add_model(report);
+        generator.set_module_root("crate::asn");
+        generator.add_module_path("Vehicle-Types", "etsi::vehicle");
+        let (_file, content) = &generator.to_string_without_generators()[2];
+
+        assert!(content.contains("use crate::asn::station_types;"));
+        assert!(content.contains("use crate::asn::etsi::vehicle as vehicle_types;"));
+        assert!(content.contains(
+            "#[asn(complex(station_types::StationId, tag(UNIVERSAL(2))))] pub station: station_types::StationId,"
+        ));
+        assert!(content.contains("pub vehicle: vehicle_types::StationId,"));
+        assert!(content.contains("pub other: Other,"));
+    }
 }
@@ -80,6 +80,9 @@ impl PsqlInserter {
                 tag: _,
                 extension_after: _,
                 ordering: _,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => {
                 Self::impl_struct_insert_statement(
                     Self::new_insert_statement_fn(implementation),
@@ -413,6 +416,9 @@ impl PsqlInserter {
                 tag: _,
                 extension_after: _,
                 ordering: _,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => {
                 Self::impl_query_statement(Self::new_query_statement_fn(implementation), name);
                 Self::impl_struct_query_fn(Self::new_query_fn(implementation, true), name);
@@ -563,20 +569,25 @@ impl PsqlInserter {
                 ));
             } else {
                 let inner = rust.as_inner_type();
+                let boxed = matches!(rust.as_no_option(), RustType::Box(_));
                 let load = if let RustType::Option(_) = rust {
                     format!(
                         "{}::value_at_column::<Option<i32>>(&row, {})?\
-                        .map(|id| {}::query_with(transaction, id)).transpose()?",
+                        .map(|id| {}::query_with(transaction, id){}).transpose()?",
                         ERROR_TYPE,
                         index + 1,
                         inner.to_string(),
+                        if boxed { ".map(Box::new)" } else { "" },
                     )
                 } else {
-                    format!(
-                        "{}::query_with(transaction, {}::value_at_column::<i32>(&row, {})?)?",
-                        inner.to_string(),
-                        ERROR_TYPE,
-                        index + 1,
+                    Self::box_if(
+                        boxed,
+                        format!(
+                            "{}::query_with(transaction, {}::value_at_column::<i32>(&row, {})?)?",
+                            inner.to_string(),
+                            ERROR_TYPE,
+                            index + 1,
+                        ),
                     )
                 };
                 block.line(&format!(
@@ -591,6 +602,14 @@ impl PsqlInserter {
         func.push_block(block);
     }
 
+    fn box_if(boxed: bool, load: String) -> String {
+        if boxed {
+            format!("Box::new({})", load)
+        } else {
+            load
+        }
+    }
+
     fn impl_data_enum_query_fn(func: &mut Function, name: &str) {
         func.line("let statement = transaction.prepare(Self::query_statement())?;");
         func.line("let rows = transaction.query(&statement, &[&id])?;");
@@ -649,10 +668,13 @@ impl PsqlInserter {
                     ));
                 }
             } else {
-                block_case.line(&format!(
-                    "{}::query_with(transaction, row.get({}))?",
-                    variant.r#type().clone().as_inner_type().to_string(),
-                    index + 1
+                block_case.line(&Self::box_if(
+                    matches!(variant.r#type(), RustType::Box(_)),
+                    format!(
+                        "{}::query_with(transaction, row.get({}))?",
+                        variant.r#type().clone().as_inner_type().to_string(),
+                        index + 1
+                    ),
                 ));
             }
 
@@ -666,7 +688,7 @@ impl PsqlInserter {
     fn impl_enum_query_fn(func: &mut Function, name: &str, r_enum: &PlainEnum) {
         let mut block = Block::new("match id");
         for (index, variant) in r_enum.variants().enumerate() {
-            block.line(&format!("{} => Ok({}::{}),", index, name, variant));
+            block.line(format!("{} => Ok({}::{}),", index, name, variant.name()));
         }
         block.line(&format!("_ => Err({}::no_result()),", ERROR_TYPE));
         func.push_block(block);
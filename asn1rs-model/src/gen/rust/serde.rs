@@ -0,0 +1,212 @@
+use crate::gen::rust::GeneratorSupplement;
+use crate::gen::rust::RustCodeGenerator;
+use crate::model::Definition;
+use crate::model::Rust;
+use crate::model::RustType;
+use crate::model::Size;
+use codegen::Scope;
+
+/// Adds `#[serde(..)]` attributes to the fields and variants of every definition, so that
+/// their JSON representation matches the ASN.1 schema and - where possible - the JSON Encoding
+/// Rules (ITU-T X.697):
+///
+///  - fields and variants are renamed to their original ASN.1 name
+///  - absent `OPTIONAL` fields (and extension additions) are skipped
+///  - `CHOICE`s are externally tagged, `ENUMERATED`s are strings of the variant name
+///  - `OCTET STRING`s and `BIT STRING`s are hexadecimal strings, see `asn1rs::io::jer`
+///
+/// The supplement does not derive `Serialize` and `Deserialize`, see
+/// [`RustCodeGenerator::add_global_derive`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SerdeSupplement;
+
+impl GeneratorSupplement<Rust> for SerdeSupplement {
+    fn add_imports(&self, _scope: &mut Scope) {}
+
+    fn impl_supplement(&self, _scope: &mut Scope, _definition: &Definition<Rust>) {}
+
+    fn attributes_of_member(
+        &self,
+        Definition(_name, rust): &Definition<Rust>,
+        index: usize,
+    ) -> Vec<String> {
+        match rust {
+            Rust::Struct { fields, .. } => fields
+                .get(index)
+                .map(|field| {
+                    let mut arguments = Vec::new();
+                    if let Some(rename) = Self::rename(
+                        field.asn_name(),
+                        &RustCodeGenerator::rust_field_name(field.name(), true),
+                    ) {
+                        arguments.push(rename);
+                    }
+                    if field.r#type().is_option() {
+                        arguments.push("default".to_string());
+                        arguments.push("skip_serializing_if = \"Option::is_none\"".to_string());
+                    }
+                    if let Some(with) = Self::with(field.r#type()) {
+                        arguments.push(with);
+                    }
+                    Self::attribute(arguments)
+                })
+                .unwrap_or_default(),
+            Rust::Enum(plain) => plain
+                .variants()
+                .nth(index)
+                .map(|variant| {
+                    Self::attribute(
+                        Self::rename(
+                            variant.asn_name(),
+                            &RustCodeGenerator::rust_variant_name(variant.name()),
+                        )
+                        .into_iter()
+                        .collect(),
+                    )
+                })
+                .unwrap_or_default(),
+            Rust::DataEnum(data) => data
+                .variants()
+                .nth(index)
+                .map(|variant| {
+                    Self::attribute(
+                        Self::rename(
+                            variant.asn_name(),
+                            &RustCodeGenerator::rust_variant_name(variant.name()),
+                        )
+                        .into_iter()
+                        .chain(Self::with(variant.r#type()))
+                        .collect(),
+                    )
+                })
+                .unwrap_or_default(),
+            Rust::TupleStruct { r#type, .. } => {
+                Self::attribute(Self::with(r#type).into_iter().collect())
+            }
+        }
+    }
+}
+
+impl SerdeSupplement {
+    fn attribute(arguments: Vec<String>) -> Vec<String> {
+        if arguments.is_empty() {
+            Vec::new()
+        } else {
+            vec![format!("#[serde({})]", arguments.join(", "))]
+        }
+    }
+
+    /// Renames the member if its ASN.1 name differs from its Rust name
+    fn rename(asn_name: &str, rust_name: &str) -> Option<String> {
+        if asn_name != rust_name {
+            Some(format!("rename = \"{}\"", asn_name))
+        } else {
+            None
+        }
+    }
+
+    /// The module of `asn1rs::io::jer` to (de-)serialize values of the given type with, if the
+    /// serde representation of the type does not match the JSON Encoding Rules. For a `BIT STRING`
+    /// of a fixed size, the functions are named instead, so that the size can be passed on.
+    fn with(r#type: &RustType) -> Option<String> {
+        fn no_default(r#type: &RustType) -> &RustType {
+            match r#type {
+                RustType::Default(inner, _) => inner,
+                r#type => r#type,
+            }
+        }
+        let (r#type, option) = match no_default(r#type) {
+            RustType::Option(inner) => (no_default(inner), "::option"),
+            r#type => (r#type, ""),
+        };
+        let module = match r#type {
+            RustType::VecU8(_) | RustType::ByteArray(_) => "octet_string",
+            // the size is not part of the encoding, so it is passed to the reader
+            RustType::BitVec(Size::Fix(bits, false)) => {
+                return Some(format!(
+                    "serialize_with = \"::asn1rs::io::jer::bit_string::fixed{0}::serialize\", \
+                     deserialize_with = \"::asn1rs::io::jer::bit_string::fixed{0}::deserialize::<_, {1}>\"",
+                    option, bits
+                ));
+            }
+            RustType::BitVec(_) => "bit_string",
+            _ => return None,
+        };
+        Some(format!(
+            "with = \"::asn1rs::io::jer::{}{}\"",
+            module, option
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::Generator;
+    use crate::model::Model;
+    use crate::parser::Tokenizer;
+
+    #[test]
+    pub fn test_serde_attributes() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"Jer DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+
+            Kind ::= ENUMERATED { passenger-car, truck }
+
+            Mac ::= OCTET STRING (SIZE(6))
+
+            Vehicle ::= SEQUENCE {
+                vehicleId INTEGER (0..255),
+                type Kind,
+                mac Mac,
+                serial-number OCTET STRING OPTIONAL,
+                flags BIT STRING (SIZE(4)),
+                ...,
+                lights BIT STRING OPTIONAL
+            }
+
+            Payload ::= CHOICE {
+                vehicle Vehicle,
+                raw-data OCTET STRING
+            }
+
+            END
+        "#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+        generator.add_supplement(SerdeSupplement);
+        let (_file_name, file_content) = generator.to_string().unwrap().into_iter().next().unwrap();
+
+        assert!(
+            file_content.contains("#[default] #[serde(rename = \"passenger-car\")] PassengerCar,")
+        );
+        assert!(file_content.contains("#[serde(rename = \"truck\")] Truck,"));
+        assert!(file_content.contains(
+            "pub struct Mac(#[asn(octet_string(size(6)))] #[serde(with = \"::asn1rs::io::jer::octet_string\")] pub Vec<u8>);"
+        ));
+        assert!(file_content.contains(
+            "#[asn(integer(0..255))] #[serde(rename = \"vehicleId\")] pub vehicle_id: u8,"
+        ));
+        assert!(file_content.contains("#[serde(rename = \"type\")] pub type_: Kind,"));
+        assert!(file_content.contains(")] pub mac: Mac,"));
+        assert!(file_content.contains(
+            "#[serde(rename = \"serial-number\", default, skip_serializing_if = \"Option::is_none\", with = \"::asn1rs::io::jer::octet_string::option\")] pub serial_number: Option<Vec<u8>>,"
+        ));
+        assert!(file_content.contains(
+            "#[serde(serialize_with = \"::asn1rs::io::jer::bit_string::fixed::serialize\", deserialize_with = \"::asn1rs::io::jer::bit_string::fixed::deserialize::<_, 4>\")] pub flags: BitVec,"
+        ));
+        assert!(file_content.contains(
+            "#[serde(default, skip_serializing_if = \"Option::is_none\", with = \"::asn1rs::io::jer::bit_string::option\")] pub lights: Option<BitVec>,"
+        ));
+        assert!(file_content.contains("#[serde(rename = \"vehicle\")] Vehicle(Vehicle),"));
+        assert!(file_content.contains(
+            "#[serde(rename = \"raw-data\", with = \"::asn1rs::io::jer::octet_string\")] RawData(Vec<u8>),"
+        ));
+    }
+}
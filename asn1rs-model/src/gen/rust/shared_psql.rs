@@ -1,5 +1,5 @@
 use crate::gen::RustCodeGenerator;
-use crate::model::rust::{DataEnum, Field};
+use crate::model::rust::{unqualified_type_name, DataEnum, Field};
 use crate::model::sql::Sql;
 use crate::model::{Model, RustType};
 
@@ -86,7 +86,7 @@ pub(crate) fn struct_list_entry_select_referenced_value_statement(
     );
     format!(
         "SELECT * FROM {} WHERE id IN (SELECT value FROM {} WHERE list = $1)",
-        RustCodeGenerator::rust_variant_name(other_type),
+        RustCodeGenerator::rust_variant_name(unqualified_type_name(other_type)),
         listentry_table,
     )
 }
@@ -1,13 +1,15 @@
 use crate::gen::RustCodeGenerator;
 use crate::model::rust::{DataEnum, EncodingOrdering, Field, PlainEnum};
 use crate::model::{
-    Charset, Definition, LiteralValue, Model, Range, Rust, RustType, Size, Tag, TagProperty,
+    Charset, Definition, IgnoredInComparison, LiteralValue, Model, Range, Rust, RustType, Size,
+    Tag, TagProperty,
 };
 use codegen::{Block, Impl, Scope};
 use std::fmt::Display;
 
 pub const CRATE_SYN_PREFIX: &str = "::asn1rs::syn::";
 pub const CRATE_MODEL_PREFIX: &str = "::asn1rs::model::";
+pub const DEFAULT_CONVERSION: &str = "::asn1rs::syn::mapped::FromInto";
 
 pub struct AsnDefWriter;
 
@@ -26,6 +28,9 @@ impl AsnDefWriter {
                 tag: _,
                 extension_after: _,
                 ordering,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => {
                 scope.raw(&format!(
                     "type AsnDef{} = {}{}<{}>;",
@@ -60,6 +65,9 @@ impl AsnDefWriter {
                 r#type: field,
                 tag: _,
                 constants: _,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => {
                 scope.raw(&format!(
                     "type AsnDef{} = {}Sequence<{}>;",
@@ -82,11 +90,15 @@ impl AsnDefWriter {
             RustType::U32(_) => format!("{}Integer<u32, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::I64(_) => format!("{}Integer<i64, {}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::U64(_) => format!("{}Integer<u64, {}Constraint>", CRATE_SYN_PREFIX, name),
-            RustType::String(_, charset) => format!(
+            RustType::String(_, charset, _) => format!(
                 "{}{:?}String<{}Constraint>",
                 CRATE_SYN_PREFIX, charset, name
             ),
             RustType::VecU8(_) => format!("{}OctetString<{}Constraint>", CRATE_SYN_PREFIX, name),
+            RustType::ByteArray(size) => format!(
+                "{}FixedOctetString<{}, {}Constraint>",
+                CRATE_SYN_PREFIX, size, name
+            ),
             RustType::BitVec(_) => format!("{}BitString<{}Constraint>", CRATE_SYN_PREFIX, name),
             RustType::Null => format!("{}NullT", CRATE_SYN_PREFIX),
             RustType::Vec(inner, _, ordering) => {
@@ -102,6 +114,16 @@ impl AsnDefWriter {
                     name
                 )
             }
+            RustType::Array(inner, size) => {
+                let virtual_field = Self::vec_virtual_field_name(name);
+                format!(
+                    "{}FixedSequenceOf<{}, {}, {}Constraint>",
+                    CRATE_SYN_PREFIX,
+                    Self::type_declaration(inner, &virtual_field),
+                    size,
+                    name
+                )
+            }
             RustType::Option(inner) => format!("Option<{}>", Self::type_declaration(inner, name)),
             RustType::Default(inner, _default) => {
                 let virtual_field = Self::default_virtual_field_name(name);
@@ -115,6 +137,19 @@ impl AsnDefWriter {
             RustType::Complex(inner, _tag) => {
                 format!("{}Complex<{}, {}Constraint>", CRATE_SYN_PREFIX, inner, name)
             }
+            RustType::Box(inner) => format!(
+                "{}Complex<Box<{}>, {}Constraint>",
+                CRATE_SYN_PREFIX,
+                inner.to_string(),
+                name
+            ),
+            RustType::Override(inner, type_override) => format!(
+                "{}Mapped<{}, {}, {}>",
+                CRATE_SYN_PREFIX,
+                Self::type_declaration(inner, name),
+                type_override.rust_type(),
+                type_override.conversion().unwrap_or(DEFAULT_CONVERSION)
+            ),
         }
     }
 
@@ -147,6 +182,9 @@ impl AsnDefWriter {
                 tag,
                 extension_after,
                 ordering,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => {
                 // ITU-T X.680 | ISO/IEC 8824-1, G.2.12.3 (SEQUENCE and SET)
                 let fields = Self::assign_implicit_tags(fields);
@@ -170,6 +208,7 @@ impl AsnDefWriter {
                         name_type: (variant.name().to_string(), variant.r#type().clone()),
                         tag: variant.tag(),
                         constants: Vec::default(),
+                        asn_name: IgnoredInComparison::default(),
                     })
                     .collect::<Vec<_>>();
 
@@ -183,11 +222,15 @@ impl AsnDefWriter {
                 r#type,
                 tag,
                 constants,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => {
                 let fields = [Field {
                     name_type: ("0".to_string(), r#type.clone()),
                     tag: *tag,
                     constants: constants.to_vec(),
+                    asn_name: IgnoredInComparison::default(),
                 }];
                 self.write_field_constraints(scope, name, &fields[..]);
                 self.write_sequence_or_set_constraint(
@@ -328,13 +371,13 @@ impl AsnDefWriter {
                     range,
                 )
             }
-            RustType::String(size, charset) => {
+            RustType::String(size, charset, alphabet) => {
                 Self::write_common_constraint_type(
                     scope,
                     constraint_type_name,
                     field.tag.unwrap_or_else(|| charset.default_tag()),
                 );
-                Self::write_size_constraint(
+                Self::write_string_constraint(
                     match charset {
                         Charset::Utf8 => "utf8string",
                         Charset::Ia5 => "ia5string",
@@ -345,6 +388,7 @@ impl AsnDefWriter {
                     scope,
                     constraint_type_name,
                     size,
+                    alphabet.as_deref(),
                 )
             }
             RustType::VecU8(size) => {
@@ -390,10 +434,25 @@ impl AsnDefWriter {
                         name_type: (virtual_field_name, *inner.clone()),
                         tag: None,
                         constants: field.constants().to_vec(),
+                        asn_name: IgnoredInComparison::default(),
                     },
                     &constraint_type_name,
                 )
             }
+            RustType::ByteArray(_) | RustType::Array(..) => {
+                // constrained like the `Vec` of the fixed size
+                self.write_field_constraint(
+                    scope,
+                    name,
+                    &Field {
+                        name_type: (field.name().to_string(), field.r#type().clone().into_vec()),
+                        tag: field.tag,
+                        constants: field.constants().to_vec(),
+                        asn_name: IgnoredInComparison::default(),
+                    },
+                    constraint_type_name,
+                )
+            }
             RustType::Null => {
                 Self::write_common_constraint_type(
                     scope,
@@ -401,16 +460,18 @@ impl AsnDefWriter {
                     field.tag.unwrap_or(Tag::DEFAULT_NULL),
                 );
             }
-            RustType::Option(inner) => self.write_field_constraint(
-                scope,
-                name,
-                &Field {
-                    name_type: (field.name().to_string(), *inner.clone()),
-                    tag: field.tag(),
-                    constants: field.constants().to_vec(),
-                },
-                constraint_type_name,
-            ),
+            RustType::Option(inner) | RustType::Box(inner) | RustType::Override(inner, _) => self
+                .write_field_constraint(
+                    scope,
+                    name,
+                    &Field {
+                        name_type: (field.name().to_string(), *inner.clone()),
+                        tag: field.tag(),
+                        constants: field.constants().to_vec(),
+                        asn_name: IgnoredInComparison::default(),
+                    },
+                    constraint_type_name,
+                ),
             RustType::Default(inner, default) => {
                 Self::write_common_constraint_type(
                     scope,
@@ -430,6 +491,7 @@ impl AsnDefWriter {
                         name_type: (virtual_field_name, *inner.clone()),
                         tag: field.tag,
                         constants: field.constants().to_vec(),
+                        asn_name: IgnoredInComparison::default(),
                     },
                     &constraint_type_name,
                 )
@@ -544,7 +606,7 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match self");
                 for (index, variant) in enumerated.variants().enumerate() {
-                    match_block.line(format!("Self::{} => {},", variant, index));
+                    match_block.line(format!("Self::{} => {},", variant.name(), index));
                 }
                 match_block
             });
@@ -556,7 +618,7 @@ impl AsnDefWriter {
             .push_block({
                 let mut match_block = Block::new("match index");
                 for (index, variant) in enumerated.variants().enumerate() {
-                    match_block.line(format!("{} => Some(Self::{}),", index, variant));
+                    match_block.line(format!("{} => Some(Self::{}),", index, variant.name()));
                 }
                 match_block.line("_ => None,");
                 match_block
@@ -721,6 +783,32 @@ impl AsnDefWriter {
             "impl {}{}::Constraint for {} {{",
             CRATE_SYN_PREFIX, module, constraint_type_name
         ));
+        Self::write_size_consts(scope, size);
+        scope.raw("}");
+    }
+
+    fn write_string_constraint(
+        module: &str,
+        scope: &mut Scope,
+        constraint_type_name: &str,
+        size: &Size,
+        alphabet: Option<&str>,
+    ) {
+        scope.raw(&format!(
+            "impl {}{}::Constraint for {} {{",
+            CRATE_SYN_PREFIX, module, constraint_type_name
+        ));
+        Self::write_size_consts(scope, size);
+        if let Some(alphabet) = alphabet {
+            scope.raw(&format!(
+                "const PERMITTED_ALPHABET: Option<&'static str> = Some({:?});",
+                alphabet
+            ));
+        }
+        scope.raw("}");
+    }
+
+    fn write_size_consts(scope: &mut Scope, size: &Size) {
         if let Some(min) = size.min() {
             scope.raw(&format!("const MIN: Option<u64> = Some({});", min));
         }
@@ -728,7 +816,6 @@ impl AsnDefWriter {
             scope.raw(&format!("const MAX: Option<u64> = Some({});", max));
         }
         scope.raw(&format!("const EXTENSIBLE: bool = {};", size.extensible()));
-        scope.raw("}");
     }
 
     fn write_default_constraint(
@@ -746,6 +833,11 @@ impl AsnDefWriter {
         let (owned, borrowed, default) = match r#type.as_no_option() {
             RustType::Option(_) => unreachable!(),
             RustType::Default(..) => panic!("Nested default detected"),
+            RustType::Complex(name, _tag) if matches!(default, LiteralValue::Reference(..)) => (
+                Cow::<'_, str>::Borrowed(name),
+                Cow::<'_, str>::Borrowed(name),
+                Cow::<'_, str>::Owned(default.to_rust_const_literal_string(false)),
+            ),
             RustType::Complex(name, _tag)
                 if !matches!(default, LiteralValue::EnumeratedVariant(..)) =>
             {
@@ -756,7 +848,7 @@ impl AsnDefWriter {
                     Cow::<'_, str>::Owned(format!(
                         "{}({})",
                         name,
-                        default.as_rust_const_literal(false)
+                        default.to_rust_const_literal_string(false)
                     )),
                 )
             }
@@ -785,7 +877,7 @@ impl AsnDefWriter {
             t => (
                 Cow::Owned(t.to_string()),
                 t.to_const_lit_string(),
-                Cow::Owned(default.as_rust_const_literal(false).to_string()),
+                Cow::Owned(default.to_rust_const_literal_string(false)),
             ),
         };
         scope.raw(&format!("type Owned = {};", owned));
@@ -956,21 +1048,21 @@ impl AsnDefWriter {
 pub(crate) mod tests {
     use crate::gen::rust::walker::AsnDefWriter;
     use crate::model::rust::{EncodingOrdering, Field};
-    use crate::model::{Charset, Definition, Rust, RustType, Size};
+    use crate::model::{Charset, Comments, Definition, IgnoredInComparison, Rust, RustType, Size};
     use codegen::Scope;
 
     fn simple_whatever_sequence() -> Definition<Rust> {
         Definition(
             String::from("Whatever"),
             Rust::struct_from_fields(vec![
-                Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8, None)),
                 Field::from_name_type(
                     "opt",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8, None))),
                 ),
                 Field::from_name_type(
                     "some",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8, None))),
                 ),
             ]),
         )
@@ -982,18 +1074,29 @@ pub(crate) mod tests {
             Rust::Struct {
                 ordering: EncodingOrdering::Keep,
                 fields: vec![
-                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8, None)),
                     Field::from_name_type(
                         "opt",
-                        RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                        RustType::Option(Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            None,
+                        ))),
                     ),
                     Field::from_name_type(
                         "some",
-                        RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                        RustType::Option(Box::new(RustType::String(
+                            Size::Any,
+                            Charset::Utf8,
+                            None,
+                        ))),
                     ),
                 ],
                 tag: None,
                 extension_after: Some(1),
+                comments: Comments::default(),
+                sources: IgnoredInComparison::default(),
+                asn_name: IgnoredInComparison::default(),
             },
         )
     }
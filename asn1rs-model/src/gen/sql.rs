@@ -1,5 +1,6 @@
 use crate::gen::Generator;
 use crate::model::sql::Column;
+use crate::model::sql::CommentTarget;
 use crate::model::sql::Constraint;
 use crate::model::sql::Sql;
 use crate::model::Definition;
@@ -84,6 +85,9 @@ impl Generator<Sql> for SqlDefGenerator {
                     Sql::SilentlyPreventAnyDelete(table) => {
                         Self::append_silently_prevent_any_delete(&mut create, name, table)?;
                     }
+                    Sql::Comment(target, comment) => {
+                        Self::append_comment(&mut create, target, comment)?;
+                    }
                 }
             }
             drop.push_str(&create);
@@ -245,6 +249,25 @@ impl SqlDefGenerator {
         Ok(())
     }
 
+    fn append_comment(
+        target: &mut dyn Write,
+        on: &CommentTarget,
+        comment: &str,
+    ) -> Result<(), Error> {
+        let on = match on {
+            CommentTarget::Table(table) => format!("TABLE {}", table),
+            CommentTarget::Type(r#type) => format!("TYPE {}", r#type),
+            CommentTarget::Column(table, column) => format!("COLUMN {}.{}", table, column),
+        };
+        writeln!(
+            target,
+            "COMMENT ON {} IS '{}';",
+            on,
+            comment.replace('\'', "''")
+        )?;
+        Ok(())
+    }
+
     fn append_abandon_children(
         target: &mut dyn Write,
         table: &str,
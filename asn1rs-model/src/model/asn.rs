@@ -1,16 +1,22 @@
 use crate::model::lor::{Error as ResolveError, TryResolve, Unresolved};
-use crate::model::lor::{ResolveState, Resolved, Resolver};
+use crate::model::lor::{ResolveState, Resolved, Resolver, TagEnvironment};
 use crate::model::{
-    BitString, Charset, Choice, ChoiceVariant, ComponentTypeList, Enumerated, Field, Integer,
-    LitOrRef, LiteralValue, Range, Size, Tag, TagProperty, Target,
+    BitString, Charset, Choice, ChoiceVariant, ComponentTypeList, Enumerated, Field,
+    IgnoredInComparison, Integer, LitOrRef, LiteralValue, Range, Size, Source, Tag, TagProperty,
+    Target,
 };
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Asn<RS: ResolveState = Resolved> {
     pub tag: Option<Tag>,
     pub r#type: Type<RS>,
     pub default: Option<RS::ConstType>,
+    /// The comment describing the definition or field in the ASN.1 source
+    pub comment: Option<String>,
+    /// Where the definition or field is defined in the ASN.1 source
+    pub source: IgnoredInComparison<Source>,
 }
 
 impl<RS: ResolveState> Target for Asn<RS> {
@@ -33,9 +39,21 @@ impl<RS: ResolveState> Asn<RS> {
             tag,
             r#type,
             default: None,
+            comment: None,
+            source: IgnoredInComparison::default(),
         }
     }
 
+    pub fn with_comment(mut self, comment: Option<String>) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.source = source.into();
+        self
+    }
+
     pub fn untagged(r#type: Type<RS>) -> Self {
         Self::opt_tagged(None, r#type)
     }
@@ -70,57 +88,52 @@ impl Asn<Unresolved> {
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ComponentTypeList<Resolved>>
+            + Resolver<Asn<Resolved>>
+            + TagEnvironment,
     >(
         &self,
         resolver: &R,
     ) -> Result<Asn<Resolved>, ResolveError> {
         let r#type = self.r#type.try_resolve(resolver)?;
         Ok(Asn {
-            tag: self.tag,
+            tag: match self.r#type.no_optional() {
+                // the tag of the referenced type is inherited
+                Type::ConstrainedTypeReference(name, _) if self.tag.is_none() => {
+                    resolver
+                        .resolve(&LitOrRef::<Asn<Resolved>>::Ref(name.clone()))?
+                        .tag
+                }
+                _ => self.tag,
+            },
             default: self
                 .default
                 .as_ref()
                 .map(|d| match d {
-                    LitOrRef::Lit(_) => resolver.resolve(d),
+                    LitOrRef::Lit(value) => value.try_resolve(&r#type, resolver),
                     LitOrRef::Ref(name) => {
-                        if let Type::TypeReference(referenced_name, _tag) = &r#type {
-                            if let Ok(Type::Enumerated(enumerated)) =
-                                resolver.resolve(&LitOrRef::Ref(referenced_name.to_string()))
-                            {
-                                if let Some(lit) =
-                                    enumerated.variants().find(|v| name.eq(v.name())).map(|v| {
-                                        LiteralValue::EnumeratedVariant(
-                                            referenced_name.to_string(),
-                                            v.name().to_string(),
-                                        )
-                                    })
-                                {
-                                    Ok(lit)
-                                } else {
-                                    resolver.resolve(d)
-                                }
-                            } else {
-                                resolver.resolve(d)
-                            }
-                        } else {
-                            resolver.resolve(d)
-                        }
+                        LiteralValue::Reference(name.clone()).try_resolve(&r#type, resolver)
                     }
                 })
                 .transpose()?,
             r#type,
+            comment: self.comment.clone(),
+            source: self.source.clone(),
         })
     }
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type<RS: ResolveState = Resolved> {
     /// ITU-T X.680 | ISO/IEC 8824-1, 18
     Boolean,
     /// ITU-T X.680 | ISO/IEC 8824-1, 19
     Integer(Integer<RS::RangeType>),
-    String(Size<RS::SizeType>, Charset),
+    /// A character string type and, if constrained by `FROM`, the sorted characters of its
+    /// effective permitted alphabet (ITU-T X.680 | ISO/IEC 8824-1, 51.7)
+    String(Size<RS::SizeType>, Charset, Option<String>),
     /// ITU-T X.680 | ISO/IEC 8824-1, 23
     OctetString(Size<RS::SizeType>),
     /// ITU-T X.680 | ISO/IEC 8824-1, 22
@@ -143,9 +156,20 @@ pub enum Type<RS: ResolveState = Resolved> {
     Enumerated(Enumerated),
     /// ITU-T X.680 | ISO/IEC 8824-1, 29
     Choice(Choice<RS>),
+    /// Currently only supported as type of value references, definitions of this type are
+    /// rejected while resolving
+    ///
+    /// ITU-T X.680 | ISO/IEC 8824-1, 32
+    ObjectIdentifier,
 
     /// ITU-T X.680 | ISO/IEC 8824-1, 16
     TypeReference(String, Option<Tag>),
+    /// A reference to a type with an additional subtype constraint. This is only present in
+    /// unresolved models, while resolving it is replaced by the referenced type with the
+    /// effective constraint applied (see [`crate::model::Applied`]).
+    ///
+    /// ITU-T X.680 | ISO/IEC 8824-1, 49.6
+    ConstrainedTypeReference(String, RS::ConstraintType),
 }
 
 impl Type {
@@ -157,13 +181,14 @@ impl Type {
         Self::Sequence(ComponentTypeList {
             fields,
             extension_after: None,
+            components_of: Vec::new(),
         })
     }
 }
 
 impl<RS: ResolveState> Type<RS> {
     pub fn unconstrained_utf8string() -> Self {
-        Self::String(Size::Any, Charset::Utf8)
+        Self::String(Size::Any, Charset::Utf8, None)
     }
 
     pub fn unconstrained_octetstring() -> Self {
@@ -195,6 +220,33 @@ impl<RS: ResolveState> Type<RS> {
         Self::Choice(Choice::from(variants))
     }
 
+    /// The names of all types referenced by this type or any of its inner types
+    pub fn type_references(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.collect_type_references(&mut names);
+        names
+    }
+
+    fn collect_type_references<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Type::Optional(inner)
+            | Type::Default(inner, _)
+            | Type::SequenceOf(inner, _)
+            | Type::SetOf(inner, _) => inner.collect_type_references(names),
+            Type::Sequence(components) | Type::Set(components) => components
+                .fields
+                .iter()
+                .for_each(|field| field.role.r#type.collect_type_references(names)),
+            Type::Choice(choice) => choice
+                .variants()
+                .for_each(|variant| variant.r#type.collect_type_references(names)),
+            Type::TypeReference(name, _) | Type::ConstrainedTypeReference(name, _) => {
+                names.push(name)
+            }
+            _ => {}
+        }
+    }
+
     pub fn optional(self) -> Self {
         Self::Optional(Box::new(self))
     }
@@ -211,6 +263,14 @@ impl<RS: ResolveState> Type<RS> {
         Asn::untagged(self)
     }
 
+    pub fn no_optional(&self) -> &Self {
+        if let Self::Optional(inner) = self {
+            inner.no_optional()
+        } else {
+            self
+        }
+    }
+
     pub fn no_optional_mut(&mut self) -> &mut Self {
         if let Self::Optional(inner) = self {
             inner.no_optional_mut()
@@ -225,7 +285,10 @@ impl Type<Unresolved> {
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ComponentTypeList<Resolved>>
+            + Resolver<Asn<Resolved>>
+            + TagEnvironment,
     >(
         &self,
         resolver: &R,
@@ -233,7 +296,9 @@ impl Type<Unresolved> {
         Ok(match self {
             Type::Boolean => Type::Boolean,
             Type::Integer(integer) => Type::Integer(integer.try_resolve(resolver)?),
-            Type::String(size, charset) => Type::String(size.try_resolve(resolver)?, *charset),
+            Type::String(size, charset, alphabet) => {
+                Type::String(size.try_resolve(resolver)?, *charset, alphabet.clone())
+            }
             Type::OctetString(size) => Type::OctetString(size.try_resolve(resolver)?),
             Type::BitString(string) => Type::BitString(string.try_resolve(resolver)?),
             Type::Null => Type::Null,
@@ -253,18 +318,122 @@ impl Type<Unresolved> {
             ),
             Type::Enumerated(e) => Type::Enumerated(e.clone()),
             Type::Choice(c) => Type::Choice(c.try_resolve(resolver)?),
+            Type::ObjectIdentifier => Type::ObjectIdentifier,
             Type::TypeReference(name, tag) => Type::TypeReference(name.clone(), *tag),
+            Type::ConstrainedTypeReference(name, constraint) => {
+                let referenced: Asn<Resolved> = resolver.resolve(&LitOrRef::Ref(name.clone()))?;
+                constraint
+                    .try_resolve(resolver)?
+                    .apply_to(name, referenced.r#type)?
+            }
         })
     }
 }
 
 impl LiteralValue {
+    /// Resolves references to values and the variants of ENUMERATED types and checks whether
+    /// structured values fit the given type
+    pub fn try_resolve<
+        R: Resolver<<Resolved as ResolveState>::ConstType>
+            + Resolver<Type<Unresolved>>
+            + Resolver<Asn<Resolved>>
+            + TagEnvironment,
+    >(
+        &self,
+        r#type: &Type,
+        resolver: &R,
+    ) -> Result<LiteralValue, ResolveError> {
+        match (self, r#type) {
+            (_, Type::Optional(inner)) | (_, Type::Default(inner, _)) => {
+                self.try_resolve(inner, resolver)
+            }
+            (LiteralValue::Reference(name), Type::TypeReference(type_name, _)) => {
+                match resolver.resolve(&LitOrRef::<Type<Unresolved>>::Ref(type_name.clone())) {
+                    Ok(Type::Enumerated(enumerated))
+                        if enumerated.variants().any(|v| name.eq(v.name())) =>
+                    {
+                        Ok(LiteralValue::EnumeratedVariant(
+                            type_name.clone(),
+                            name.clone(),
+                        ))
+                    }
+                    _ => resolver.resolve(&LitOrRef::Ref(name.clone())),
+                }
+            }
+            (LiteralValue::Reference(name), _) => resolver.resolve(&LitOrRef::Ref(name.clone())),
+            (
+                LiteralValue::Sequence(_) | LiteralValue::Choice(..),
+                Type::TypeReference(type_name, _),
+            ) => {
+                let referenced: Asn = resolver.resolve(&LitOrRef::Ref(type_name.clone()))?;
+                self.try_resolve(&referenced.r#type, resolver)
+            }
+            (LiteralValue::Sequence(values), Type::Sequence(components))
+            | (LiteralValue::Sequence(values), Type::Set(components)) => {
+                for (index, field) in components.fields.iter().enumerate() {
+                    let mandatory = field.role.default.is_none()
+                        && !matches!(field.role.r#type, Type::Optional(_))
+                        && !matches!(components.extension_after, Some(e) if index > e);
+                    if mandatory && !values.iter().any(|(name, _)| field.name.eq(name)) {
+                        return Err(ResolveError::FailedToResolveValue(format!(
+                            "missing value for component {}",
+                            field.name
+                        )));
+                    }
+                }
+                values
+                    .iter()
+                    .map(|(name, value)| {
+                        let field = components
+                            .fields
+                            .iter()
+                            .find(|f| f.name.eq(name))
+                            .ok_or_else(|| {
+                                ResolveError::FailedToResolveValue(format!(
+                                    "unknown component {}",
+                                    name
+                                ))
+                            })?;
+                        Ok((
+                            name.clone(),
+                            value.try_resolve(&field.role.r#type, resolver)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(LiteralValue::Sequence)
+            }
+            (LiteralValue::Choice(name, value), Type::Choice(choice)) => {
+                let variant = choice
+                    .variants()
+                    .find(|v| name.eq(v.name()))
+                    .ok_or_else(|| {
+                        ResolveError::FailedToResolveValue(format!("unknown alternative {}", name))
+                    })?;
+                Ok(LiteralValue::Choice(
+                    name.clone(),
+                    Box::new(value.try_resolve(variant.r#type(), resolver)?),
+                ))
+            }
+            (LiteralValue::Sequence(_), _) => Err(ResolveError::FailedToResolveValue(
+                "SEQUENCE or SET value for other type".to_string(),
+            )),
+            (LiteralValue::Choice(name, _), _) => Err(ResolveError::FailedToResolveValue(format!(
+                "CHOICE value {} for other type",
+                name
+            ))),
+            (LiteralValue::ObjectIdentifier(oid), _) => {
+                Ok(LiteralValue::ObjectIdentifier(oid.try_resolve(resolver)?))
+            }
+            (value, _) => Ok(value.clone()),
+        }
+    }
+
     pub fn try_from_asn_str(asn: &str) -> Option<LiteralValue> {
         Some(match asn {
             bool if bool.eq_ignore_ascii_case("true") => LiteralValue::Boolean(true),
             bool if bool.eq_ignore_ascii_case("false") => LiteralValue::Boolean(false),
             slice if slice.starts_with('"') && slice.ends_with('"') => {
-                LiteralValue::String(slice[1..slice.len() - 1].to_owned())
+                LiteralValue::String(slice[1..slice.len() - 1].replace("\"\"", "\""))
             }
             slice
                 if slice.chars().all(|c| c.is_ascii_digit())
@@ -320,3 +489,40 @@ impl LiteralValue {
         })
     }
 }
+
+/// Formats the value in the ASN.1 value notation, which is also understood by the parser
+impl Display for LiteralValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LiteralValue::Boolean(true) => write!(f, "TRUE"),
+            LiteralValue::Boolean(false) => write!(f, "FALSE"),
+            LiteralValue::String(string) => {
+                // ITU-T X.680 | ISO/IEC 8824-1, 12.14: quotation marks are doubled
+                write!(f, "\"{}\"", string.replace('"', "\"\""))
+            }
+            LiteralValue::Integer(integer) => write!(f, "{}", integer),
+            LiteralValue::OctetString(octets) => {
+                write!(f, "'")?;
+                for octet in octets {
+                    write!(f, "{:02X}", octet)?;
+                }
+                write!(f, "'H")
+            }
+            LiteralValue::EnumeratedVariant(_, variant) => write!(f, "{}", variant),
+            LiteralValue::Sequence(components) if components.is_empty() => write!(f, "{{}}"),
+            LiteralValue::Sequence(components) => {
+                write!(f, "{{ ")?;
+                for (index, (name, value)) in components.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} {}", name, value)?;
+                }
+                write!(f, " }}")
+            }
+            LiteralValue::Choice(name, value) => write!(f, "{} : {}", name, value),
+            LiteralValue::ObjectIdentifier(oid) => Display::fmt(oid, f),
+            LiteralValue::Reference(name) => write!(f, "{}", name),
+        }
+    }
+}
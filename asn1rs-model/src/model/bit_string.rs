@@ -7,6 +7,7 @@ use std::fmt::{Debug, Display};
 use std::iter::Peekable;
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BitString<T: Display + Debug + Clone = usize> {
     pub size: Size<T>,
     pub constants: Vec<(String, u64)>,
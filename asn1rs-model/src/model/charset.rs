@@ -2,6 +2,7 @@ use crate::model::Tag;
 
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, EnumString)]
 #[strum(serialize_all = "lowercase")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Charset {
     Utf8,
     /// ITU-T X.680 | ISO/IEC 8824-1, 43.3
@@ -67,6 +68,17 @@ impl Charset {
     pub const VISIBLE_STRING_CHARACTERS: &'static str =
         " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
 
+    /// The name of the string type in ASN.1, such as `UTF8String`
+    pub const fn asn_name(self) -> &'static str {
+        match self {
+            Charset::Utf8 => "UTF8String",
+            Charset::Numeric => "NumericString",
+            Charset::Printable => "PrintableString",
+            Charset::Ia5 => "IA5String",
+            Charset::Visible => "VisibleString",
+        }
+    }
+
     pub fn default_tag(self) -> Tag {
         match self {
             Charset::Utf8 => Tag::DEFAULT_UTF8_STRING,
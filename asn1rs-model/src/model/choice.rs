@@ -1,11 +1,17 @@
-use crate::model::lor::{Error as ResolveError, ResolveState, Resolved, Resolver, Unresolved};
-use crate::model::{Asn, Error, Model, PeekableTokens, Tag, TagProperty, Type};
+use crate::model::lor::{
+    Error as ResolveError, ResolveState, Resolved, Resolver, TagEnvironment, Unresolved,
+};
+use crate::model::{
+    Asn, ComponentTypeList, Error, IgnoredInComparison, Model, PeekableTokens, Source, Tag,
+    TagProperty, Type,
+};
 use crate::parser::Token;
 use std::convert::TryFrom;
 
 use std::iter::Peekable;
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Choice<RS: ResolveState = Resolved> {
     variants: Vec<ChoiceVariant<RS>>,
     extension_after: Option<usize>,
@@ -50,6 +56,10 @@ impl<RS: ResolveState> Choice<RS> {
         self.variants.iter()
     }
 
+    pub fn variants_mut(&mut self) -> impl Iterator<Item = &mut ChoiceVariant<RS>> {
+        self.variants.iter_mut()
+    }
+
     pub fn is_extensible(&self) -> bool {
         self.extension_after.is_some()
     }
@@ -81,13 +91,23 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Choice<Unresolved>
                     choice.extension_after = Some(choice.variants.len() - 1);
                 }
             } else {
+                let source = iter
+                    .peek()
+                    .map(|token| Source::new(token.location()))
+                    .unwrap_or_default();
                 let name = iter.next_text_or_err()?;
                 let (token, tag) = Model::<Asn<Unresolved>>::next_with_opt_tag(iter)?;
                 let r#type = Model::<Asn<Unresolved>>::read_role_given_text(
                     iter,
                     token.into_text_or_else(Error::no_text)?,
                 )?;
-                choice.variants.push(ChoiceVariant { name, tag, r#type });
+                choice.variants.push(ChoiceVariant {
+                    name,
+                    tag,
+                    r#type,
+                    comment: None,
+                    source: source.into(),
+                });
             }
 
             loop_ctrl_separator!(iter.next_or_err()?);
@@ -102,7 +122,10 @@ impl Choice<Unresolved> {
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ComponentTypeList<Resolved>>
+            + Resolver<Asn<Resolved>>
+            + TagEnvironment,
     >(
         &self,
         resolver: &R,
@@ -119,10 +142,15 @@ impl Choice<Unresolved> {
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChoiceVariant<RS: ResolveState = Resolved> {
     pub name: String,
     pub tag: Option<Tag>,
     pub r#type: Type<RS>,
+    /// The comment describing the variant in the ASN.1 source
+    pub comment: Option<String>,
+    /// Where the variant is defined in the ASN.1 source
+    pub source: IgnoredInComparison<Source>,
 }
 
 impl<RS: ResolveState> ChoiceVariant<RS> {
@@ -132,6 +160,8 @@ impl<RS: ResolveState> ChoiceVariant<RS> {
             name: name.to_string(),
             tag: None,
             r#type,
+            comment: None,
+            source: IgnoredInComparison::default(),
         }
     }
 
@@ -163,7 +193,10 @@ impl ChoiceVariant<Unresolved> {
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ComponentTypeList<Resolved>>
+            + Resolver<Asn<Resolved>>
+            + TagEnvironment,
     >(
         &self,
         resolver: &R,
@@ -172,6 +205,8 @@ impl ChoiceVariant<Unresolved> {
             name: self.name.clone(),
             tag: self.tag,
             r#type: self.r#type.try_resolve(resolver)?,
+            comment: self.comment.clone(),
+            source: self.source.clone(),
         })
     }
 }
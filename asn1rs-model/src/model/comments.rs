@@ -0,0 +1,237 @@
+use crate::model::lor::Unresolved;
+use crate::model::{Asn, Model, Type};
+use crate::parser::{Location, Token};
+
+/// The comments of a definition and of its fields or variants (members), which are referred to
+/// by their index. Members without a comment do not occupy any space, so that `Comments` of
+/// definitions without any comments equal [`Comments::default`].
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Comments {
+    definition: Option<String>,
+    members: Vec<Option<String>>,
+}
+
+impl Comments {
+    pub fn with_definition(mut self, comment: Option<String>) -> Self {
+        self.definition = comment;
+        self
+    }
+
+    pub fn set_definition(&mut self, comment: Option<String>) {
+        self.definition = comment;
+    }
+
+    pub fn with_member(mut self, index: usize, comment: Option<String>) -> Self {
+        self.set_member(index, comment);
+        self
+    }
+
+    pub fn set_member(&mut self, index: usize, comment: Option<String>) {
+        if let Some(comment) = comment {
+            if self.members.len() <= index {
+                self.members.resize(index + 1, None);
+            }
+            self.members[index] = Some(comment);
+        } else if let Some(member) = self.members.get_mut(index) {
+            *member = None;
+        }
+    }
+
+    pub fn definition(&self) -> Option<&str> {
+        self.definition.as_deref()
+    }
+
+    pub fn member(&self, index: usize) -> Option<&str> {
+        self.members.get(index).and_then(Option::as_deref)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.definition.is_none() && self.members.iter().all(Option::is_none)
+    }
+}
+
+/// A definition, field or variant in the token stream. The path consists of the name of the
+/// definition followed by the names of the (nested) members leading to the element.
+#[derive(Debug, PartialEq, Eq)]
+struct Element {
+    location: Location,
+    path: Vec<String>,
+}
+
+/// Attaches the [`Token::Comment`]s to the definitions, fields and variants they describe:
+///
+///  - a comment on the same line as a previous token describes the latest element before it,
+///    a comment right below that is aligned with it continues it
+///  - a comment on its own line describes the element that directly follows it
+///
+/// Comments that cannot be assigned to any element are dropped. The `tokens` must not contain
+/// any comments.
+pub(crate) fn attach_comments(
+    model: &mut Model<Asn<Unresolved>>,
+    tokens: &[Token],
+    comments: &[Token],
+) {
+    let elements = elements(tokens);
+    let mut texts = vec![Vec::<&str>::new(); elements.len()];
+    // column, index of the previous token and element of the latest trailing comment
+    let mut trailing: Option<(usize, usize, usize)> = None;
+
+    for comment in comments {
+        let location = comment.location();
+        let previous = tokens.partition_point(|t| t.location() < location);
+        let element = if previous > 0 && tokens[previous - 1].location().line() == location.line() {
+            let element = elements.iter().rposition(|e| e.location < location);
+            trailing = element.map(|element| (location.column(), previous, element));
+            element
+        } else if let Some((_, _, element)) = trailing
+            .filter(|(column, before, _)| *column == location.column() && *before == previous)
+        {
+            Some(element)
+        } else {
+            trailing = None;
+            let next = tokens.get(previous).map(Token::location);
+            elements.iter().position(|e| Some(e.location) == next)
+        };
+
+        if let (Some(element), Some(text)) = (element, comment.comment()) {
+            texts[element].push(text);
+        }
+    }
+
+    for (element, texts) in elements.iter().zip(texts) {
+        // empty comments only separate paragraphs
+        let start = texts.iter().position(|t| !t.is_empty());
+        let end = texts.iter().rposition(|t| !t.is_empty());
+        if let (Some(start), Some(end)) = (start, end) {
+            if let Some(comment) = comment_of(model, &element.path) {
+                *comment = Some(texts[start..=end].join("\n"));
+            }
+        }
+    }
+}
+
+/// Finds the definitions and their (nested) members in the token stream. Members are the first
+/// names after a `{` or `,` on the same nesting level, ignoring constraints in parentheses.
+/// Extension markers are elements as well, but cannot be resolved to any member.
+fn elements(tokens: &[Token]) -> Vec<Element> {
+    let boundaries = Model::<Asn<Unresolved>>::definition_boundaries(tokens);
+    let mut elements = Vec::new();
+    let mut path = Vec::<String>::new();
+    let mut braces = 0_usize;
+    let mut parentheses = 0_usize;
+    let mut expect_member = false;
+
+    for token in tokens {
+        if boundaries.contains(&token.location()) {
+            path.clear();
+            braces = 0;
+            parentheses = 0;
+            expect_member = false;
+            if let Some(name) = token.text().filter(|t| !t.eq_ignore_ascii_case("END")) {
+                path.push(name.to_string());
+                elements.push(Element {
+                    location: token.location(),
+                    path: path.clone(),
+                });
+            }
+            continue;
+        } else if path.is_empty() {
+            continue;
+        }
+
+        match token {
+            Token::Separator(_, '(') => parentheses += 1,
+            Token::Separator(_, ')') => parentheses = parentheses.saturating_sub(1),
+            Token::Separator(_, '{') if parentheses == 0 => {
+                braces += 1;
+                path.truncate(braces);
+                expect_member = true;
+            }
+            Token::Separator(_, '}') if parentheses == 0 => {
+                braces = braces.saturating_sub(1);
+                path.truncate(braces + 1);
+                expect_member = false;
+            }
+            Token::Separator(_, ',') if parentheses == 0 && braces > 0 => {
+                path.truncate(braces);
+                expect_member = true;
+            }
+            Token::Separator(location, '.') if expect_member && parentheses == 0 => {
+                // the extension marker, so that a comment next to it is not attached elsewhere
+                path.truncate(braces);
+                path.push("...".to_string());
+                elements.push(Element {
+                    location: *location,
+                    path: path.clone(),
+                });
+                expect_member = false;
+            }
+            Token::Text(location, name) if expect_member && parentheses == 0 => {
+                path.truncate(braces);
+                path.push(name.clone());
+                elements.push(Element {
+                    location: *location,
+                    path: path.clone(),
+                });
+                expect_member = false;
+            }
+            _ => {}
+        }
+    }
+
+    elements
+}
+
+fn comment_of<'a>(
+    model: &'a mut Model<Asn<Unresolved>>,
+    path: &[String],
+) -> Option<&'a mut Option<String>> {
+    let (name, members) = path.split_first()?;
+    let definition = model
+        .definitions
+        .iter_mut()
+        .find(|definition| definition.name().eq(name))?;
+    asn_comment_of(&mut definition.1, members)
+}
+
+fn asn_comment_of<'a>(
+    asn: &'a mut Asn<Unresolved>,
+    path: &[String],
+) -> Option<&'a mut Option<String>> {
+    if path.is_empty() {
+        Some(&mut asn.comment)
+    } else {
+        type_comment_of(&mut asn.r#type, path)
+    }
+}
+
+fn type_comment_of<'a>(
+    r#type: &'a mut Type<Unresolved>,
+    path: &[String],
+) -> Option<&'a mut Option<String>> {
+    let (name, members) = path.split_first()?;
+    match r#type {
+        Type::Optional(inner)
+        | Type::Default(inner, _)
+        | Type::SequenceOf(inner, _)
+        | Type::SetOf(inner, _) => type_comment_of(inner, path),
+        Type::Sequence(list) | Type::Set(list) => {
+            let field = list.fields.iter_mut().find(|f| f.name.eq(name))?;
+            asn_comment_of(&mut field.role, members)
+        }
+        Type::Choice(choice) => {
+            let variant = choice.variants_mut().find(|v| v.name.eq(name))?;
+            if members.is_empty() {
+                Some(&mut variant.comment)
+            } else {
+                type_comment_of(&mut variant.r#type, members)
+            }
+        }
+        Type::Enumerated(enumerated) if members.is_empty() => enumerated
+            .variants_mut()
+            .find(|v| v.name.eq(name))
+            .map(|v| &mut v.comment),
+        _ => None,
+    }
+}
@@ -0,0 +1,733 @@
+use crate::model::lor::Resolved;
+use crate::model::{Asn, Choice, ChoiceVariant, Definition, Field, Model, Range, Size};
+use crate::model::{ComponentTypeList, Enumerated, EnumeratedVariant, Tag, Type};
+use std::fmt::{Display, Formatter};
+
+/// How a [`Change`] affects peers that still use the old version of a module. The variants are
+/// ordered from the least to the most severe.
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Ord, Eq, Hash)]
+pub enum Compatibility {
+    /// The encoding is not affected, such as for renamed fields or changed comments
+    Cosmetic,
+    /// An extension addition or a new definition, which peers that use the old version skip
+    /// or do not know about, but can still decode
+    Extension,
+    /// Peers that use the old version can no longer decode the new encoding or vice versa
+    Breaking,
+}
+
+impl Display for Compatibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Compatibility::Cosmetic => write!(f, "cosmetic"),
+            Compatibility::Extension => write!(f, "extension"),
+            Compatibility::Breaking => write!(f, "breaking"),
+        }
+    }
+}
+
+/// A difference between the old and the new version of a module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub compatibility: Compatibility,
+    /// The name of the (new) module
+    pub module: String,
+    /// The affected definition and the path to the affected field or variant, like
+    /// `Position.lat`. Elements of a `SEQUENCE OF` or `SET OF` are denoted by `[]`.
+    pub path: String,
+    pub description: String,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(
+                f,
+                "{}: {}: {}",
+                self.compatibility, self.module, self.description
+            )
+        } else {
+            write!(
+                f,
+                "{}: {}.{}: {}",
+                self.compatibility, self.module, self.path, self.description
+            )
+        }
+    }
+}
+
+/// All [`Change`]s between two versions of a set of modules
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Report {
+    changes: Vec<Change>,
+}
+
+impl Report {
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// The most severe [`Compatibility`] of all changes, [`None`] if there are no changes
+    pub fn compatibility(&self) -> Option<Compatibility> {
+        self.changes.iter().map(|c| c.compatibility).max()
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.compatibility() == Some(Compatibility::Breaking)
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares the old and the new version of a set of resolved modules under the PER extension
+/// rules. Modules are matched by name, definitions by name, and the components and variants of
+/// a `SEQUENCE`, `SET`, `CHOICE` or `ENUMERATED` by their position, so that renaming them is
+/// only [`Compatibility::Cosmetic`]. A removed definition that matches an added one structurally
+/// is reported as renamed, which is also only [`Compatibility::Cosmetic`], as are the references
+/// to it.
+///
+/// Changes of the root of a type (such as added or removed root components, changed ranges or
+/// sizes and changed tags) are [`Compatibility::Breaking`], new extension additions and new
+/// definitions are [`Compatibility::Extension`]s.
+pub fn compare(old: &[Model<Asn<Resolved>>], new: &[Model<Asn<Resolved>>]) -> Report {
+    let mut report = Report::default();
+    for old_model in old {
+        match new.iter().find(|model| model.name == old_model.name) {
+            Some(new_model) => Checker {
+                module: &new_model.name,
+                report: &mut report,
+                renames: Vec::new(),
+            }
+            .compare_models(old_model, new_model),
+            None => report.changes.push(Change {
+                compatibility: Compatibility::Breaking,
+                module: old_model.name.clone(),
+                path: String::new(),
+                description: "module removed".to_string(),
+            }),
+        }
+    }
+    for new_model in new {
+        if !old.iter().any(|model| model.name == new_model.name) {
+            report.changes.push(Change {
+                compatibility: Compatibility::Extension,
+                module: new_model.name.clone(),
+                path: String::new(),
+                description: "module added".to_string(),
+            });
+        }
+    }
+    report
+}
+
+/// The root members and, if extensible, the extension additions of a type
+struct Members<'a, T> {
+    root: &'a [T],
+    additions: Option<&'a [T]>,
+}
+
+impl<'a, T> Members<'a, T> {
+    fn new(members: &'a [T], extension_after: Option<usize>) -> Self {
+        match extension_after {
+            Some(index) => {
+                let (root, additions) = members.split_at((index + 1).min(members.len()));
+                Self {
+                    root,
+                    additions: Some(additions),
+                }
+            }
+            None => Self {
+                root: members,
+                additions: None,
+            },
+        }
+    }
+}
+
+struct Checker<'a> {
+    module: &'a str,
+    report: &'a mut Report,
+    /// The old and new names of the renamed definitions
+    renames: Vec<(String, String)>,
+}
+
+impl Checker<'_> {
+    fn push<S: Into<String>>(&mut self, compatibility: Compatibility, path: &str, description: S) {
+        self.report.changes.push(Change {
+            compatibility,
+            module: self.module.to_string(),
+            path: path.to_string(),
+            description: description.into(),
+        });
+    }
+
+    fn compare_models(&mut self, old: &Model<Asn<Resolved>>, new: &Model<Asn<Resolved>>) {
+        let removed = old
+            .definitions
+            .iter()
+            .filter(|o| !new.definitions.iter().any(|n| n.name() == o.name()));
+        for Definition(old_name, old_asn) in removed {
+            let renamed = new
+                .definitions
+                .iter()
+                .filter(|n| !old.definitions.iter().any(|o| o.name() == n.name()))
+                .filter(|n| !self.renames.iter().any(|(_, new)| new == n.name()))
+                .find(|Definition(new_name, new_asn)| {
+                    self.is_renamed(old_name, old_asn, new_name, new_asn)
+                });
+            if let Some(Definition(new_name, _)) = renamed {
+                self.renames.push((old_name.clone(), new_name.clone()));
+            }
+        }
+
+        for Definition(name, old_asn) in &old.definitions {
+            let new_name = self
+                .renames
+                .iter()
+                .find(|(old, _)| old == name)
+                .map_or_else(|| name.clone(), |(_, new)| new.clone());
+            match new.definitions.iter().find(|d| d.name() == new_name) {
+                Some(Definition(_, new_asn)) => {
+                    if new_name != *name {
+                        self.push(
+                            Compatibility::Cosmetic,
+                            &new_name,
+                            format!("definition renamed from {} to {}", name, new_name),
+                        );
+                    }
+                    self.compare_asn(&new_name, old_asn, new_asn)
+                }
+                None => self.push(Compatibility::Breaking, name, "definition removed"),
+            }
+        }
+        for Definition(name, _) in &new.definitions {
+            if !old.definitions.iter().any(|d| d.name() == name)
+                && !self.renames.iter().any(|(_, new)| new == name)
+            {
+                self.push(Compatibility::Extension, name, "definition added");
+            }
+        }
+    }
+
+    /// Whether the old definition matches the new definition with another name structurally,
+    /// which is the case if they only differ in cosmetic changes, see [`Compatibility::Cosmetic`]
+    fn is_renamed(
+        &self,
+        old_name: &str,
+        old: &Asn<Resolved>,
+        new_name: &str,
+        new: &Asn<Resolved>,
+    ) -> bool {
+        let mut report = Report::default();
+        let mut renames = self.renames.clone();
+        // the definition might refer to itself
+        renames.push((old_name.to_string(), new_name.to_string()));
+        Checker {
+            module: self.module,
+            report: &mut report,
+            renames,
+        }
+        .compare_asn(new_name, old, new);
+        report.compatibility() <= Some(Compatibility::Cosmetic)
+    }
+
+    fn compare_asn(&mut self, path: &str, old: &Asn<Resolved>, new: &Asn<Resolved>) {
+        self.compare_tag(path, old.tag, new.tag);
+        self.compare_comment(path, old.comment.as_deref(), new.comment.as_deref());
+
+        let old_optional = matches!(old.r#type, Type::Optional(_)) || old.default.is_some();
+        let new_optional = matches!(new.r#type, Type::Optional(_)) || new.default.is_some();
+        if old_optional && !new_optional {
+            self.push(Compatibility::Breaking, path, "became mandatory");
+        } else if !old_optional && new_optional {
+            self.push(Compatibility::Breaking, path, "became optional");
+        }
+
+        match (&old.default, &new.default) {
+            (Some(old), Some(new)) if old != new => self.push(
+                Compatibility::Breaking,
+                path,
+                format!("DEFAULT changed from {} to {}", old, new),
+            ),
+            (None, Some(new)) if old_optional => self.push(
+                Compatibility::Breaking,
+                path,
+                format!("DEFAULT {} added", new),
+            ),
+            (Some(old), None) if new_optional => self.push(
+                Compatibility::Breaking,
+                path,
+                format!("DEFAULT {} removed", old),
+            ),
+            _ => {}
+        }
+
+        self.compare_types(path, old.r#type.no_optional(), new.r#type.no_optional());
+    }
+
+    fn compare_tag(&mut self, path: &str, old: Option<Tag>, new: Option<Tag>) {
+        if old != new {
+            self.push(
+                Compatibility::Breaking,
+                path,
+                format!(
+                    "tag changed from {} to {}",
+                    describe_tag(old),
+                    describe_tag(new)
+                ),
+            );
+        }
+    }
+
+    fn compare_comment(&mut self, path: &str, old: Option<&str>, new: Option<&str>) {
+        if old != new {
+            self.push(Compatibility::Cosmetic, path, "comment changed");
+        }
+    }
+
+    fn compare_types(&mut self, path: &str, old: &Type<Resolved>, new: &Type<Resolved>) {
+        match (old, new) {
+            (Type::Boolean, Type::Boolean)
+            | (Type::Null, Type::Null)
+            | (Type::ObjectIdentifier, Type::ObjectIdentifier) => {}
+            (Type::Integer(old), Type::Integer(new)) => {
+                self.compare_ranges(path, &old.range, &new.range);
+                if old.constants != new.constants {
+                    self.push(Compatibility::Cosmetic, path, "named numbers changed");
+                }
+            }
+            (
+                Type::String(old_size, old_charset, old_alphabet),
+                Type::String(new_size, new_charset, new_alphabet),
+            ) => {
+                if old_charset != new_charset {
+                    self.push(
+                        Compatibility::Breaking,
+                        path,
+                        format!(
+                            "changed from {} to {}",
+                            old_charset.asn_name(),
+                            new_charset.asn_name()
+                        ),
+                    );
+                } else if old_alphabet != new_alphabet {
+                    // the permitted alphabet determines the encoding of each character in PER
+                    self.push(Compatibility::Breaking, path, "permitted alphabet changed");
+                }
+                self.compare_sizes(path, old_size, new_size);
+            }
+            (Type::OctetString(old), Type::OctetString(new)) => self.compare_sizes(path, old, new),
+            (Type::BitString(old), Type::BitString(new)) => {
+                self.compare_sizes(path, &old.size, &new.size);
+                if old.constants != new.constants {
+                    self.push(Compatibility::Cosmetic, path, "named bits changed");
+                }
+            }
+            (Type::Default(old, _), Type::Default(new, _)) => self.compare_types(path, old, new),
+            (Type::Sequence(old), Type::Sequence(new)) | (Type::Set(old), Type::Set(new)) => {
+                self.compare_components(path, old, new)
+            }
+            (Type::SequenceOf(old, old_size), Type::SequenceOf(new, new_size))
+            | (Type::SetOf(old, old_size), Type::SetOf(new, new_size)) => {
+                self.compare_sizes(path, old_size, new_size);
+                self.compare_types(&format!("{}[]", path), old, new);
+            }
+            (Type::Enumerated(old), Type::Enumerated(new)) => {
+                self.compare_enumerated(path, old, new)
+            }
+            (Type::Choice(old), Type::Choice(new)) => self.compare_choices(path, old, new),
+            (Type::TypeReference(old, _), Type::TypeReference(new, _))
+                if old == new || self.renames.iter().any(|(o, n)| o == old && n == new) =>
+            {
+                // the referenced definition is compared on its own
+            }
+            (old, new) => self.push(
+                Compatibility::Breaking,
+                path,
+                format!(
+                    "type changed from {} to {}",
+                    describe_type(old),
+                    describe_type(new)
+                ),
+            ),
+        }
+    }
+
+    fn compare_ranges(&mut self, path: &str, old: &Range<Option<i64>>, new: &Range<Option<i64>>) {
+        if old == new {
+            return;
+        }
+        let old_min = old.min().unwrap_or(i64::MIN);
+        let old_max = old.max().unwrap_or(i64::MAX);
+        let new_min = new.min().unwrap_or(i64::MIN);
+        let new_max = new.max().unwrap_or(i64::MAX);
+        let how = if (old_min, old_max) == (new_min, new_max) {
+            "extensibility changed"
+        } else if new_min >= old_min && new_max <= old_max {
+            "range tightened"
+        } else if new_min <= old_min && new_max >= old_max {
+            "range widened"
+        } else {
+            "range changed"
+        };
+        self.push(
+            Compatibility::Breaking,
+            path,
+            format!(
+                "{} from {} to {}",
+                how,
+                describe_range(old),
+                describe_range(new)
+            ),
+        );
+    }
+
+    fn compare_sizes(&mut self, path: &str, old: &Size<usize>, new: &Size<usize>) {
+        if old == new {
+            return;
+        }
+        let old_min = old.min().copied().unwrap_or(0);
+        let old_max = old.max().copied().unwrap_or(usize::MAX);
+        let new_min = new.min().copied().unwrap_or(0);
+        let new_max = new.max().copied().unwrap_or(usize::MAX);
+        let how = if (old_min, old_max) == (new_min, new_max) {
+            "size extensibility changed"
+        } else if new_min >= old_min && new_max <= old_max {
+            "size tightened"
+        } else if new_min <= old_min && new_max >= old_max {
+            "size widened"
+        } else {
+            "size changed"
+        };
+        self.push(
+            Compatibility::Breaking,
+            path,
+            format!(
+                "{} from {} to {}",
+                how,
+                describe_size(old),
+                describe_size(new)
+            ),
+        );
+    }
+
+    fn compare_components(
+        &mut self,
+        path: &str,
+        old: &ComponentTypeList<Resolved>,
+        new: &ComponentTypeList<Resolved>,
+    ) {
+        self.compare_members(
+            path,
+            "component",
+            Members::new(&old.fields, old.extension_after),
+            Members::new(&new.fields, new.extension_after),
+            |field: &Field<Asn<Resolved>>| &field.name,
+            |checker, path, old, new| checker.compare_asn(path, &old.role, &new.role),
+        );
+    }
+
+    fn compare_choices(&mut self, path: &str, old: &Choice<Resolved>, new: &Choice<Resolved>) {
+        let old_variants = old.variants().cloned().collect::<Vec<_>>();
+        let new_variants = new.variants().cloned().collect::<Vec<_>>();
+        self.compare_members(
+            path,
+            "variant",
+            Members::new(&old_variants, old.extension_after_index()),
+            Members::new(&new_variants, new.extension_after_index()),
+            |variant: &ChoiceVariant<Resolved>| &variant.name,
+            |checker, path, old, new| {
+                checker.compare_tag(path, old.tag, new.tag);
+                checker.compare_comment(path, old.comment.as_deref(), new.comment.as_deref());
+                checker.compare_types(path, &old.r#type, &new.r#type);
+            },
+        );
+    }
+
+    fn compare_enumerated(&mut self, path: &str, old: &Enumerated, new: &Enumerated) {
+        let old_variants = old.variants().cloned().collect::<Vec<_>>();
+        let new_variants = new.variants().cloned().collect::<Vec<_>>();
+        self.compare_members(
+            path,
+            "variant",
+            Members::new(&old_variants, old.extension_after_index()),
+            Members::new(&new_variants, new.extension_after_index()),
+            EnumeratedVariant::name,
+            |checker, path, old, new| {
+                checker.compare_comment(path, old.comment(), new.comment());
+                if old.number() != new.number() {
+                    checker.push(Compatibility::Breaking, path, "number changed");
+                }
+            },
+        );
+    }
+
+    /// Compares the members by their position. Renamed members are cosmetic changes, added or
+    /// removed root members and removed extension additions are breaking changes.
+    fn compare_members<T>(
+        &mut self,
+        path: &str,
+        kind: &str,
+        old: Members<'_, T>,
+        new: Members<'_, T>,
+        name: impl Fn(&T) -> &str,
+        mut compare: impl FnMut(&mut Self, &str, &T, &T),
+    ) {
+        let root_len = old.root.len().max(new.root.len());
+        for index in 0..root_len {
+            match (old.root.get(index), new.root.get(index)) {
+                (Some(old), Some(new)) => {
+                    let path = self.compare_names(path, kind, name(old), name(new));
+                    compare(self, &path, old, new);
+                }
+                (Some(old), None) => self.push(
+                    Compatibility::Breaking,
+                    path,
+                    format!("{} {} removed from the root", kind, name(old)),
+                ),
+                (None, Some(new)) => self.push(
+                    Compatibility::Breaking,
+                    path,
+                    format!("{} {} added to the root", kind, name(new)),
+                ),
+                (None, None) => {}
+            }
+        }
+
+        match (old.additions, new.additions) {
+            (None, None) => {}
+            (None, Some(_)) => self.push(Compatibility::Breaking, path, "became extensible"),
+            (Some(_), None) => self.push(Compatibility::Breaking, path, "no longer extensible"),
+            (Some(old), Some(new)) => {
+                for index in 0..old.len().max(new.len()) {
+                    match (old.get(index), new.get(index)) {
+                        (Some(old), Some(new)) => {
+                            let path = self.compare_names(path, kind, name(old), name(new));
+                            compare(self, &path, old, new);
+                        }
+                        (Some(old), None) => self.push(
+                            Compatibility::Breaking,
+                            path,
+                            format!("extension addition {} removed", name(old)),
+                        ),
+                        (None, Some(new)) => self.push(
+                            Compatibility::Extension,
+                            path,
+                            format!("extension addition {} added", name(new)),
+                        ),
+                        (None, None) => {}
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reports a renamed member and returns the path of the (new) member
+    fn compare_names(&mut self, path: &str, kind: &str, old: &str, new: &str) -> String {
+        if old != new {
+            self.push(
+                Compatibility::Cosmetic,
+                path,
+                format!("{} renamed from {} to {}", kind, old, new),
+            );
+        }
+        format!("{}.{}", path, new)
+    }
+}
+
+fn describe_tag(tag: Option<Tag>) -> String {
+    tag.map_or_else(|| "none".to_string(), |tag| tag.to_string())
+}
+
+fn describe_range(range: &Range<Option<i64>>) -> String {
+    format!(
+        "{}..{}{}",
+        range
+            .min()
+            .map_or_else(|| "MIN".to_string(), |min| min.to_string()),
+        range
+            .max()
+            .map_or_else(|| "MAX".to_string(), |max| max.to_string()),
+        if range.extensible() { ", ..." } else { "" }
+    )
+}
+
+fn describe_size(size: &Size<usize>) -> String {
+    size.to_constraint_string()
+        .unwrap_or_else(|| "unconstrained".to_string())
+}
+
+fn describe_type(r#type: &Type<Resolved>) -> String {
+    match r#type {
+        Type::Boolean => "BOOLEAN".to_string(),
+        Type::Integer(_) => "INTEGER".to_string(),
+        Type::String(_, charset, _) => charset.asn_name().to_string(),
+        Type::OctetString(_) => "OCTET STRING".to_string(),
+        Type::BitString(_) => "BIT STRING".to_string(),
+        Type::Null => "NULL".to_string(),
+        Type::Optional(inner) | Type::Default(inner, _) => describe_type(inner),
+        Type::Sequence(_) => "SEQUENCE".to_string(),
+        Type::SequenceOf(..) => "SEQUENCE OF".to_string(),
+        Type::Set(_) => "SET".to_string(),
+        Type::SetOf(..) => "SET OF".to_string(),
+        Type::Enumerated(_) => "ENUMERATED".to_string(),
+        Type::Choice(_) => "CHOICE".to_string(),
+        Type::ObjectIdentifier => "OBJECT IDENTIFIER".to_string(),
+        Type::TypeReference(name, _) => name.clone(),
+        Type::ConstrainedTypeReference(_, applied) => match *applied {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Tokenizer;
+
+    fn resolve(asn: &str) -> Vec<Model<Asn<Resolved>>> {
+        vec![
+            Model::try_from(Tokenizer::default().parse_with_comments(asn))
+                .unwrap()
+                .try_resolve()
+                .unwrap(),
+        ]
+    }
+
+    fn compare_definitions(old: &str, new: &str) -> Report {
+        compare(
+            &resolve(&format!(
+                "Cam DEFINITIONS AUTOMATIC TAGS ::= BEGIN\n{}\nEND",
+                old
+            )),
+            &resolve(&format!(
+                "Cam DEFINITIONS AUTOMATIC TAGS ::= BEGIN\n{}\nEND",
+                new
+            )),
+        )
+    }
+
+    #[test]
+    fn test_identical_modules_have_no_changes() {
+        let definitions = "Position ::= SEQUENCE { lat INTEGER (-90..90), ... }";
+        let report = compare_definitions(definitions, definitions);
+        assert!(report.changes().is_empty());
+        assert_eq!(None, report.compatibility());
+    }
+
+    #[test]
+    fn test_extension_additions_are_compatible() {
+        let report = compare_definitions(
+            "Position ::= SEQUENCE { lat INTEGER, ... }
+             Kind ::= ENUMERATED { a, ... }
+             Data ::= CHOICE { a BOOLEAN, ... }",
+            "Position ::= SEQUENCE { lat INTEGER, ..., alt INTEGER OPTIONAL }
+             Kind ::= ENUMERATED { a, ..., b }
+             Data ::= CHOICE { a BOOLEAN, ..., b NULL }
+             Speed ::= INTEGER",
+        );
+        assert_eq!(Some(Compatibility::Extension), report.compatibility());
+        assert_eq!(
+            "extension: Cam.Position: extension addition alt added\n\
+             extension: Cam.Kind: extension addition b added\n\
+             extension: Cam.Data: extension addition b added\n\
+             extension: Cam.Speed: definition added\n",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn test_root_changes_are_breaking() {
+        let report = compare_definitions(
+            "Position ::= SEQUENCE { lat INTEGER (-90..90), lon INTEGER }
+             Kind ::= ENUMERATED { a, b, c }
+             Data ::= CHOICE { a [0] BOOLEAN, b [1] NULL }
+             Gone ::= NULL",
+            "Position ::= SEQUENCE { lat INTEGER (-45..45), lon INTEGER, alt INTEGER }
+             Kind ::= ENUMERATED { a, b }
+             Data ::= CHOICE { a [2] BOOLEAN, b [1] NULL }",
+        );
+        assert!(report.is_breaking());
+        assert_eq!(
+            "breaking: Cam.Position.lat: range tightened from -90..90 to -45..45\n\
+             breaking: Cam.Position: component alt added to the root\n\
+             breaking: Cam.Kind: variant c removed from the root\n\
+             breaking: Cam.Data.a: tag changed from [0] to [2]\n\
+             breaking: Cam.Gone: definition removed\n",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn test_renames_and_comments_are_cosmetic() {
+        let report = compare_definitions(
+            "Position ::= SEQUENCE { lat INTEGER, lon INTEGER }",
+            "-- the position
+             Position ::= SEQUENCE { latitude INTEGER, lon INTEGER }",
+        );
+        assert_eq!(Some(Compatibility::Cosmetic), report.compatibility());
+        assert_eq!(
+            "cosmetic: Cam.Position: comment changed\n\
+             cosmetic: Cam.Position: component renamed from lat to latitude\n",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn test_renamed_definitions_are_cosmetic() {
+        let report = compare_definitions(
+            "Position ::= SEQUENCE { lat Latitude, next Position OPTIONAL }
+             Latitude ::= INTEGER (-90..90)
+             Kind ::= ENUMERATED { a, b }",
+            "Position ::= SEQUENCE { lat Lat, next Position OPTIONAL }
+             Lat ::= INTEGER (-90..90)
+             Category ::= ENUMERATED { a, b, c }",
+        );
+        assert_eq!(
+            "cosmetic: Cam.Lat: definition renamed from Latitude to Lat\n\
+             breaking: Cam.Kind: definition removed\n\
+             extension: Cam.Category: definition added\n",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn test_optionality_size_and_type_changes_are_breaking() {
+        let report = compare_definitions(
+            "Message ::= SEQUENCE {
+                 id INTEGER OPTIONAL,
+                 name UTF8String (SIZE(1..8)),
+                 items SEQUENCE (SIZE(1..4)) OF INTEGER (0..7),
+                 flag BOOLEAN
+             }",
+            "Message ::= SEQUENCE {
+                 id INTEGER,
+                 name UTF8String (SIZE(1..16)),
+                 items SEQUENCE (SIZE(1..4)) OF INTEGER (0..7, ...),
+                 flag NULL
+             }",
+        );
+        assert_eq!(
+            "breaking: Cam.Message.id: became mandatory\n\
+             breaking: Cam.Message.name: size widened from size(1..8) to size(1..16)\n\
+             breaking: Cam.Message.items[]: extensibility changed from 0..7 to 0..7, ...\n\
+             breaking: Cam.Message.flag: type changed from BOOLEAN to NULL\n",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn test_removed_and_added_modules() {
+        let old = resolve("Old DEFINITIONS AUTOMATIC TAGS ::= BEGIN END");
+        let new = resolve("New DEFINITIONS AUTOMATIC TAGS ::= BEGIN END");
+        assert_eq!(
+            "breaking: Old: module removed\nextension: New: module added\n",
+            compare(&old, &new).to_string()
+        );
+    }
+}
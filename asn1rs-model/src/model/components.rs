@@ -1,15 +1,22 @@
-use crate::model::lor::{Error as ResolveError, Resolved, Resolver};
-use crate::model::lor::{ResolveState, Unresolved};
-use crate::model::{Asn, Error, Field, Model, PeekableTokens, Type};
+use crate::model::lor::{Error as ResolveError, LitOrRef, Resolved, Resolver};
+use crate::model::lor::{ResolveState, TagEnvironment, Unresolved};
+use crate::model::{Asn, Error, Field, Model, PeekableTokens, TagDefault, TagProperty, Type};
 use crate::parser::Token;
 use std::convert::TryFrom;
 use std::iter::Peekable;
 
 /// ITU-T X.680 | ISO/IEC 8824-1:2015, Annex L
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComponentTypeList<RS: ResolveState = Unresolved> {
     pub fields: Vec<Field<Asn<RS>>>,
     pub extension_after: Option<usize>,
+    /// Indices of the fields that are placeholders for `COMPONENTS OF` notations. The name of
+    /// such a field is the name of the referenced type. These placeholders are replaced by the
+    /// components of the referenced type while resolving.
+    ///
+    /// ITU-T X.680 | ISO/IEC 8824-1:2015, 25.4
+    pub components_of: Vec<usize>,
 }
 
 impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<Unresolved> {
@@ -20,6 +27,7 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<
         let mut sequence = Self {
             fields: Vec::default(),
             extension_after: None,
+            components_of: Vec::default(),
         };
 
         loop {
@@ -31,6 +39,21 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<
                 let field_len = sequence.fields.len();
                 sequence.extension_after = Some(field_len.saturating_sub(1));
 
+                match iter.next_or_err()? {
+                    token if token.eq_separator(',') => true,
+                    token if token.eq_separator('}') => false,
+                    token => return Err(Error::unexpected_token(token)),
+                }
+            } else if iter.peek_is_text_eq("COMPONENTS") {
+                iter.next_text_eq_ignore_case_or_err("COMPONENTS")?;
+                iter.next_text_eq_ignore_case_or_err("OF")?;
+                let name = iter.next_text_or_err()?;
+                sequence.components_of.push(sequence.fields.len());
+                sequence.fields.push(Field {
+                    name: name.clone(),
+                    role: Type::TypeReference(name, None).untagged(),
+                });
+
                 match iter.next_or_err()? {
                     token if token.eq_separator(',') => true,
                     token if token.eq_separator('}') => false,
@@ -51,23 +74,74 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ComponentTypeList<
     }
 }
 
+impl<RS: ResolveState> ComponentTypeList<RS> {
+    /// The components that are not extension additions
+    pub fn root_fields(&self) -> &[Field<Asn<RS>>] {
+        let len = self
+            .extension_after
+            .map(|index| index + 1)
+            .unwrap_or(self.fields.len())
+            .min(self.fields.len());
+        &self.fields[..len]
+    }
+}
+
 impl ComponentTypeList<Unresolved> {
     pub fn try_resolve<
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ComponentTypeList<Resolved>>
+            + Resolver<Asn<Resolved>>
+            + TagEnvironment,
     >(
         &self,
         resolver: &R,
     ) -> Result<ComponentTypeList<Resolved>, ResolveError> {
-        Ok(ComponentTypeList {
-            fields: self
+        // ITU-T X.680 | ISO/IEC 8824-1:2015, 25.3: whether automatic tagging is applied is
+        // decided before the COMPONENTS OF transformation, in the AUTOMATIC TAGS environment only
+        let automatic_tagging = resolver.tag_default() == TagDefault::Automatic
+            && self
                 .fields
                 .iter()
-                .map(|f| f.try_resolve(resolver))
-                .collect::<Result<Vec<_>, _>>()?,
-            extension_after: self.extension_after,
+                .enumerate()
+                .filter(|(index, _)| !self.components_of.contains(index))
+                .all(|(_, field)| field.role.tag.is_none());
+
+        let mut fields = Vec::with_capacity(self.fields.len());
+        // the number of root fields after the expansion, the indices of the placeholders are
+        // compared against the extension marker before any expansion
+        let mut root_len = self.extension_after.map(|after| after + 1);
+
+        for (index, field) in self.fields.iter().enumerate() {
+            if self.components_of.contains(&index) {
+                let referenced: ComponentTypeList<Resolved> =
+                    resolver.resolve(&LitOrRef::Ref(field.name.clone()))?;
+                let inserted = referenced.root_fields();
+
+                if let (Some(after), Some(len)) = (self.extension_after, root_len.as_mut()) {
+                    if index <= after {
+                        // the placeholder itself is replaced
+                        *len = *len + inserted.len() - 1;
+                    }
+                }
+
+                fields.extend(inserted.iter().cloned().map(|mut field| {
+                    if automatic_tagging {
+                        field.reset_tag();
+                    }
+                    field
+                }));
+            } else {
+                fields.push(field.try_resolve(resolver)?);
+            }
+        }
+
+        Ok(ComponentTypeList {
+            fields,
+            extension_after: root_len.map(|len| len.saturating_sub(1)),
+            components_of: Vec::default(),
         })
     }
 }
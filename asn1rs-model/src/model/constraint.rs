@@ -0,0 +1,284 @@
+use crate::gen::asn::{permitted_alphabet_constraint, range_constraint, size_constraint};
+use crate::model::lor::Error as ResolveError;
+use crate::model::lor::{ResolveState, Resolved, Resolver, TryResolve, Unresolved};
+use crate::model::{
+    Asn, BitString, Charset, Error, Integer, LiteralValue, Model, PeekableTokens, Range, Size, Type,
+};
+use crate::parser::Token;
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+
+/// Additional subtype constraint of a type reference, such as the `SIZE(1..8)` in
+/// `ShortName ::= Name (SIZE(1..8))` or the `FROM("A".."Z")` in
+/// `Code ::= Name (FROM("A".."Z"))`. While resolving, it is combined with the constraints of
+/// the referenced type to the effective (PER-visible) constraint.
+///
+/// ITU-T X.680 | ISO/IEC 8824-1:2015, 49.6
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Constraint<RS: ResolveState = Resolved> {
+    pub range: Range<Option<RS::RangeType>>,
+    pub size: Size<RS::SizeType>,
+    /// The sorted characters of a permitted alphabet constraint
+    pub alphabet: Option<String>,
+}
+
+/// The constraint of a [`Type::ConstrainedTypeReference`] in a resolved model, which cannot
+/// exist: while resolving, the constraint is applied to the referenced type instead.
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Applied {}
+
+impl Display for Applied {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {}
+    }
+}
+
+/// Writes the constraint in the ASN.1 notation, such as `(SIZE(1..8))(FROM("AZ"))`
+impl<RS: ResolveState> Display for Constraint<RS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(size) = size_constraint(&self.size) {
+            write!(f, "({})", size)?;
+        }
+        if let Some(range) = range_constraint(&self.range) {
+            write!(f, "({})", range)?;
+        }
+        if let Some(alphabet) = &self.alphabet {
+            write!(f, "({})", permitted_alphabet_constraint(alphabet))?;
+        }
+        Ok(())
+    }
+}
+
+impl<RS: ResolveState> Default for Constraint<RS> {
+    fn default() -> Self {
+        Self {
+            range: Range::none(),
+            size: Size::Any,
+            alphabet: None,
+        }
+    }
+}
+
+/// Expects the opening parenthesis to be consumed already, consumes the closing parenthesis
+impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Constraint<Unresolved> {
+    type Error = Error;
+
+    fn try_from(iter: &mut Peekable<T>) -> Result<Self, Self::Error> {
+        if iter.next_is_text_and_eq_ignore_case("FROM") {
+            let alphabet = Self::read_permitted_alphabet(iter)?;
+            iter.next_separator_eq_or_err(')')?;
+            Ok(Self {
+                alphabet: Some(alphabet),
+                ..Default::default()
+            })
+        } else if iter.peek_is_text_eq_ignore_case("SIZE") {
+            let size = Size::try_from(&mut *iter)?;
+            iter.next_separator_eq_or_err(')')?;
+            Ok(Self {
+                size,
+                ..Default::default()
+            })
+        } else {
+            Ok(Self {
+                range: Integer::read_range(iter)?,
+                ..Default::default()
+            })
+        }
+    }
+}
+
+impl Constraint<Unresolved> {
+    /// Reads the parenthesized characters of a permitted alphabet, such as
+    /// `("A".."Z" | "0".."9" | "-_")`, into the sorted characters it permits
+    fn read_permitted_alphabet<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<String, Error> {
+        iter.next_separator_eq_or_err('(')?;
+        let mut alphabet = BTreeSet::new();
+        loop {
+            let (token, first) = Self::read_characters(iter)?;
+            if iter.next_is_separator_and_eq('.') {
+                iter.next_separator_eq_or_err('.')?;
+                let (_, last) = Self::read_characters(iter)?;
+                let mut first_chars = first.chars();
+                let mut last_chars = last.chars();
+                match (
+                    first_chars.next(),
+                    first_chars.next(),
+                    last_chars.next(),
+                    last_chars.next(),
+                ) {
+                    (Some(first), None, Some(last), None) if first <= last => {
+                        alphabet.extend(first..=last)
+                    }
+                    _ => return Err(Error::unsupported_constraint(token)),
+                }
+            } else {
+                alphabet.extend(first.chars());
+            }
+            if iter.next_is_separator_and_eq(')') {
+                return Ok(alphabet.into_iter().collect());
+            } else if !iter.next_is_text_and_eq_ignore_case("|")
+                && !iter.next_is_text_and_eq_ignore_case("UNION")
+            {
+                return Err(Error::unexpected_token(iter.next_or_err()?));
+            }
+        }
+    }
+
+    /// Reads a character string literal, such as `"A"`
+    fn read_characters<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<(Token, String), Error> {
+        let token = iter.peek_or_err()?.clone();
+        match Model::<Asn<Unresolved>>::read_literal(iter)? {
+            LiteralValue::String(string) => Ok((token, string)),
+            _ => Err(Error::unsupported_constraint(token)),
+        }
+    }
+
+    /// Combines this constraint with the serial constraint `other`, if they do not both
+    /// constrain the size, the range or the permitted alphabet
+    pub fn and(self, other: Self) -> Option<Self> {
+        let range = match (self.range == Range::none(), other.range == Range::none()) {
+            (_, true) => self.range,
+            (true, false) => other.range,
+            (false, false) => return None,
+        };
+        let size = match (self.size, other.size) {
+            (size, Size::Any) | (Size::Any, size) => size,
+            _ => return None,
+        };
+        let alphabet = match (self.alphabet, other.alphabet) {
+            (alphabet, None) | (None, alphabet) => alphabet,
+            _ => return None,
+        };
+        Some(Self {
+            range,
+            size,
+            alphabet,
+        })
+    }
+
+    pub fn try_resolve<
+        R: Resolver<<Resolved as ResolveState>::SizeType>
+            + Resolver<<Resolved as ResolveState>::RangeType>,
+    >(
+        &self,
+        resolver: &R,
+    ) -> Result<Constraint<Resolved>, ResolveError> {
+        Ok(Constraint {
+            range: Integer::with_range(self.range.clone())
+                .try_resolve(resolver)?
+                .range,
+            size: self.size.try_resolve(resolver)?,
+            alphabet: self.alphabet.clone(),
+        })
+    }
+}
+
+impl Constraint<Resolved> {
+    /// Applies this constraint to the given type, which is referenced by the given name. Fails
+    /// if the type cannot be constrained by this constraint or if no value would satisfy both,
+    /// the constraint of the type and this constraint.
+    pub fn apply_to(&self, name: &str, r#type: Type) -> Result<Type, ResolveError> {
+        let has_range = self.range != Range::none();
+        let has_size = self.size != Size::Any;
+        // only character strings can be constrained by a permitted alphabet
+        let sized = !has_range && self.alphabet.is_none();
+        let size = |size| {
+            self.intersect_size(size)
+                .ok_or_else(|| ResolveError::EmptyConstraint(name.to_string()))
+        };
+        Ok(match r#type {
+            Type::Integer(Integer { range, constants }) if !has_size && self.alphabet.is_none() => {
+                Type::Integer(Integer {
+                    range: self
+                        .intersect_range(range)
+                        .ok_or_else(|| ResolveError::EmptyConstraint(name.to_string()))?,
+                    constants,
+                })
+            }
+            Type::String(s, charset, alphabet) if !has_range => Type::String(
+                size(s)?,
+                charset,
+                self.intersect_alphabet(charset, alphabet)
+                    .ok_or_else(|| ResolveError::EmptyConstraint(name.to_string()))?,
+            ),
+            Type::OctetString(s) if sized => Type::OctetString(size(s)?),
+            Type::BitString(BitString { size: s, constants }) if sized => {
+                Type::BitString(BitString {
+                    size: size(s)?,
+                    constants,
+                })
+            }
+            Type::SequenceOf(inner, s) if sized => Type::SequenceOf(inner, size(s)?),
+            Type::SetOf(inner, s) if sized => Type::SetOf(inner, size(s)?),
+            _ => return Err(ResolveError::FailedToApplyConstraint(name.to_string())),
+        })
+    }
+
+    /// The intersection of both ranges, [`None`] if it is empty
+    fn intersect_range(&self, range: Range<Option<i64>>) -> Option<Range<Option<i64>>> {
+        if self.range == Range::none() {
+            return Some(range);
+        }
+        let min = match (range.0, self.range.0) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        let max = match (range.1, self.range.1) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        if matches!((min, max), (Some(min), Some(max)) if min > max) {
+            return None;
+        }
+        // ITU-T X.680 | ISO/IEC 8824-1:2015, 49.8: only the extensibility of the last
+        // constraint applies
+        Some(Range(min, max, self.range.2))
+    }
+
+    /// The characters of this permitted alphabet that are valid in the charset and within the
+    /// given permitted alphabet, [`None`] if there are none
+    fn intersect_alphabet(
+        &self,
+        charset: Charset,
+        alphabet: Option<String>,
+    ) -> Option<Option<String>> {
+        let permitted = match &self.alphabet {
+            None => return Some(alphabet),
+            Some(permitted) => permitted,
+        };
+        let intersection = permitted
+            .chars()
+            .filter(|char| charset.is_valid(*char))
+            .filter(|char| !matches!(&alphabet, Some(a) if !a.contains(*char)))
+            .collect::<String>();
+        if intersection.is_empty() {
+            None
+        } else {
+            Some(Some(intersection))
+        }
+    }
+
+    /// The intersection of both sizes, [`None`] if it is empty
+    fn intersect_size(&self, size: Size) -> Option<Size> {
+        if self.size == Size::Any {
+            return Some(size);
+        }
+        let min = (*size.min().unwrap_or(&0)).max(*self.size.min().unwrap_or(&0));
+        let max = match (size.max(), self.size.max()) {
+            (Some(a), Some(b)) => *a.min(b),
+            (a, b) => *a.or(b).unwrap_or(&(i64::MAX as usize)),
+        };
+        if min > max {
+            return None;
+        }
+        Some(Size::Range(min, max, self.size.extensible()).reconsider_constraints())
+    }
+}
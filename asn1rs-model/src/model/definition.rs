@@ -1,6 +1,7 @@
 use crate::model::{Asn, Rust, Tag, TagProperty};
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Definition<T>(pub String, pub T);
 
 impl<T> Definition<T> {
@@ -53,6 +54,14 @@ impl TagProperty for Definition<Asn> {
     }
 }
 
+impl Definition<Rust> {
+    /// The name of the definition in the ASN.1 module, which differs from [`Definition::name`]
+    /// if it was changed to be a valid Rust identifier
+    pub fn asn_name(&self) -> &str {
+        self.1.asn_name().unwrap_or(&self.0)
+    }
+}
+
 impl TagProperty for Definition<Rust> {
     fn tag(&self) -> Option<Tag> {
         self.1.tag()
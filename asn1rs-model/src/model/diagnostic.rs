@@ -0,0 +1,278 @@
+use crate::model::lor::Error as ResolveError;
+use crate::model::{Error, Source};
+use crate::parser::{Location, Token};
+use std::fmt::Write;
+
+/// Builtin type names, that are suggested for unresolvable type references
+pub(crate) const BUILTIN_TYPES: &[&str] = &[
+    "BIT STRING",
+    "BOOLEAN",
+    "BMPString",
+    "CHOICE",
+    "ENUMERATED",
+    "IA5String",
+    "INTEGER",
+    "NULL",
+    "NumericString",
+    "OCTET STRING",
+    "PrintableString",
+    "SEQUENCE",
+    "SET",
+    "UTF8String",
+    "VisibleString",
+];
+
+/// A human readable description of an [`Error`] or [`ResolveError`] that can be rendered with
+/// the offending line of the ASN.1 source and a hint on how to fix it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    message: String,
+    location: Option<Location>,
+    width: usize,
+    help: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+            width: 1,
+            help: None,
+        }
+    }
+
+    /// Points the diagnostic to the given [`Token`] in the source
+    pub fn with_token(mut self, token: &Token) -> Self {
+        self.location = Some(token.location());
+        self.width = match token {
+            Token::Text(_, text) | Token::Comment(_, text) => text.chars().count().max(1),
+            Token::Separator(..) => 1,
+        };
+        self
+    }
+
+    /// Points the diagnostic to the given location in the source
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self.width = 1;
+        self
+    }
+
+    pub fn with_help<S: Into<String>>(mut self, help: S) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Adds a "did you mean" hint if one of the candidates is similar to the given name
+    pub fn with_suggestion<'a, I: IntoIterator<Item = &'a str>>(
+        self,
+        name: &str,
+        candidates: I,
+    ) -> Self {
+        match did_you_mean(name, candidates) {
+            Some(suggestion) => self.with_help(format!("did you mean `{}`?", suggestion)),
+            None => self,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        self.location
+    }
+
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    /// Renders the diagnostic like
+    ///
+    /// ```text
+    /// error: expected a separator like '=', but instead got: ':'
+    ///  --> schema.asn:3:13
+    ///   |
+    /// 3 |     Value ::: INTEGER
+    ///   |             ^
+    /// ```
+    ///
+    /// The source line and caret are omitted if the location is unknown or not within `source`.
+    pub fn render(&self, file_name: &str, source: &str) -> String {
+        let mut out = format!("error: {}\n", self.message);
+        let line = self.location.and_then(|location| {
+            source
+                .lines()
+                .nth(location.line().checked_sub(1)?)
+                .map(|line| (location, line))
+        });
+
+        let gutter = match self.location {
+            Some(location) => location.line().to_string().len(),
+            None => 1,
+        };
+
+        match line {
+            Some((location, line)) => {
+                let _ = writeln!(
+                    out,
+                    "{:gutter$}--> {}:{}:{}",
+                    "",
+                    file_name,
+                    location.line(),
+                    location.column(),
+                    gutter = gutter
+                );
+                let _ = writeln!(out, "{:gutter$} |", "", gutter = gutter);
+                let _ = writeln!(out, "{} | {}", location.line(), line);
+                let indent = line
+                    .chars()
+                    .take(location.column().saturating_sub(1))
+                    .map(|c| if c == '\t' { '\t' } else { ' ' })
+                    .collect::<String>();
+                let _ = writeln!(
+                    out,
+                    "{:gutter$} | {}{}",
+                    "",
+                    indent,
+                    "^".repeat(self.width),
+                    gutter = gutter
+                );
+            }
+            None => {
+                let _ = writeln!(out, "{:gutter$}--> {}", "", file_name, gutter = gutter);
+            }
+        }
+
+        if let Some(help) = &self.help {
+            let _ = writeln!(out, "{:gutter$} = help: {}", "", help, gutter = gutter);
+        }
+        out
+    }
+}
+
+impl From<&Error> for Diagnostic {
+    fn from(error: &Error) -> Self {
+        let diagnostic = Diagnostic::new(error.description());
+        match error.token() {
+            Some(token) => diagnostic.with_token(token),
+            None => diagnostic,
+        }
+    }
+}
+
+impl From<&ResolveError> for Diagnostic {
+    fn from(error: &ResolveError) -> Self {
+        let diagnostic = Diagnostic::new(error.kind().to_string());
+        match error.origin().and_then(Source::location) {
+            Some(location) => diagnostic.with_location(location),
+            None => diagnostic,
+        }
+    }
+}
+
+/// Finds the candidate that is most similar to the given name, if any is similar enough to be
+/// a likely misspelling of it. Candidates that only differ in case are always suggested.
+pub fn did_you_mean<'a, I: IntoIterator<Item = &'a str>>(
+    name: &str,
+    candidates: I,
+) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter(|candidate| !candidate.eq(&name))
+        .filter_map(|candidate| {
+            if candidate.eq_ignore_ascii_case(name) {
+                Some((0, candidate))
+            } else {
+                let distance = edit_distance(name, candidate);
+                if distance <= max_distance {
+                    Some((distance, candidate))
+                } else {
+                    None
+                }
+            }
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The Levenshtein distance between the two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Asn, Model};
+    use crate::parser::Tokenizer;
+
+    #[test]
+    fn test_render_with_source_line_and_caret() {
+        let source = "Schema DEFINITIONS AUTOMATIC TAGS ::= BEGIN\n    Value ::: INTEGER\nEND";
+        let error = Model::<Asn<_>>::try_from(Tokenizer::default().parse(source)).unwrap_err();
+        assert_eq!(
+            "error: expected a separator like '=', but instead got: ':'\n \
+             --> schema.asn:2:13\n  \
+             |\n\
+             2 |     Value ::: INTEGER\n  \
+             |             ^\n",
+            Diagnostic::from(&error).render("schema.asn", source)
+        );
+    }
+
+    #[test]
+    fn test_render_without_location_but_with_help() {
+        assert_eq!(
+            "error: Failed to resolve type with name: INTEGR\n \
+             --> schema.asn\n  \
+             = help: did you mean `INTEGER`?\n",
+            Diagnostic::from(&ResolveError::FailedToResolveType("INTEGR".to_string()))
+                .with_suggestion("INTEGR", BUILTIN_TYPES.iter().copied())
+                .render("schema.asn", "")
+        );
+    }
+
+    #[test]
+    fn test_caret_spans_text_token() {
+        let token = Token::Text(Location::at(1, 5), "Wrong".to_string());
+        assert!(Diagnostic::new("unknown")
+            .with_token(&token)
+            .render("a.asn", "    Wrong ::= NULL")
+            .ends_with("1 |     Wrong ::= NULL\n  |     ^^^^^\n"));
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        let candidates = ["Position", "Latitude", "max-value"];
+        assert_eq!(
+            Some("Position"),
+            did_you_mean("Postion", candidates.iter().copied())
+        );
+        assert_eq!(
+            Some("Latitude"),
+            did_you_mean("latitude", candidates.iter().copied())
+        );
+        assert_eq!(
+            Some("max-value"),
+            did_you_mean("maxValue", candidates.iter().copied())
+        );
+        assert_eq!(None, did_you_mean("Speed", candidates.iter().copied()));
+        assert_eq!(None, did_you_mean("Position", candidates.iter().copied()));
+    }
+}
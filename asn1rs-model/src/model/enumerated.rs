@@ -1,9 +1,10 @@
-use crate::model::{Error, PeekableTokens};
+use crate::model::{Error, IgnoredInComparison, PeekableTokens, Source};
 use crate::parser::Token;
 use std::convert::TryFrom;
 use std::iter::Peekable;
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enumerated {
     variants: Vec<EnumeratedVariant>,
     extension_after: Option<usize>,
@@ -55,6 +56,10 @@ impl Enumerated {
         self.variants.iter()
     }
 
+    pub fn variants_mut(&mut self) -> impl Iterator<Item = &mut EnumeratedVariant> {
+        self.variants.iter_mut()
+    }
+
     pub fn is_extensible(&self) -> bool {
         self.extension_after.is_some()
     }
@@ -87,13 +92,17 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Enumerated {
                     loop_ctrl_separator!(iter.next_or_err()?);
                 }
             } else {
+                let source = iter
+                    .peek()
+                    .map(|token| Source::new(token.location()))
+                    .unwrap_or_default();
                 let variant_name = iter.next_text_or_err()?;
                 let token = iter.next_or_err()?;
 
                 if token.eq_separator(',') || token.eq_separator('}') {
                     enumerated
                         .variants
-                        .push(EnumeratedVariant::from_name(variant_name));
+                        .push(EnumeratedVariant::from_name(variant_name).with_source(source));
                     loop_ctrl_separator!(token);
                 } else if token.eq_separator('(') {
                     let token = iter.next_or_err()?;
@@ -102,9 +111,10 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Enumerated {
                         .and_then(|t| t.parse::<usize>().ok())
                         .ok_or_else(|| Error::invalid_number_for_enum_variant(token))?;
                     iter.next_separator_eq_or_err(')')?;
-                    enumerated
-                        .variants
-                        .push(EnumeratedVariant::from_name_number(variant_name, number));
+                    enumerated.variants.push(
+                        EnumeratedVariant::from_name_number(variant_name, number)
+                            .with_source(source),
+                    );
                     loop_ctrl_separator!(iter.next_or_err()?);
                 } else {
                     loop_ctrl_separator!(token);
@@ -117,9 +127,12 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Enumerated {
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnumeratedVariant {
     pub(crate) name: String,
     pub(crate) number: Option<usize>,
+    pub(crate) comment: Option<String>,
+    pub(crate) source: IgnoredInComparison<Source>,
 }
 
 #[cfg(test)]
@@ -134,6 +147,8 @@ impl EnumeratedVariant {
         Self {
             name: name.to_string(),
             number: None,
+            comment: None,
+            source: IgnoredInComparison::default(),
         }
     }
 
@@ -141,6 +156,8 @@ impl EnumeratedVariant {
         Self {
             name: name.to_string(),
             number: Some(number),
+            comment: None,
+            source: IgnoredInComparison::default(),
         }
     }
 
@@ -160,4 +177,17 @@ impl EnumeratedVariant {
     pub fn number(&self) -> Option<usize> {
         self.number
     }
+
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    pub(crate) fn with_source(mut self, source: Source) -> Self {
+        self.source = source.into();
+        self
+    }
 }
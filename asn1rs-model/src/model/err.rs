@@ -22,6 +22,7 @@ pub enum ErrorKind {
     InvalidIntText(Token),
     UnsupportedLiteral(Token),
     InvalidLiteral(Token),
+    UnsupportedConstraint(Token),
 }
 
 pub struct Error {
@@ -101,6 +102,68 @@ impl Error {
         ErrorKind::UnsupportedLiteral(token).into()
     }
 
+    pub fn unsupported_constraint(token: Token) -> Self {
+        ErrorKind::UnsupportedConstraint(token).into()
+    }
+
+    /// The message of this error without the location of the offending [`Token`]
+    pub fn description(&self) -> String {
+        match &self.kind {
+            ErrorKind::ExpectedText(token) => {
+                format!("expected text, but instead got: {}", token)
+            }
+            ErrorKind::ExpectedTextGot(text, token) => format!(
+                "expected a text like \"{}\", but instead got: {}",
+                text, token,
+            ),
+            ErrorKind::ExpectedSeparator(token) => {
+                format!("expected separator, but instead got: {}", token)
+            }
+            ErrorKind::ExpectedSeparatorGot(separator, token) => format!(
+                "expected a separator like '{}', but instead got: {}",
+                separator, token,
+            ),
+            ErrorKind::UnexpectedToken(token) => {
+                format!("an unexpected token was encountered: {}", token)
+            }
+            ErrorKind::MissingModuleName => {
+                "The ASN definition is missing the module name".to_string()
+            }
+            ErrorKind::UnexpectedEndOfStream => "Unexpected end of stream or file".to_string(),
+            ErrorKind::InvalidRangeValue(token) => {
+                format!("an unexpected range value was encountered: {}", token)
+            }
+            ErrorKind::InvalidNumberForEnumVariant(token) => format!(
+                "an invalid value for an enum variant was encountered: {}",
+                token
+            ),
+            ErrorKind::InvalidValueForConstant(token) => format!(
+                "an invalid value for an constant value was encountered: {}",
+                token
+            ),
+            ErrorKind::InvalidTag(token) => {
+                format!("an invalid value for a tag was encountered: {}", token)
+            }
+            ErrorKind::InvalidPositionForExtensionMarker(_) => {
+                "an extension marker is present, which this is not allowed at that position"
+                    .to_string()
+            }
+            ErrorKind::InvalidIntText(token) => {
+                format!("a number was expected but instead got: {}", token)
+            }
+            ErrorKind::UnsupportedLiteral(token) => format!(
+                "an (yet) unsupported value reference literal was discovered: {}",
+                token
+            ),
+            ErrorKind::InvalidLiteral(token) => {
+                format!("an invalid literal was discovered: {}", token)
+            }
+            ErrorKind::UnsupportedConstraint(token) => {
+                format!("an (yet) unsupported constraint was discovered: {}", token)
+            }
+        }
+    }
+
     fn backtrace(&self) -> &Backtrace {
         &self.backtrace
     }
@@ -122,6 +185,7 @@ impl Error {
             ErrorKind::InvalidIntText(t) => Some(t),
             ErrorKind::UnsupportedLiteral(t) => Some(t),
             ErrorKind::InvalidLiteral(t) => Some(t),
+            ErrorKind::UnsupportedConstraint(t) => Some(t),
         }
     }
 }
@@ -138,103 +202,15 @@ impl Debug for Error {
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        match &self.kind {
-            ErrorKind::ExpectedText(token) => write!(
-                f,
-                "At line {}, column {} expected text, but instead got: {}",
-                token.location().line(),
-                token.location().column(),
-                token,
-            ),
-            ErrorKind::ExpectedTextGot(text, token) => write!(
+        match self.token() {
+            Some(token) => write!(
                 f,
-                "At line {}, column {} expected a text like \"{}\", but instead got: {}",
+                "At line {}, column {} {}",
                 token.location().line(),
                 token.location().column(),
-                text,
-                token,
-            ),
-            ErrorKind::ExpectedSeparator(token) => write!(
-                f,
-                "At line {}, column {} expected separator, but instead got: {}",
-                token.location().line(),
-                token.location().column(),
-                token,
-            ),
-            ErrorKind::ExpectedSeparatorGot(separator, token) => write!(
-                f,
-                "At line {}, column {} expected a separator like '{}', but instead got: {}",
-                token.location().line(),
-                token.location().column(),
-                separator,
-                token,
-            ),
-            ErrorKind::UnexpectedToken(token) => write!(
-                f,
-                "At line {}, column {} an unexpected token was encountered: {}",
-                token.location().line(),
-                token.location().column(),
-                token,
-            ),
-            ErrorKind::MissingModuleName => {
-                writeln!(f, "The ASN definition is missing the module name")
-            }
-            ErrorKind::UnexpectedEndOfStream => write!(f, "Unexpected end of stream or file"),
-            ErrorKind::InvalidRangeValue(token) => write!(
-                f,
-                "At line {}, column {} an unexpected range value was encountered: {}",
-                token.location().line(),
-                token.location().column(),
-                token,
-            ),
-            ErrorKind::InvalidNumberForEnumVariant(token) => write!(
-                f,
-                "At line {}, column {} an invalid value for an enum variant was encountered: {}",
-                token.location().line(),
-                token.location().column(),
-                token,
-            ),
-            ErrorKind::InvalidValueForConstant(token) => write!(
-                f,
-                "At line {}, column {} an invalid value for an constant value was encountered: {}",
-                token.location().line(),
-                token.location().column(),
-                token,
-            ),
-            ErrorKind::InvalidTag(token) => write!(
-                f,
-                "At line {}, column {} an invalid value for a tag was encountered: {}",
-                token.location().line(),
-                token.location().column(),
-                token,
-            ),
-            ErrorKind::InvalidPositionForExtensionMarker(token) => write!(
-                f,
-                "At line {}, column {} an extension marker is present, which this is not allowed at that position",
-                token.location().line(),
-                token.location().column(),
-            ),
-            ErrorKind::InvalidIntText(token) => write!(
-                f,
-                "At line {}, column {} a number was expected but instead got: {}",
-                token.location().line(),
-                token.location().column(),
-                token
-            ),
-            ErrorKind::UnsupportedLiteral(token) => write!(
-                f,
-                "At line {}, column {} an (yet) unsupported value reference literal was discovered: {}",
-                token.location().line(),
-                token.location().column(),
-                token
-            ),
-            ErrorKind::InvalidLiteral(token) => write!(
-                f,
-                "At line {}, column {} an invalid literal was discovered: {}",
-                token.location().line(),
-                token.location().column(),
-                token
+                self.description()
             ),
+            None => write!(f, "{}", self.description()),
         }
     }
 }
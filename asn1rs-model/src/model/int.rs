@@ -7,6 +7,7 @@ use std::fmt::{Debug, Display};
 use std::iter::Peekable;
 
 #[derive(Default, Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Integer<T: Display + Debug + Clone = i64> {
     pub range: Range<Option<T>>,
     pub constants: Vec<(String, i64)>,
@@ -30,45 +31,54 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>>
         let constants =
             Model::<Asn>::maybe_read_constants(iter, Model::<Asn>::constant_i64_parser)?;
         let range = if iter.next_is_separator_and_eq('(') {
-            let start = iter.next_or_err()?;
+            Self::read_range(iter)?
+        } else {
+            Range(None, None, false)
+        };
+        Ok(Self { range, constants })
+    }
+}
+
+impl Integer<<Unresolved as ResolveState>::RangeType> {
+    /// Expects the opening parenthesis to be consumed already, consumes the closing parenthesis
+    pub(crate) fn read_range<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<Range<Option<<Unresolved as ResolveState>::RangeType>>, Error> {
+        let start = iter.next_or_err()?;
+        iter.next_separator_eq_or_err('.')?;
+        iter.next_separator_eq_or_err('.')?;
+        let end = iter.next_or_err()?;
+        let extensible = if iter.next_is_separator_and_eq(',') {
+            iter.next_separator_eq_or_err('.')?;
             iter.next_separator_eq_or_err('.')?;
             iter.next_separator_eq_or_err('.')?;
-            let end = iter.next_or_err()?;
-            let extensible = if iter.next_is_separator_and_eq(',') {
-                iter.next_separator_eq_or_err('.')?;
-                iter.next_separator_eq_or_err('.')?;
-                iter.next_separator_eq_or_err('.')?;
-                true
-            } else {
-                false
-            };
-            iter.next_separator_eq_or_err(')')?;
-            let start = start
-                .text()
-                .filter(|txt| !txt.eq_ignore_ascii_case("MIN"))
-                .map(|t| match t.parse::<i64>() {
-                    Ok(lit) => LitOrRef::Lit(lit),
-                    Err(_) => LitOrRef::Ref(t.to_string()),
-                });
+            true
+        } else {
+            false
+        };
+        iter.next_separator_eq_or_err(')')?;
+        let start = start
+            .text()
+            .filter(|txt| !txt.eq_ignore_ascii_case("MIN"))
+            .map(|t| match t.parse::<i64>() {
+                Ok(lit) => LitOrRef::Lit(lit),
+                Err(_) => LitOrRef::Ref(t.to_string()),
+            });
 
-            let end = end
-                .text()
-                .filter(|txt| !txt.eq_ignore_ascii_case("MAX"))
-                .map(|t| match t.parse::<i64>() {
-                    Ok(lit) => LitOrRef::Lit(lit),
-                    Err(_) => LitOrRef::Ref(t.to_string()),
-                });
+        let end = end
+            .text()
+            .filter(|txt| !txt.eq_ignore_ascii_case("MAX"))
+            .map(|t| match t.parse::<i64>() {
+                Ok(lit) => LitOrRef::Lit(lit),
+                Err(_) => LitOrRef::Ref(t.to_string()),
+            });
 
-            match (start, end) {
-                (Some(LitOrRef::Lit(0)), None) | (None, Some(LitOrRef::Lit(i64::MAX))) => {
-                    Range(None, None, extensible)
-                }
-                (start, end) => Range(start, end, extensible),
+        Ok(match (start, end) {
+            (Some(LitOrRef::Lit(0)), None) | (None, Some(LitOrRef::Lit(i64::MAX))) => {
+                Range(None, None, extensible)
             }
-        } else {
-            Range(None, None, false)
-        };
-        Ok(Self { range, constants })
+            (start, end) => Range(start, end, extensible),
+        })
     }
 }
 
@@ -88,6 +88,7 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for ValueConstraint {
                     }
                     string.push(separator);
                 }
+                Token::Comment(..) => {}
             }
         }
 
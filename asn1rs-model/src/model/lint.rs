@@ -0,0 +1,572 @@
+use crate::model::lor::Resolved;
+use crate::model::rust::{
+    rust_constant_name, rust_field_name, rust_struct_or_enum_name, rust_variant_name,
+};
+use crate::model::{Asn, ComponentTypeList, Definition, Exports, LiteralValue, Model, Range};
+use crate::model::{Size, Source, Type};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+/// The kinds of modeling mistakes found by [`lint`]
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Ord, Eq, Hash)]
+pub enum LintKind {
+    /// A range or size constraint whose lower bound is greater than its upper bound
+    InvalidRange,
+    /// A `DEFAULT` value that does not satisfy the constraint of its type
+    DefaultOutsideConstraint,
+    /// A definition that is neither referenced by any other definition nor exported, which can
+    /// only happen in modules with an explicit `EXPORTS` clause
+    UnusedDefinition,
+    /// Modules, definitions, fields, variants or value references whose names collide after
+    /// being converted to Rust names, like `foo-bar` and `foo_bar`
+    NameCollision,
+    /// An extension marker without any root components or followed by mandatory components
+    SuspiciousExtensionMarker,
+}
+
+impl Display for LintKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintKind::InvalidRange => write!(f, "invalid-range"),
+            LintKind::DefaultOutsideConstraint => write!(f, "default-outside-constraint"),
+            LintKind::UnusedDefinition => write!(f, "unused-definition"),
+            LintKind::NameCollision => write!(f, "name-collision"),
+            LintKind::SuspiciousExtensionMarker => write!(f, "suspicious-extension-marker"),
+        }
+    }
+}
+
+/// A warning about a likely modeling mistake
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub kind: LintKind,
+    pub module: String,
+    /// The affected definition and the path to the affected field or variant, like
+    /// `Position.lat`. Elements of a `SEQUENCE OF` or `SET OF` are denoted by `[]`.
+    pub path: String,
+    pub source: Source,
+    pub message: String,
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: warning[{}]: {}.{}: {}",
+            self.source, self.kind, self.module, self.path, self.message
+        )
+    }
+}
+
+/// Checks the resolved modules for common modeling mistakes, see [`LintKind`]. Because the
+/// modules are checked together, a definition is only unused if none of the modules refers to
+/// it.
+pub fn lint(models: &[Model<Asn<Resolved>>]) -> Vec<Lint> {
+    let mut linter = Linter {
+        models,
+        module: "",
+        lints: Vec::new(),
+    };
+
+    let names = models.iter().map(|model| model.name.as_str());
+    for (name, index) in collisions(names, rust_module_name) {
+        linter.lints.push(Lint {
+            kind: LintKind::NameCollision,
+            module: name.to_string(),
+            path: String::new(),
+            source: Source::clone(&models[index].source),
+            message: format!("collides with another module as {}", rust_module_name(name)),
+        });
+    }
+
+    let used = used_definitions(models);
+    for model in models {
+        linter.module = &model.name;
+        linter.lint_model(model, &used);
+    }
+
+    linter.lints
+}
+
+/// The module and name of all definitions that are referenced by any other definition or value
+/// reference
+fn used_definitions(models: &[Model<Asn<Resolved>>]) -> HashSet<(&str, &str)> {
+    let mut used = HashSet::new();
+    for model in models {
+        // a reference is either to a definition of the same module or to an imported one
+        let module_of = |reference: &str| {
+            model
+                .imports
+                .iter()
+                .find(|import| import.what.iter().any(|what| what == reference))
+                .map(|import| import.from.as_str())
+                .unwrap_or(model.name.as_str())
+        };
+        for Definition(name, asn) in &model.definitions {
+            used.extend(
+                asn.r#type
+                    .type_references()
+                    .into_iter()
+                    .filter(|reference| reference != name)
+                    .map(|reference| (module_of(reference), reference)),
+            );
+        }
+        for value_reference in &model.value_references {
+            used.extend(
+                value_reference
+                    .role
+                    .r#type
+                    .type_references()
+                    .into_iter()
+                    .map(|reference| (module_of(reference), reference)),
+            );
+        }
+    }
+    used
+}
+
+fn rust_module_name(name: &str) -> String {
+    crate::model::rust::rust_module_name(name, false)
+}
+
+/// The names (and their index) that result in the same mangled name as a previous name
+fn collisions<'a>(
+    names: impl Iterator<Item = &'a str>,
+    mangle: impl Fn(&str) -> String,
+) -> Vec<(&'a str, usize)> {
+    let mut seen = HashSet::new();
+    names
+        .enumerate()
+        .filter(|(_, name)| !seen.insert(mangle(name)))
+        .map(|(index, name)| (name, index))
+        .collect()
+}
+
+struct Linter<'a> {
+    models: &'a [Model<Asn<Resolved>>],
+    module: &'a str,
+    lints: Vec<Lint>,
+}
+
+impl Linter<'_> {
+    fn push<S: Into<String>>(&mut self, kind: LintKind, path: &str, source: &Source, message: S) {
+        self.lints.push(Lint {
+            kind,
+            module: self.module.to_string(),
+            path: path.to_string(),
+            source: source.clone(),
+            message: message.into(),
+        });
+    }
+
+    fn lint_model(&mut self, model: &Model<Asn<Resolved>>, used: &HashSet<(&str, &str)>) {
+        let names = model.definitions.iter().map(Definition::name);
+        for (name, index) in collisions(names, rust_struct_or_enum_name) {
+            self.push(
+                LintKind::NameCollision,
+                name,
+                &model.definitions[index].1.source,
+                format!(
+                    "collides with another definition as {}",
+                    rust_struct_or_enum_name(name)
+                ),
+            );
+        }
+
+        let names = model.value_references.iter().map(|v| v.name.as_str());
+        for (name, index) in collisions(names, rust_constant_name) {
+            self.push(
+                LintKind::NameCollision,
+                name,
+                &model.value_references[index].role.source,
+                format!(
+                    "collides with another value as {}",
+                    rust_constant_name(name)
+                ),
+            );
+        }
+
+        for Definition(name, asn) in &model.definitions {
+            let exported = match &model.exports {
+                Exports::All => true,
+                Exports::Symbols(symbols) => symbols.contains(name),
+            };
+            if !exported && !used.contains(&(model.name.as_str(), name.as_str())) {
+                self.push(
+                    LintKind::UnusedDefinition,
+                    name,
+                    &asn.source,
+                    "is neither used nor exported",
+                );
+            }
+            self.lint_asn(name, asn);
+        }
+    }
+
+    fn lint_asn(&mut self, path: &str, asn: &Asn<Resolved>) {
+        let r#type = asn.r#type.no_optional();
+        self.lint_type(path, &asn.source, r#type);
+        if let Some(default) = &asn.default {
+            self.lint_default(path, &asn.source, r#type, default);
+        }
+    }
+
+    fn lint_type(&mut self, path: &str, source: &Source, r#type: &Type<Resolved>) {
+        match r#type {
+            Type::Integer(integer) => self.lint_range(path, source, &integer.range),
+            Type::String(size, ..) | Type::OctetString(size) => self.lint_size(path, source, size),
+            Type::BitString(bit_string) => self.lint_size(path, source, &bit_string.size),
+            Type::Optional(inner) | Type::Default(inner, _) => self.lint_type(path, source, inner),
+            Type::Sequence(list) | Type::Set(list) => self.lint_components(path, source, list),
+            Type::SequenceOf(inner, size) | Type::SetOf(inner, size) => {
+                self.lint_size(path, source, size);
+                self.lint_type(&format!("{}[]", path), source, inner);
+            }
+            Type::Enumerated(enumerated) => {
+                let variants = enumerated.variants().collect::<Vec<_>>();
+                let names = variants.iter().map(|variant| variant.name());
+                for (name, index) in collisions(names, rust_variant_name) {
+                    self.push(
+                        LintKind::NameCollision,
+                        &format!("{}.{}", path, name),
+                        variants[index].source(),
+                        format!(
+                            "collides with another variant as {}",
+                            rust_variant_name(name)
+                        ),
+                    );
+                }
+            }
+            Type::Choice(choice) => {
+                let variants = choice.variants().collect::<Vec<_>>();
+                let names = variants.iter().map(|variant| variant.name.as_str());
+                for (name, index) in collisions(names, rust_variant_name) {
+                    self.push(
+                        LintKind::NameCollision,
+                        &format!("{}.{}", path, name),
+                        &variants[index].source,
+                        format!(
+                            "collides with another variant as {}",
+                            rust_variant_name(name)
+                        ),
+                    );
+                }
+                for variant in variants {
+                    let path = format!("{}.{}", path, variant.name);
+                    self.lint_type(&path, &variant.source, &variant.r#type);
+                }
+            }
+            Type::Boolean | Type::Null | Type::ObjectIdentifier | Type::TypeReference(..) => {}
+            Type::ConstrainedTypeReference(_, applied) => match *applied {},
+        }
+    }
+
+    fn lint_components(&mut self, path: &str, source: &Source, list: &ComponentTypeList<Resolved>) {
+        let names = list.fields.iter().map(|field| field.name.as_str());
+        for (name, index) in collisions(names, rust_field_name) {
+            self.push(
+                LintKind::NameCollision,
+                &format!("{}.{}", path, name),
+                &list.fields[index].role.source,
+                format!("collides with another field as {}", rust_field_name(name)),
+            );
+        }
+
+        if list.extension_after.is_some() {
+            if list.fields.is_empty() {
+                self.push(
+                    LintKind::SuspiciousExtensionMarker,
+                    path,
+                    source,
+                    "the extension marker is not preceded by any root component",
+                );
+            }
+            for field in &list.fields[list.root_fields().len()..] {
+                let optional =
+                    matches!(field.role.r#type, Type::Optional(_)) || field.role.default.is_some();
+                if !optional {
+                    self.push(
+                        LintKind::SuspiciousExtensionMarker,
+                        &format!("{}.{}", path, field.name),
+                        &field.role.source,
+                        "the extension addition is neither OPTIONAL nor has a DEFAULT value, \
+                         but is missing in encodings of peers that do not know it yet",
+                    );
+                }
+            }
+        }
+
+        for field in &list.fields {
+            self.lint_asn(&format!("{}.{}", path, field.name), &field.role);
+        }
+    }
+
+    fn lint_range(&mut self, path: &str, source: &Source, range: &Range<Option<i64>>) {
+        if let Range(Some(min), Some(max), _) = range {
+            if min > max {
+                self.push(
+                    LintKind::InvalidRange,
+                    path,
+                    source,
+                    format!(
+                        "the lower bound {} is greater than the upper bound {}",
+                        min, max
+                    ),
+                );
+            }
+        }
+    }
+
+    fn lint_size(&mut self, path: &str, source: &Source, size: &Size<usize>) {
+        if let Size::Range(min, max, _) = size {
+            if min > max {
+                self.push(
+                    LintKind::InvalidRange,
+                    path,
+                    source,
+                    format!(
+                        "the lower size bound {} is greater than the upper size bound {}",
+                        min, max
+                    ),
+                );
+            }
+        }
+    }
+
+    fn lint_default(
+        &mut self,
+        path: &str,
+        source: &Source,
+        r#type: &Type<Resolved>,
+        default: &LiteralValue,
+    ) {
+        let outside = match (self.underlying(r#type), default) {
+            (Type::Integer(integer), LiteralValue::Integer(value))
+                if !integer.range.extensible() =>
+            {
+                matches!(*integer.range.min(), Some(min) if *value < min)
+                    || matches!(*integer.range.max(), Some(max) if *value > max)
+            }
+            (Type::String(size, charset, alphabet), LiteralValue::String(value)) => {
+                charset.find_invalid(value).is_some()
+                    || matches!(alphabet, Some(alphabet) if value.chars().any(|c| !alphabet.contains(c)))
+                    || outside_size(size, value.chars().count())
+            }
+            (Type::OctetString(size), LiteralValue::OctetString(value)) => {
+                outside_size(size, value.len())
+            }
+            _ => false,
+        };
+        if outside {
+            self.push(
+                LintKind::DefaultOutsideConstraint,
+                path,
+                source,
+                format!(
+                    "the DEFAULT value {} does not satisfy the constraint",
+                    default
+                ),
+            );
+        }
+    }
+
+    /// The type that is referenced by the given type, if it is a [`Type::TypeReference`]
+    fn underlying<'a>(&'a self, mut r#type: &'a Type<Resolved>) -> &'a Type<Resolved> {
+        // the limit protects from (invalid) cyclic references
+        for _ in 0..16 {
+            match r#type {
+                Type::TypeReference(name, _) => {
+                    match self
+                        .models
+                        .iter()
+                        .flat_map(|model| model.definitions.iter())
+                        .find(|definition| definition.name() == name)
+                    {
+                        Some(definition) => r#type = definition.1.r#type.no_optional(),
+                        None => break,
+                    }
+                }
+                _ => break,
+            }
+        }
+        r#type
+    }
+}
+
+fn outside_size(size: &Size<usize>, len: usize) -> bool {
+    !size.extensible()
+        && (matches!(size.min(), Some(min) if len < *min)
+            || matches!(size.max(), Some(max) if len > *max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::MultiModuleResolver;
+    use crate::parser::Tokenizer;
+
+    fn lint_definitions(definitions: &str) -> Vec<String> {
+        let model = Model::try_from(Tokenizer::default().parse(&format!(
+            "Lint DEFINITIONS AUTOMATIC TAGS ::= BEGIN\n{}\nEND",
+            definitions
+        )))
+        .unwrap()
+        .try_resolve()
+        .unwrap();
+        lint(&[model]).iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_clean_module_has_no_lints() {
+        assert!(lint_definitions(
+            "EXPORTS Position;
+             Position ::= SEQUENCE { lat Latitude DEFAULT 0, ..., alt INTEGER OPTIONAL }
+             Latitude ::= INTEGER (-90..90)"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_invalid_ranges() {
+        assert_eq!(
+            vec![
+                "3:18: warning[invalid-range]: Lint.Inverted: the lower bound 10 is greater than the upper bound 1",
+                "4:18: warning[invalid-range]: Lint.Text: the lower size bound 8 is greater than the upper size bound 2",
+            ],
+            lint_definitions(
+                "EXPORTS Inverted, Text;
+                 Inverted ::= INTEGER (10..1)
+                 Text ::= UTF8String (SIZE(8..2))"
+            )
+        );
+    }
+
+    #[test]
+    fn test_default_outside_constraint() {
+        assert_eq!(
+            vec![
+                "3:45: warning[default-outside-constraint]: Lint.Message.level: the DEFAULT value 9 does not satisfy the constraint",
+                "4:37: warning[default-outside-constraint]: Lint.Message.code: the DEFAULT value \"abc\" does not satisfy the constraint",
+                "5:37: warning[default-outside-constraint]: Lint.Message.ref: the DEFAULT value 200 does not satisfy the constraint",
+            ],
+            lint_definitions(
+                "EXPORTS Message; Message ::= SEQUENCE {
+                                            level INTEGER (0..7) DEFAULT 9,
+                                    code NumericString DEFAULT \"abc\",
+                                    ref Level DEFAULT 200
+                                 }
+                 Level ::= INTEGER (0..100)"
+            )
+        );
+    }
+
+    #[test]
+    fn test_unused_definitions() {
+        assert_eq!(
+            vec!["4:18: warning[unused-definition]: Lint.Unused: is neither used nor exported"],
+            lint_definitions(
+                "EXPORTS Root;
+                 Root ::= SEQUENCE { used Used }
+                 Unused ::= SEQUENCE { next Unused OPTIONAL }
+                 Used ::= NULL"
+            )
+        );
+    }
+
+    #[test]
+    fn test_definitions_of_modules_without_exports_are_exported() {
+        assert!(lint_definitions("Unused ::= NULL").is_empty());
+    }
+
+    #[test]
+    fn test_definitions_are_used_per_module() {
+        let models = [
+            "First DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+             EXPORTS Root;
+             IMPORTS Imported FROM Second;
+             Root ::= SEQUENCE { local Shared, imported Imported }
+             Shared ::= NULL
+             END",
+            "Second DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+             EXPORTS Imported;
+             Imported ::= NULL
+             Shared ::= NULL
+             END",
+        ]
+        .iter()
+        .map(|module| Model::try_from(Tokenizer::default().parse(module)).unwrap())
+        .fold(MultiModuleResolver::default(), |mut resolver, model| {
+            resolver.push(model);
+            resolver
+        })
+        .try_resolve_all()
+        .unwrap();
+        assert_eq!(
+            vec!["4:14: warning[unused-definition]: Second.Shared: is neither used nor exported"],
+            lint(&models)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_name_collisions_of_modules_and_values() {
+        let models = [
+            "Foo-Bar DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+             a-b INTEGER ::= 1
+             a_b INTEGER ::= 2
+             END",
+            "Foo_Bar DEFINITIONS AUTOMATIC TAGS ::= BEGIN END",
+        ]
+        .iter()
+        .map(|module| {
+            let mut model = Model::try_from(Tokenizer::default().parse(module)).unwrap();
+            model.set_source_file(&format!("{}.asn1", model.name));
+            model.try_resolve().unwrap()
+        })
+        .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "Foo_Bar.asn1:1:1: warning[name-collision]: Foo_Bar.: collides with another module as foo_bar",
+                "Foo-Bar.asn1:3:14: warning[name-collision]: Foo-Bar.a_b: collides with another value as A_B",
+            ],
+            lint(&models)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_name_collisions() {
+        assert_eq!(
+            vec![
+                "4:18: warning[name-collision]: Lint.Foo_Bar: collides with another definition as FooBar",
+                "3:55: warning[name-collision]: Lint.Foo-Bar.foo_bar: collides with another field as foo_bar",
+                "5:45: warning[name-collision]: Lint.Kind.a_b: collides with another variant as AB",
+            ],
+            lint_definitions(
+                "EXPORTS Foo-Bar, Foo_Bar, Kind;
+                 Foo-Bar ::= SEQUENCE { foo-bar NULL, foo_bar NULL }
+                 Foo_Bar ::= NULL
+                 Kind ::= ENUMERATED { a-b, a_b }"
+            )
+        );
+    }
+
+    #[test]
+    fn test_suspicious_extension_markers() {
+        assert_eq!(
+            vec![
+                "3:52: warning[suspicious-extension-marker]: Lint.Grown.added: the extension addition is neither OPTIONAL nor has a DEFAULT value, but is missing in encodings of peers that do not know it yet",
+                "4:18: warning[suspicious-extension-marker]: Lint.Empty: the extension marker is not preceded by any root component",
+            ],
+            lint_definitions(
+                "EXPORTS Grown, Empty;
+                 Grown ::= SEQUENCE { a NULL, ..., added INTEGER, optional INTEGER OPTIONAL }
+                 Empty ::= SEQUENCE { ... }"
+            )
+        );
+    }
+}
@@ -1,30 +1,52 @@
 use crate::model::rs::ResolveScope;
-use crate::model::{Asn, LiteralValue, Model};
+use crate::model::{Applied, Asn, Constraint, LiteralValue, Model, Source, TagDefault};
 use std::fmt::{Debug, Display, Formatter};
 
 pub trait ResolveState: Clone {
-    type SizeType: Display + Debug + Clone + PartialOrd + PartialEq;
-    type RangeType: Display + Debug + Clone + PartialOrd + PartialEq;
-    type ConstType: Debug + Clone + PartialOrd + PartialEq;
+    type SizeType: Display + Debug + Clone + PartialOrd + PartialEq + Serde;
+    type RangeType: Display + Debug + Clone + PartialOrd + PartialEq + Serde;
+    type ConstType: Display + Debug + Clone + PartialOrd + PartialEq + Serde;
+    type ConstraintType: Display + Debug + Clone + PartialOrd + PartialEq + Serde;
 }
 
+/// Requires the types of a [`ResolveState`] to be (de-)serializable if the `serde` feature is
+/// enabled, so that the model can be (de-)serialized in any state.
+#[cfg(feature = "serde")]
+pub trait Serde: serde::Serialize + serde::de::DeserializeOwned {}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + serde::de::DeserializeOwned> Serde for T {}
+
+/// Requires the types of a [`ResolveState`] to be (de-)serializable if the `serde` feature is
+/// enabled, so that the model can be (de-)serialized in any state.
+#[cfg(not(feature = "serde"))]
+pub trait Serde {}
+
+#[cfg(not(feature = "serde"))]
+impl<T> Serde for T {}
+
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Resolved;
 impl ResolveState for Resolved {
     type SizeType = usize;
     type RangeType = i64;
     type ConstType = LiteralValue;
+    type ConstraintType = Applied;
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Unresolved;
 impl ResolveState for Unresolved {
     type SizeType = LitOrRef<usize>;
     type RangeType = LitOrRef<i64>;
     type ConstType = LitOrRef<LiteralValue>;
+    type ConstraintType = Constraint<Unresolved>;
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LitOrRef<T> {
     Lit(T),
     Ref(String),
@@ -56,6 +78,58 @@ pub enum Error {
     FailedToResolveType(String),
     FailedToResolveReference(String),
     FailedToParseLiteral(String),
+    FailedToExpandComponentsOf(String),
+    FailedToApplyConstraint(String),
+    /// The constraint on the referenced type excludes all of its values
+    EmptyConstraint(String),
+    /// The definition is or contains an `OBJECT IDENTIFIER`, which is only supported as type of
+    /// value references
+    UnsupportedObjectIdentifier(String),
+    FailedToResolveValue(String),
+    /// The symbol (first) is imported from a module (second) that does not export it
+    FailedToImportNotExported(String, String),
+    /// The symbol (first) is imported from more than one module (second and third)
+    AmbiguousImport(String, String, String),
+    /// More than one module in scope satisfies the import from the given module
+    AmbiguousModule(String),
+    /// The error (second) occurred while resolving the definition or value reference at the
+    /// given source (first)
+    At(Source, Box<Error>),
+}
+
+impl Error {
+    /// Attaches the source of the definition or value reference that failed to resolve, unless
+    /// its location is unknown
+    pub fn at(self, source: &Source) -> Self {
+        match self {
+            error @ Error::At(..) => error,
+            error if source.location().is_none() => error,
+            error => Error::At(source.clone(), Box::new(error)),
+        }
+    }
+
+    /// The source of the definition or value reference that failed to resolve, if known
+    pub fn origin(&self) -> Option<&Source> {
+        match self {
+            Error::At(source, _) => Some(source),
+            _ => None,
+        }
+    }
+
+    /// The error without the source it occurred at
+    pub fn kind(&self) -> &Error {
+        match self {
+            Error::At(_, error) => error.kind(),
+            error => error,
+        }
+    }
+
+    pub fn into_kind(self) -> Error {
+        match self {
+            Error::At(_, error) => error.into_kind(),
+            error => error,
+        }
+    }
 }
 
 impl std::error::Error for Error {}
@@ -71,6 +145,59 @@ impl std::fmt::Display for Error {
             Error::FailedToParseLiteral(literal) => {
                 write!(f, "Failed to parse literal: {}", literal)
             }
+            Error::FailedToExpandComponentsOf(name) => {
+                write!(
+                    f,
+                    "Failed to expand COMPONENTS OF, {} is not a SEQUENCE or SET type",
+                    name
+                )
+            }
+            Error::FailedToResolveValue(value) => {
+                write!(f, "Failed to resolve value: {}", value)
+            }
+            Error::FailedToImportNotExported(name, module) => {
+                write!(
+                    f,
+                    "Failed to import {} from {}, it is not exported by that module",
+                    name, module
+                )
+            }
+            Error::AmbiguousImport(name, module_a, module_b) => {
+                write!(
+                    f,
+                    "Ambiguous import of {}, it is imported from {} and {}",
+                    name, module_a, module_b
+                )
+            }
+            Error::AmbiguousModule(name) => {
+                write!(
+                    f,
+                    "Ambiguous import from {}, more than one version of the module is loaded",
+                    name
+                )
+            }
+            Error::FailedToApplyConstraint(name) => {
+                write!(
+                    f,
+                    "Failed to apply constraint, {} cannot be constrained this way",
+                    name
+                )
+            }
+            Error::UnsupportedObjectIdentifier(name) => {
+                write!(
+                    f,
+                    "Failed to resolve {}, OBJECT IDENTIFIER is only supported for value references",
+                    name
+                )
+            }
+            Error::EmptyConstraint(name) => {
+                write!(
+                    f,
+                    "Failed to apply constraint, it excludes all values of {}",
+                    name
+                )
+            }
+            Error::At(source, error) => write!(f, "{}: {}", source, error),
         }
     }
 }
@@ -79,6 +206,11 @@ pub trait Resolver<T> {
     fn resolve(&self, lor: &LitOrRef<T>) -> Result<T, Error>;
 }
 
+/// The tagging environment of the module whose types are resolved
+pub trait TagEnvironment {
+    fn tag_default(&self) -> TagDefault;
+}
+
 pub trait TryResolve<T, R: Sized> {
     fn try_resolve(&self, resolver: &impl Resolver<T>) -> Result<R, Error>;
 }
@@ -1,6 +1,9 @@
+pub mod compat;
+pub mod lint;
 pub mod protobuf;
 pub mod rust;
 pub mod sql;
+pub mod visit;
 
 pub use self::rust::Rust;
 pub use self::rust::RustType;
@@ -28,8 +31,11 @@ mod asn;
 mod bit_string;
 mod charset;
 mod choice;
+mod comments;
 mod components;
+mod constraint;
 mod definition;
+mod diagnostic;
 mod enumerated;
 mod err;
 mod int;
@@ -39,20 +45,25 @@ mod oid;
 mod parse;
 mod range;
 mod rs;
+mod search;
 mod size;
+mod source;
 mod tag;
 mod tag_resolver;
 
 use crate::model::itc::InnerTypeConstraints;
-use crate::model::lor::{ResolveState, Resolved, Resolver, Unresolved};
+use crate::model::lor::{ResolveState, Resolved, Resolver, TagEnvironment, Unresolved};
 pub use asn::Asn;
 pub use asn::Type;
 pub use bit_string::BitString;
 pub use charset::Charset;
 pub use choice::Choice;
 pub use choice::ChoiceVariant;
+pub use comments::Comments;
 pub use components::ComponentTypeList;
+pub use constraint::{Applied, Constraint};
 pub use definition::Definition;
+pub use diagnostic::{did_you_mean, Diagnostic};
 pub use enumerated::Enumerated;
 pub use enumerated::EnumeratedVariant;
 pub use err::Error;
@@ -64,16 +75,32 @@ pub use oid::{ObjectIdentifier, ObjectIdentifierComponent};
 pub use parse::PeekableTokens;
 pub use range::Range;
 pub use rs::MultiModuleResolver;
+pub use search::{module_files, SearchError, SkippedFile};
 pub use size::Size;
+pub use source::{IgnoredInComparison, Source, Sources};
 pub use tag::Tag;
 pub use tag::TagProperty;
 pub use tag_resolver::TagResolver;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T::DefinitionType: serde::Serialize, T::ValueReferenceType: serde::Serialize",
+        deserialize = "T::DefinitionType: serde::Deserialize<'de>, T::ValueReferenceType: serde::Deserialize<'de>"
+    ))
+)]
 pub struct Model<T: Target> {
     pub name: String,
+    /// Where the name of the module is in the ASN.1 source
+    pub source: IgnoredInComparison<Source>,
     pub oid: Option<ObjectIdentifier>,
     pub imports: Vec<Import>,
+    pub exports: Exports,
+    pub tag_default: TagDefault,
+    /// Whether the module header declares `EXTENSIBILITY IMPLIED`
+    pub extensibility_implied: bool,
     pub definitions: Vec<Definition<T::DefinitionType>>,
     pub value_references: Vec<ValueReference<T::ValueReferenceType>>,
 }
@@ -83,12 +110,31 @@ pub trait Target {
     type ValueReferenceType;
 }
 
+impl<T: Target> PartialEq for Model<T>
+where
+    T::DefinitionType: PartialEq,
+    T::ValueReferenceType: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.oid == other.oid
+            && self.imports == other.imports
+            && self.exports == other.exports
+            && self.definitions == other.definitions
+            && self.value_references == other.value_references
+    }
+}
+
 impl<T: Target> Default for Model<T> {
     fn default() -> Self {
         Model {
             name: Default::default(),
+            source: IgnoredInComparison::default(),
             oid: None,
             imports: Default::default(),
+            exports: Default::default(),
+            tag_default: Default::default(),
+            extensibility_implied: false,
             definitions: Default::default(),
             value_references: Vec::default(),
         }
@@ -97,34 +143,128 @@ impl<T: Target> Default for Model<T> {
 
 impl Model<Asn<Unresolved>> {
     pub fn try_from(value: Vec<Token>) -> Result<Self, Error> {
+        Self::try_from_recovering(value).map_err(|mut errors| errors.remove(0))
+    }
+
+    /// Like [`Model::try_from`], but instead of stopping at the first error, the parser skips
+    /// to the next definition (or `END`) and continues, so that all errors of the module are
+    /// reported at once. Errors in the module header are not recovered from.
+    ///
+    /// [`Token::Comment`]s are attached to the definitions, fields and variants they describe.
+    pub fn try_from_recovering(value: Vec<Token>) -> Result<Self, Vec<Error>> {
+        let (comments, value): (Vec<Token>, Vec<Token>) =
+            value.into_iter().partition(Token::is_comment);
+        let tokens = if comments.is_empty() {
+            Vec::default()
+        } else {
+            value.clone()
+        };
+        let boundaries = Self::definition_boundaries(&value);
         let mut model = Model::default();
+        let mut errors = Vec::new();
         let mut iter = value.into_iter().peekable();
 
-        model.name = Self::read_name(&mut iter)?;
-        model.oid = Self::maybe_read_oid(&mut iter)?;
-        Self::skip_until_after_text_ignore_ascii_case(&mut iter, "BEGIN")?;
+        if let Some(token) = iter.peek() {
+            model.source = Source::new(token.location()).into();
+        }
+        model.name = Self::read_name(&mut iter).map_err(|e| vec![e])?;
+        model.oid = Self::maybe_read_oid(&mut iter).map_err(|e| vec![e])?;
+        Self::read_header_until_begin(&mut iter, &mut model).map_err(|e| vec![e])?;
 
         while let Some(token) = iter.next() {
-            if token.eq_text_ignore_ascii_case("END") {
+            let location = token.location();
+            let result = if token.eq_text_ignore_ascii_case("END") {
                 model.make_names_nice();
-                return Ok(model);
+                if !comments.is_empty() {
+                    comments::attach_comments(&mut model, &tokens, &comments);
+                }
+                return if errors.is_empty() {
+                    Ok(model)
+                } else {
+                    Err(errors)
+                };
+            } else if token.eq_text_ignore_ascii_case("EXPORTS") {
+                Self::read_exports(&mut iter).map(|exports| model.exports = exports)
             } else if token.eq_text_ignore_ascii_case("IMPORTS") {
-                Self::read_imports(&mut iter)?
-                    .into_iter()
-                    .for_each(|i| model.imports.push(i));
+                Self::read_imports(&mut iter).map(|imports| model.imports.extend(imports))
             } else if iter.peek_is_separator_eq(':') {
-                model.definitions.push(Self::read_definition(
-                    &mut iter,
-                    token.into_text_or_else(Error::unexpected_token)?,
-                )?);
+                token
+                    .into_text_or_else(Error::unexpected_token)
+                    .and_then(|name| Self::read_definition(&mut iter, name))
+                    .map(|Definition(name, asn)| {
+                        let asn = asn.with_source(Source::new(location));
+                        model.definitions.push(Definition(name, asn))
+                    })
             } else {
-                model.value_references.push(Self::read_value_reference(
-                    &mut iter,
-                    token.into_text_or_else(Error::unexpected_token)?,
-                )?);
+                token
+                    .into_text_or_else(Error::unexpected_token)
+                    .and_then(|name| Self::read_value_reference(&mut iter, name))
+                    .map(|mut value_reference| {
+                        value_reference.role.source = Source::new(location).into();
+                        model.value_references.push(value_reference)
+                    })
+            };
+
+            if let Err(e) = result {
+                errors.push(e);
+                let next = boundaries.iter().find(|boundary| **boundary > location);
+                while iter
+                    .peek()
+                    .map(|t| next.map(|next| t.location() < *next).unwrap_or(true))
+                    .unwrap_or(false)
+                {
+                    iter.next();
+                }
             }
         }
-        Err(Error::unexpected_end_of_stream())
+
+        if errors.is_empty() {
+            errors.push(Error::unexpected_end_of_stream());
+        }
+        Err(errors)
+    }
+
+    /// The [`Location`]s of the first [`Token`] of each definition, value reference and the
+    /// final `END`, in ascending order. A definition is introduced by `Name ::=` and a value
+    /// reference by `name Type ::=`, where the type might consist of multiple words (like
+    /// `OBJECT IDENTIFIER`).
+    fn definition_boundaries(tokens: &[Token]) -> Vec<Location> {
+        let mut boundaries = Vec::new();
+        let begin = tokens
+            .iter()
+            .position(|t| t.eq_text_ignore_ascii_case("BEGIN"))
+            .map(|index| index + 1)
+            .unwrap_or(1);
+        for index in begin.max(1)..tokens.len().saturating_sub(2) {
+            if tokens[index].eq_separator(':')
+                && tokens[index + 1].eq_separator(':')
+                && tokens[index + 2].eq_separator('=')
+                && tokens[index - 1].is_text()
+            {
+                let mut start = index - 1;
+                let mut candidate = start;
+                while candidate > 0 {
+                    candidate -= 1;
+                    match tokens[candidate].text().and_then(|t| t.chars().next()) {
+                        Some(c) if c.is_lowercase() => {
+                            start = candidate;
+                            break;
+                        }
+                        Some(c) if c.is_uppercase() => continue,
+                        _ => break,
+                    }
+                }
+                boundaries.push(tokens[start].location());
+            }
+        }
+        if let Some(end) = tokens
+            .iter()
+            .rev()
+            .find(|t| t.eq_text_ignore_ascii_case("END"))
+        {
+            boundaries.push(end.location());
+        }
+        boundaries
     }
 
     fn read_name(iter: &mut Peekable<IntoIter<Token>>) -> Result<String, Error> {
@@ -133,8 +273,8 @@ impl Model<Asn<Unresolved>> {
             .ok_or_else(Error::missing_module_name)
     }
 
-    fn maybe_read_oid(
-        iter: &mut Peekable<IntoIter<Token>>,
+    fn maybe_read_oid<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
     ) -> Result<Option<ObjectIdentifier>, Error> {
         if iter.next_is_separator_and_eq('{') {
             Ok(Some(Self::read_oid(iter)?))
@@ -143,7 +283,9 @@ impl Model<Asn<Unresolved>> {
         }
     }
 
-    fn read_oid(iter: &mut Peekable<IntoIter<Token>>) -> Result<ObjectIdentifier, Error> {
+    fn read_oid<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<ObjectIdentifier, Error> {
         let mut vec = Vec::default();
         while let Some(token) = iter.next() {
             if token.eq_separator('}') {
@@ -175,14 +317,28 @@ impl Model<Asn<Unresolved>> {
         Ok(ObjectIdentifier(vec))
     }
 
-    fn skip_until_after_text_ignore_ascii_case(
+    /// Reads the tagging environment and whether `EXTENSIBILITY IMPLIED` is declared from the
+    /// remaining module header, other header properties are skipped
+    fn read_header_until_begin(
         iter: &mut Peekable<IntoIter<Token>>,
-        text: &str,
+        model: &mut Model<Asn<Unresolved>>,
     ) -> Result<(), Error> {
+        // ITU-T X.680 | ISO/IEC 8824-1, 13.2: no TagDefault means EXPLICIT TAGS
+        model.tag_default = TagDefault::Explicit;
+        let mut previous = None;
         for t in iter {
-            if t.eq_text_ignore_ascii_case(text) {
+            if t.eq_text_ignore_ascii_case("BEGIN") {
                 return Ok(());
+            } else if t.eq_text_ignore_ascii_case("TAGS") {
+                if let Some(tag_default) = previous.as_ref().and_then(TagDefault::from_keyword) {
+                    model.tag_default = tag_default;
+                }
+            } else if t.eq_text_ignore_ascii_case("IMPLIED")
+                && matches!(&previous, Some(p) if p.eq_text_ignore_ascii_case("EXTENSIBILITY"))
+            {
+                model.extensibility_implied = true;
             }
+            previous = Some(t);
         }
         Err(Error::unexpected_end_of_stream())
     }
@@ -209,6 +365,26 @@ impl Model<Asn<Unresolved>> {
         }
         Err(Error::unexpected_end_of_stream())
     }
+    fn read_exports(iter: &mut Peekable<IntoIter<Token>>) -> Result<Exports, Error> {
+        if iter.next_is_text_and_eq_ignore_case("ALL") {
+            iter.next_separator_eq_or_err(';')?;
+            return Ok(Exports::All);
+        }
+        let mut symbols = Vec::new();
+        while let Some(token) = iter.next() {
+            if token.eq_separator(';') {
+                return Ok(Exports::Symbols(symbols));
+            } else {
+                symbols.push(token.into_text_or_else(Error::unexpected_token)?);
+                if !iter.next_is_separator_and_eq(',') {
+                    iter.next_separator_eq_or_err(';')?;
+                    return Ok(Exports::Symbols(symbols));
+                }
+            }
+        }
+        Err(Error::unexpected_end_of_stream())
+    }
+
     fn read_definition(
         iter: &mut Peekable<IntoIter<Token>>,
         name: String,
@@ -260,16 +436,51 @@ impl Model<Asn<Unresolved>> {
                 iter.next_separator_eq_or_err(':')?;
                 iter.next_separator_eq_or_err(':')?;
                 iter.next_separator_eq_or_err('=')?;
-                Self::read_literal(iter)?
-            },
-            role: Asn {
-                tag: None,
-                r#type,
-                default: None,
+                if let Type::ObjectIdentifier = r#type {
+                    iter.next_separator_eq_or_err('{')?;
+                    LiteralValue::ObjectIdentifier(Self::read_oid(iter)?)
+                } else {
+                    Self::read_value(iter)?
+                }
             },
+            role: Asn::untagged(r#type),
         })
     }
 
+    /// Reads a literal, a structured SEQUENCE or SET value (`{ lat 0, lon 0 }`), a CHOICE value
+    /// (`alt : 5`) or the name of a value, which is resolved later on
+    fn read_value<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+    ) -> Result<LiteralValue, Error> {
+        if iter.next_is_separator_and_eq('{') {
+            let mut components = Vec::new();
+            if !iter.next_is_separator_and_eq('}') {
+                loop {
+                    let name = iter.next_text_or_err()?;
+                    components.push((name, Self::read_value(iter)?));
+                    loop_ctrl_separator!(iter.next_or_err()?);
+                }
+            }
+            Ok(LiteralValue::Sequence(components))
+        } else {
+            match Self::read_literal(iter) {
+                Ok(value) => Ok(value),
+                Err(ErrorKind::UnsupportedLiteral(token, ..)) if token.is_text() => {
+                    let name = iter.next_text_or_err()?;
+                    if iter.next_is_separator_and_eq(':') {
+                        Ok(LiteralValue::Choice(
+                            name,
+                            Box::new(Self::read_value(iter)?),
+                        ))
+                    } else {
+                        Ok(LiteralValue::Reference(name))
+                    }
+                }
+                Err(e) => Err(e.into()),
+            }
+        }
+    }
+
     fn read_literal<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
     ) -> Result<LiteralValue, ErrorKind> {
@@ -304,33 +515,42 @@ impl Model<Asn<Unresolved>> {
         delimiter: char,
     ) -> Result<String, ErrorKind> {
         iter.next_separator_eq_or_err(delimiter)?;
-        let token = iter.next_or_err()?;
-
-        let first_text = token.text().unwrap_or_default();
         let mut string = String::from(delimiter);
-        string.push_str(first_text);
-        let mut prev_loc = Location::at(
-            token.location().line(),
-            token.location().column() + first_text.chars().count(),
-        );
+        let mut prev_loc: Option<Location> = None;
 
         loop {
-            match iter.next_or_err()? {
-                t if t.eq_separator(delimiter) => break,
-                Token::Text(loc, str) => {
-                    for _ in prev_loc.column()..loc.column() {
-                        string.push(' ');
+            let token = iter.next_or_err()?;
+            if token.is_comment() {
+                continue;
+            } else if let Some(prev_loc) = prev_loc {
+                for _ in prev_loc.column()..token.location().column() {
+                    string.push(' ');
+                }
+            }
+            match token {
+                Token::Separator(loc, char) if char == delimiter => {
+                    // ITU-T X.680 | ISO/IEC 8824-1, 12.14: a quotation mark within the string
+                    // is written as two adjacent quotation marks
+                    let next = Location::at(loc.line(), loc.column() + 1);
+                    if delimiter != '"'
+                        || !matches!(iter.peek(), Some(t) if t.eq_separator(delimiter) && t.location() == next)
+                    {
+                        break;
                     }
+                    iter.next();
+                    string.push(delimiter);
+                    string.push(delimiter);
+                    prev_loc = Some(Location::at(loc.line(), loc.column() + 2));
+                }
+                Token::Text(loc, str) => {
                     string.push_str(&str);
-                    prev_loc = Location::at(loc.line(), loc.column() + str.chars().count())
+                    prev_loc = Some(Location::at(loc.line(), loc.column() + str.chars().count()))
                 }
                 Token::Separator(loc, char) => {
-                    for _ in prev_loc.column()..loc.column() {
-                        string.push(' ');
-                    }
                     string.push(char);
-                    prev_loc = Location::at(loc.line(), loc.column() + 1)
+                    prev_loc = Some(Location::at(loc.line(), loc.column() + 1))
                 }
+                Token::Comment(..) => {}
             }
         }
 
@@ -379,11 +599,15 @@ impl Model<Asn<Unresolved>> {
             "integer" => Type::Integer(Integer::try_from(iter)?),
             "boolean" => Type::Boolean,
             "null" => Type::Null,
-            "utf8string" => Type::String(Self::maybe_read_size(iter)?, Charset::Utf8),
-            "ia5string" => Type::String(Self::maybe_read_size(iter)?, Charset::Ia5),
-            "numericstring" => Type::String(Self::maybe_read_size(iter)?, Charset::Numeric),
-            "printablestring" => Type::String(Self::maybe_read_size(iter)?, Charset::Printable),
-            "visiblestring" => Type::String(Self::maybe_read_size(iter)?, Charset::Visible),
+            "object" => {
+                iter.next_text_eq_ignore_case_or_err("IDENTIFIER")?;
+                Type::ObjectIdentifier
+            }
+            "utf8string" => Self::read_string(iter, Charset::Utf8)?,
+            "ia5string" => Self::read_string(iter, Charset::Ia5)?,
+            "numericstring" => Self::read_string(iter, Charset::Numeric)?,
+            "printablestring" => Self::read_string(iter, Charset::Printable)?,
+            "visiblestring" => Self::read_string(iter, Charset::Visible)?,
             "octet" => {
                 iter.next_text_eq_ignore_case_or_err("STRING")?;
                 Type::OctetString(Self::maybe_read_size(iter)?)
@@ -397,27 +621,32 @@ impl Model<Asn<Unresolved>> {
             "sequence" => Self::read_sequence_or_sequence_of(iter)?,
             "set" => Self::read_set_or_set_of(iter)?,
             _ => {
-                // TODO use InnerTypeConstraints to flatten TypeReference to an actual type and
-                //      prevent tuple-type nesting in the generated rust and other code by copying
-                //      over the fields and adding these additional constraints
-                let _ = Self::maybe_read_with_components_constraint(iter)?;
-                Type::TypeReference(text, None)
+                if iter.next_is_separator_and_eq('(') {
+                    if iter.peek_is_text_eq_ignore_case("WITH") {
+                        // TODO use InnerTypeConstraints to flatten TypeReference to an actual type
+                        //      and prevent tuple-type nesting in the generated rust and other code
+                        //      by copying over the fields and adding these additional constraints
+                        let _ = InnerTypeConstraints::try_from(&mut *iter)?;
+                        iter.next_separator_eq_or_err(')')?;
+                        Type::TypeReference(text, None)
+                    } else {
+                        let mut constraint = Constraint::try_from(&mut *iter)?;
+                        // serial constraints, such as `(SIZE(2))(1..5)`
+                        while iter.peek_is_separator_eq('(') {
+                            let token = iter.next_or_err()?;
+                            constraint = constraint
+                                .and(Constraint::try_from(&mut *iter)?)
+                                .ok_or_else(|| Error::unsupported_constraint(token))?;
+                        }
+                        Type::ConstrainedTypeReference(text, constraint)
+                    }
+                } else {
+                    Type::TypeReference(text, None)
+                }
             }
         })
     }
 
-    fn maybe_read_with_components_constraint<T: Iterator<Item = Token>>(
-        iter: &mut Peekable<T>,
-    ) -> Result<Option<InnerTypeConstraints>, Error> {
-        if iter.next_is_separator_and_eq('(') {
-            let result = InnerTypeConstraints::try_from(&mut *iter)?;
-            iter.next_separator_eq_or_err(')')?;
-            Ok(Some(result))
-        } else {
-            Ok(None)
-        }
-    }
-
     fn maybe_read_size<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
     ) -> Result<Size<<Unresolved as ResolveState>::SizeType>, Error> {
@@ -432,6 +661,33 @@ impl Model<Asn<Unresolved>> {
         }
     }
 
+    /// Reads the `SIZE` and permitted alphabet constraints of a character string type, such as
+    /// `IA5String (SIZE(1..8)) (FROM("A".."Z"))`
+    fn read_string<T: Iterator<Item = Token>>(
+        iter: &mut Peekable<T>,
+        charset: Charset,
+    ) -> Result<Type<Unresolved>, Error> {
+        let mut constraint = Constraint::default();
+        if iter.peek_is_text_eq_ignore_case("SIZE") {
+            constraint.size = Size::try_from(&mut *iter)?;
+        }
+        while iter.peek_is_separator_eq('(') {
+            let token = iter.next_or_err()?;
+            constraint = constraint
+                .and(Constraint::try_from(&mut *iter)?)
+                .filter(|constraint| constraint.range == Range::none())
+                .map(|mut constraint| {
+                    if let Some(alphabet) = &mut constraint.alphabet {
+                        alphabet.retain(|char| charset.is_valid(char));
+                    }
+                    constraint
+                })
+                .filter(|constraint| !matches!(&constraint.alphabet, Some(a) if a.is_empty()))
+                .ok_or_else(|| Error::unsupported_constraint(token))?;
+        }
+        Ok(Type::String(constraint.size, charset, constraint.alphabet))
+    }
+
     fn read_sequence_or_sequence_of<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
     ) -> Result<Type<Unresolved>, Error> {
@@ -459,12 +715,17 @@ impl Model<Asn<Unresolved>> {
     fn read_field<T: Iterator<Item = Token>>(
         iter: &mut Peekable<T>,
     ) -> Result<(Field<Asn<Unresolved>>, bool), Error> {
+        let source = iter
+            .peek()
+            .map(|token| Source::new(token.location()))
+            .unwrap_or_default();
         let name = iter.next_text_or_err()?;
         let (token, tag) = Self::next_with_opt_tag(iter)?;
         let mut field = Field {
             name,
             role: Self::read_role_given_text(iter, token.into_text_or_else(Error::no_text)?)?
-                .opt_tagged(tag),
+                .opt_tagged(tag)
+                .with_source(source),
         };
 
         let token = {
@@ -476,12 +737,9 @@ impl Model<Asn<Unresolved>> {
                 if cfg!(feature = "debug-proc-macro") {
                     println!("TOKEN:::: {:?}", token);
                 }
-                field.role.set_default(match Self::read_literal(iter) {
-                    Ok(value) => LitOrRef::Lit(value),
-                    Err(ErrorKind::UnsupportedLiteral(token, ..)) if token.is_text() => {
-                        LitOrRef::Ref(iter.next_text_or_err()?)
-                    }
-                    Err(e) => return Err(e.into()),
+                field.role.set_default(match Self::read_value(iter)? {
+                    LiteralValue::Reference(name) => LitOrRef::Ref(name),
+                    value => LitOrRef::Lit(value),
                 });
                 if cfg!(feature = "debug-proc-macro") {
                     println!("     :::: {:?}", field);
@@ -579,6 +837,7 @@ impl<RS: ResolveState> Model<Asn<RS>> {
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValueReference<T> {
     pub name: String,
     pub role: T,
@@ -586,12 +845,26 @@ pub struct ValueReference<T> {
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LiteralValue {
     Boolean(bool),
     String(String),
     Integer(i64),
     OctetString(Vec<u8>),
     EnumeratedVariant(String, String),
+    /// The values of the components of a SEQUENCE or SET by their name
+    ///
+    /// ITU-T X.680 | ISO/IEC 8824-1, 25.18 and 27.7
+    Sequence(Vec<(String, LiteralValue)>),
+    /// The name of the chosen alternative and its value
+    ///
+    /// ITU-T X.680 | ISO/IEC 8824-1, 29.11
+    Choice(String, Box<LiteralValue>),
+    /// ITU-T X.680 | ISO/IEC 8824-1, 32.3
+    ObjectIdentifier(ObjectIdentifier),
+    /// A value that is given by its name, such as the variant of an ENUMERATED or a value
+    /// reference. While resolving, it is replaced by the actual value.
+    Reference(String),
 }
 
 impl LiteralValue {
@@ -604,14 +877,81 @@ impl LiteralValue {
     }
 }
 
+/// The tagging environment of a module, ITU-T X.680 | ISO/IEC 8824-1, 13.1
+#[derive(Debug, Default, Copy, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TagDefault {
+    Explicit,
+    Implicit,
+    /// The tagging that is assumed by the generated code
+    #[default]
+    Automatic,
+}
+
+impl TagDefault {
+    fn from_keyword(token: &Token) -> Option<Self> {
+        if token.eq_text_ignore_ascii_case("EXPLICIT") {
+            Some(TagDefault::Explicit)
+        } else if token.eq_text_ignore_ascii_case("IMPLICIT") {
+            Some(TagDefault::Implicit)
+        } else if token.eq_text_ignore_ascii_case("AUTOMATIC") {
+            Some(TagDefault::Automatic)
+        } else {
+            None
+        }
+    }
+
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            TagDefault::Explicit => "EXPLICIT",
+            TagDefault::Implicit => "IMPLICIT",
+            TagDefault::Automatic => "AUTOMATIC",
+        }
+    }
+}
+
+/// ITU-T X.680 | ISO/IEC 8824-1, 13.13
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Exports {
+    /// `EXPORTS ALL;` or no `EXPORTS` clause at all
+    #[default]
+    All,
+    /// `EXPORTS a, b, c;`, which might also be empty
+    Symbols(Vec<String>),
+}
+
+impl Exports {
+    pub fn exports(&self, symbol: &str) -> bool {
+        match self {
+            Exports::All => true,
+            Exports::Symbols(symbols) => symbols.iter().any(|s| s.eq(symbol)),
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Import {
     pub what: Vec<String>,
     pub from: String,
     pub from_oid: Option<ObjectIdentifier>,
 }
 
+impl Import {
+    /// Whether the given model is the module this import refers to. If the import as well as
+    /// the model have an [`ObjectIdentifier`], only these are compared. This allows to
+    /// distinguish different versions of a module with the same name.
+    pub fn is_satisfied_by<T: Target>(&self, model: &Model<T>) -> bool {
+        match (&self.from_oid, &model.oid) {
+            (Some(import_oid), Some(model_oid)) => import_oid.matches(model_oid),
+            _ => self.from.eq(&model.name),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Field<T> {
     pub name: String,
     pub role: T,
@@ -636,7 +976,10 @@ impl Field<Asn<Unresolved>> {
         R: Resolver<<Resolved as ResolveState>::SizeType>
             + Resolver<<Resolved as ResolveState>::RangeType>
             + Resolver<<Resolved as ResolveState>::ConstType>
-            + Resolver<Type<Unresolved>>,
+            + Resolver<Type<Unresolved>>
+            + Resolver<ComponentTypeList<Resolved>>
+            + Resolver<Asn<Resolved>>
+            + TagEnvironment,
     >(
         &self,
         resolver: &R,
@@ -1632,6 +1975,9 @@ pub(crate) mod tests {
             fields,
             tag,
             extension_after: _,
+            comments: _,
+            sources: _,
+            asn_name: _,
         } = rust.definitions[0].value()
         {
             assert_eq!("Implicit", rust.definitions[0].0.as_str());
@@ -1794,12 +2140,12 @@ pub(crate) mod tests {
             &[
                 ValueReference {
                     name: "utf8".to_string(),
-                    role: Type::String(Size::Any, Charset::Utf8).untagged(),
+                    role: Type::String(Size::Any, Charset::Utf8, None).untagged(),
                     value: LiteralValue::String("häw äre yöu .. .. doing".to_string())
                 },
                 ValueReference {
                     name: "ia5".to_string(),
-                    role: Type::String(Size::Any, Charset::Ia5).untagged(),
+                    role: Type::String(Size::Any, Charset::Ia5, None).untagged(),
                     value: LiteralValue::String("how are you".to_string())
                 }
             ],
@@ -1929,4 +2275,1014 @@ pub(crate) mod tests {
             &model.definitions[..]
         );
     }
+
+    #[test]
+    pub fn test_components_of_is_expanded() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Base ::= SEQUENCE {
+                    abc INTEGER,
+                    def BOOLEAN,
+                    ...,
+                    ghi UTF8String
+                }
+
+                Extended ::= SEQUENCE {
+                    first BOOLEAN,
+                    COMPONENTS OF Base,
+                    ...,
+                    last INTEGER
+                }
+                END",
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+        assert_eq!(
+            Definition(
+                "Extended".to_string(),
+                Type::Sequence(ComponentTypeList {
+                    fields: vec![
+                        Field {
+                            name: "first".to_string(),
+                            role: Type::Boolean.untagged(),
+                        },
+                        Field {
+                            name: "abc".to_string(),
+                            role: Type::unconstrained_integer().untagged(),
+                        },
+                        Field {
+                            name: "def".to_string(),
+                            role: Type::Boolean.untagged(),
+                        },
+                        Field {
+                            name: "last".to_string(),
+                            role: Type::unconstrained_integer().untagged(),
+                        },
+                    ],
+                    extension_after: Some(2),
+                    components_of: Vec::default(),
+                })
+                .untagged(),
+            ),
+            model.definitions[1]
+        );
+    }
+
+    #[test]
+    pub fn test_multiple_components_of_keep_the_extension_marker() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Base ::= SEQUENCE {
+                    abc INTEGER,
+                    def BOOLEAN,
+                    ...,
+                    ghi UTF8String
+                }
+
+                Other ::= SEQUENCE {
+                    xyz BOOLEAN,
+                    uvw INTEGER
+                }
+
+                BothInRoot ::= SEQUENCE {
+                    COMPONENTS OF Base,
+                    COMPONENTS OF Other,
+                    ...,
+                    last INTEGER
+                }
+
+                OneOnEachSide ::= SEQUENCE {
+                    COMPONENTS OF Base,
+                    middle BOOLEAN,
+                    ...,
+                    COMPONENTS OF Other
+                }
+                END",
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        let fields = |index: usize| match &model.definitions[index].1.r#type {
+            Type::Sequence(sequence) => (
+                sequence
+                    .fields
+                    .iter()
+                    .map(|field| field.name.as_str())
+                    .collect::<Vec<_>>(),
+                sequence.extension_after,
+            ),
+            _ => panic!("Expected SEQUENCE"),
+        };
+
+        assert_eq!(
+            (vec!["abc", "def", "xyz", "uvw", "last"], Some(3)),
+            fields(2)
+        );
+        assert_eq!(
+            (vec!["abc", "def", "middle", "xyz", "uvw"], Some(2)),
+            fields(3)
+        );
+    }
+
+    #[test]
+    pub fn test_components_of_imported_type_drops_tags_for_automatic_tagging() {
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"ExternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    External ::= SET {
+                        abc [5] INTEGER,
+                        def [6] BOOLEAN
+                    }
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"InternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS External FROM ExternalModule;
+
+                    Automatic ::= SET {
+                        COMPONENTS OF External,
+                        ghi INTEGER
+                    }
+
+                    Manual ::= SET {
+                        COMPONENTS OF External,
+                        ghi [7] INTEGER
+                    }
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+
+        let models = resolver.try_resolve_all().expect("Failed to resolve");
+        let tags = |index: usize| match &models[1].definitions[index].1.r#type {
+            Type::Set(set) => set.fields.iter().map(|f| f.role.tag).collect::<Vec<_>>(),
+            _ => panic!("Expected SET"),
+        };
+
+        assert_eq!(vec![None, None, None], tags(0));
+        assert_eq!(
+            vec![
+                Some(Tag::ContextSpecific(5)),
+                Some(Tag::ContextSpecific(6)),
+                Some(Tag::ContextSpecific(7))
+            ],
+            tags(1)
+        );
+    }
+
+    #[test]
+    pub fn test_components_of_keeps_tags_without_automatic_tagging() {
+        for tag_default in &["EXPLICIT", "IMPLICIT"] {
+            let model = Model::try_from(Tokenizer::default().parse(&format!(
+                r"SomeName DEFINITIONS {} TAGS ::= BEGIN
+                    Base ::= SEQUENCE {{
+                        a [5] INTEGER,
+                        b [6] BOOLEAN
+                    }}
+
+                    Extended ::= SEQUENCE {{
+                        COMPONENTS OF Base,
+                        c BOOLEAN
+                    }}
+                    END",
+                tag_default
+            )))
+            .expect("Failed to load model")
+            .try_resolve()
+            .expect("Failed to resolve");
+
+            match &model.definitions[1].1.r#type {
+                Type::Sequence(sequence) => assert_eq!(
+                    vec![
+                        Some(Tag::ContextSpecific(5)),
+                        Some(Tag::ContextSpecific(6)),
+                        None
+                    ],
+                    sequence
+                        .fields
+                        .iter()
+                        .map(|field| field.role.tag)
+                        .collect::<Vec<_>>(),
+                    "{} TAGS",
+                    tag_default
+                ),
+                _ => panic!("Expected SEQUENCE"),
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_components_of_non_sequence_fails_to_resolve() {
+        let result = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Base ::= INTEGER
+
+                Extended ::= SEQUENCE {
+                    COMPONENTS OF Base
+                }
+                END",
+        ))
+        .expect("Failed to load model")
+        .try_resolve();
+        assert_eq!(
+            Some(ResolveError::FailedToExpandComponentsOf("Base".to_string())),
+            result.map_err(ResolveError::into_kind).err()
+        );
+    }
+
+    #[test]
+    pub fn test_constrained_type_reference() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Name ::= UTF8String (SIZE(1..32))
+                ShortName ::= Name (SIZE(1..8))
+                Percent ::= [APPLICATION 3] INTEGER (0..100,...)
+                Small ::= Percent (5..MAX)
+                END",
+        ))
+        .expect("Failed to load model");
+        assert_eq!(
+            Type::ConstrainedTypeReference(
+                "Name".to_string(),
+                Constraint {
+                    size: Size::Range(LitOrRef::Lit(1), LitOrRef::Lit(8), false),
+                    ..Default::default()
+                }
+            ),
+            model.definitions[1].1.r#type
+        );
+
+        let model = model.try_resolve().expect("Failed to resolve");
+        assert_eq!(
+            &[
+                Definition(
+                    "ShortName".to_string(),
+                    Type::String(Size::Range(1, 8, false), Charset::Utf8, None).untagged(),
+                ),
+                Definition(
+                    "Small".to_string(),
+                    Type::<Resolved>::Integer(Integer::with_range(Range::inclusive(
+                        Some(5),
+                        Some(100)
+                    )))
+                    .tagged(Tag::Application(3)),
+                ),
+            ],
+            &[model.definitions[1].clone(), model.definitions[3].clone()]
+        );
+    }
+
+    #[test]
+    pub fn test_constrained_imported_type_reference() {
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"ExternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    max-length INTEGER ::= 16
+                    Names ::= SEQUENCE (SIZE(0..max-length)) OF UTF8String
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"InternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Names FROM ExternalModule;
+
+                    Container ::= SEQUENCE {
+                        names Names (SIZE(2..4,...)) OPTIONAL
+                    }
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+
+        let models = resolver.try_resolve_all().expect("Failed to resolve");
+        assert_eq!(
+            Definition(
+                "Container".to_string(),
+                Type::sequence_from_fields(vec![Field {
+                    name: "names".to_string(),
+                    role: Type::SequenceOf(
+                        Box::new(Type::unconstrained_utf8string()),
+                        Size::Range(2, 4, true)
+                    )
+                    .optional()
+                    .untagged(),
+                }])
+                .untagged(),
+            ),
+            models[1].definitions[0]
+        );
+    }
+
+    #[test]
+    pub fn test_constrained_type_reference_not_applicable() {
+        let result = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Flag ::= BOOLEAN
+                Other ::= Flag (0..1)
+                END",
+        ))
+        .expect("Failed to load model")
+        .try_resolve();
+        assert_eq!(
+            Some(ResolveError::FailedToApplyConstraint("Flag".to_string())),
+            result.map_err(ResolveError::into_kind).err()
+        );
+    }
+
+    #[test]
+    pub fn test_constrained_type_reference_without_values() {
+        for (definition, referenced) in [
+            ("Empty ::= Name (SIZE(40..50))", "Name"),
+            ("Empty ::= Percent (101..200)", "Percent"),
+            ("Empty ::= Percent (MIN..-1)", "Percent"),
+        ] {
+            let result = Model::try_from(Tokenizer::default().parse(&format!(
+                r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Name ::= UTF8String (SIZE(1..32))
+                    Percent ::= INTEGER (0..100)
+                    {}
+                    END",
+                definition
+            )))
+            .expect("Failed to load model")
+            .try_resolve();
+            assert_eq!(
+                Some(ResolveError::EmptyConstraint(referenced.to_string())),
+                result.map_err(ResolveError::into_kind).err(),
+                "{}",
+                definition
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_constrained_type_reference_with_permitted_alphabet() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Name ::= IA5String (SIZE(1..32))
+                Hex ::= Name (FROM("0".."9" | "A".."F"))
+                Digits ::= NumericString (FROM("0".."9" UNION "A"))
+                Binary ::= Hex (FROM("01xy"))
+                Quoted ::= UTF8String (SIZE(1..8)) (FROM("a""b"))
+                END"#,
+        ))
+        .expect("Failed to load model");
+        assert_eq!(
+            Type::ConstrainedTypeReference(
+                "Name".to_string(),
+                Constraint {
+                    alphabet: Some("0123456789ABCDEF".to_string()),
+                    ..Default::default()
+                }
+            ),
+            model.definitions[1].1.r#type
+        );
+
+        let model = model.try_resolve().expect("Failed to resolve");
+        let string = |index: usize| match &model.definitions[index].1.r#type {
+            Type::String(size, charset, alphabet) => (size.clone(), *charset, alphabet.clone()),
+            _ => panic!("Expected a string"),
+        };
+        assert_eq!(
+            (
+                Size::Range(1, 32, false),
+                Charset::Ia5,
+                Some("0123456789ABCDEF".to_string())
+            ),
+            string(1)
+        );
+        // the permitted alphabet is intersected with the charset and the referenced alphabet
+        assert_eq!(
+            (Size::Any, Charset::Numeric, Some("0123456789".to_string())),
+            string(2)
+        );
+        assert_eq!(
+            (
+                Size::Range(1, 32, false),
+                Charset::Ia5,
+                Some("01".to_string())
+            ),
+            string(3)
+        );
+        assert_eq!(
+            (
+                Size::Range(1, 8, false),
+                Charset::Utf8,
+                Some("\"ab".to_string())
+            ),
+            string(4)
+        );
+    }
+
+    #[test]
+    pub fn test_constrained_type_reference_with_permitted_alphabet_not_applicable() {
+        for (definition, error) in [
+            (
+                "Other ::= Percent (FROM(\"0\"))",
+                ResolveError::FailedToApplyConstraint("Percent".to_string()),
+            ),
+            (
+                "Other ::= Digits (FROM(\"A\"..\"Z\"))",
+                ResolveError::EmptyConstraint("Digits".to_string()),
+            ),
+        ] {
+            let result = Model::try_from(Tokenizer::default().parse(&format!(
+                r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Percent ::= INTEGER (0..100)
+                    Digits ::= NumericString
+                    {}
+                    END",
+                definition
+            )))
+            .expect("Failed to load model")
+            .try_resolve();
+            assert_eq!(
+                Some(error),
+                result.map_err(ResolveError::into_kind).err(),
+                "{}",
+                definition
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_structured_value_references() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Position ::= SEQUENCE {
+                    lat INTEGER,
+                    lon INTEGER,
+                    label UTF8String OPTIONAL
+                }
+                Shape ::= CHOICE {
+                    alt INTEGER,
+                    name UTF8String
+                }
+                zero INTEGER ::= 0
+                origin Position ::= { lat zero, lon 1 }
+                altitude Shape ::= alt : 5
+                empty Position ::= {}
+                END"#,
+        ))
+        .expect("Failed to load model");
+
+        assert_eq!(
+            LiteralValue::Sequence(vec![
+                (
+                    "lat".to_string(),
+                    LiteralValue::Reference("zero".to_string())
+                ),
+                ("lon".to_string(), LiteralValue::Integer(1)),
+            ]),
+            model.value_references[1].value
+        );
+        assert_eq!(
+            LiteralValue::Choice("alt".to_string(), Box::new(LiteralValue::Integer(5))),
+            model.value_references[2].value
+        );
+        assert_eq!(
+            LiteralValue::Sequence(Vec::default()),
+            model.value_references[3].value
+        );
+
+        let result = model.try_resolve();
+        assert_eq!(
+            Some(ResolveError::FailedToResolveValue(
+                "missing value for component lat".to_string()
+            )),
+            result.map_err(ResolveError::into_kind).err()
+        );
+    }
+
+    #[test]
+    pub fn test_structured_value_references_resolved() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Position ::= SEQUENCE {
+                    lat INTEGER,
+                    lon INTEGER,
+                    label UTF8String OPTIONAL
+                }
+                zero INTEGER ::= 0
+                origin Position ::= { lat zero, lon 1 }
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_eq!(
+            LiteralValue::Sequence(vec![
+                ("lat".to_string(), LiteralValue::Integer(0)),
+                ("lon".to_string(), LiteralValue::Integer(1)),
+            ]),
+            model.value_references[1].value
+        );
+    }
+
+    #[test]
+    pub fn test_structured_value_with_unknown_component_fails_to_resolve() {
+        let result = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Shape ::= CHOICE {
+                    alt INTEGER,
+                    name UTF8String
+                }
+                altitude Shape ::= height : 5
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve();
+        assert_eq!(
+            Some(ResolveError::FailedToResolveValue(
+                "unknown alternative height".to_string()
+            )),
+            result.map_err(ResolveError::into_kind).err()
+        );
+    }
+
+    #[test]
+    pub fn test_object_identifier_value_references() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                base OBJECT IDENTIFIER ::= { iso identified-organization(3) 6 }
+                derived OBJECT IDENTIFIER ::= { base 1 2 }
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_eq!(
+            Type::ObjectIdentifier,
+            model.value_references[1].role.r#type
+        );
+        match &model.value_references[1].value {
+            LiteralValue::ObjectIdentifier(oid) => {
+                assert_eq!(Some(vec![1, 3, 6, 1, 2]), oid.arcs())
+            }
+            v => panic!("Unexpected value {:?}", v),
+        }
+    }
+
+    #[test]
+    pub fn test_object_identifier_definitions_are_rejected() {
+        for definition in [
+            "Oid ::= OBJECT IDENTIFIER",
+            "Holder ::= SEQUENCE { id OBJECT IDENTIFIER }",
+        ] {
+            let result = Model::try_from(Tokenizer::default().parse(&format!(
+                "SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN\n{}\nEND",
+                definition
+            )))
+            .expect("Failed to load model")
+            .try_resolve();
+            assert!(
+                matches!(
+                    result.as_ref().map_err(ResolveError::kind),
+                    Err(ResolveError::UnsupportedObjectIdentifier(_))
+                ),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_sequence_typed_default() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Position ::= SEQUENCE {
+                    lat INTEGER,
+                    lon INTEGER
+                }
+                Track ::= SEQUENCE {
+                    start Position DEFAULT { lat 1, lon 2 }
+                }
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        match &model.definitions[1].1.r#type {
+            Type::Sequence(ComponentTypeList { fields, .. }) => assert_eq!(
+                Some(&LiteralValue::Sequence(vec![
+                    ("lat".to_string(), LiteralValue::Integer(1)),
+                    ("lon".to_string(), LiteralValue::Integer(2)),
+                ])),
+                fields[0].role.default.as_ref()
+            ),
+            t => panic!("Unexpected type {:?}", t),
+        }
+    }
+
+    #[test]
+    pub fn test_exports() {
+        let all = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                EXPORTS ALL;
+                Flag ::= BOOLEAN
+                END",
+        ))
+        .expect("Failed to load model");
+        assert_eq!(Exports::All, all.exports);
+
+        let some = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                EXPORTS Flag, max-value;
+                Flag ::= BOOLEAN
+                Other ::= BOOLEAN
+                max-value INTEGER ::= 5
+                END",
+        ))
+        .expect("Failed to load model");
+        assert_eq!(
+            Exports::Symbols(vec!["Flag".to_string(), "max-value".to_string()]),
+            some.exports
+        );
+        assert!(!some.exports.exports("Other"));
+
+        let none = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                EXPORTS ;
+                Flag ::= BOOLEAN
+                END",
+        ))
+        .expect("Failed to load model");
+        assert_eq!(Exports::Symbols(Vec::default()), none.exports);
+        assert!(!none.exports.exports("Flag"));
+    }
+
+    #[test]
+    pub fn test_import_of_not_exported_symbol_fails() {
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"ExternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    EXPORTS Public;
+                    Public ::= BOOLEAN
+                    Private ::= BOOLEAN
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"InternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Public, Private FROM ExternalModule;
+                    Container ::= SEQUENCE {
+                        private Private
+                    }
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+
+        assert_eq!(
+            Some(ResolveError::FailedToImportNotExported(
+                "Private".to_string(),
+                "External".to_string()
+            )),
+            resolver.try_resolve_all().err()
+        );
+    }
+
+    #[test]
+    pub fn test_import_of_exported_symbol() {
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"ExternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    EXPORTS Public;
+                    Public ::= BOOLEAN
+                    Private ::= BOOLEAN
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"InternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Public FROM ExternalModule;
+                    Container ::= SEQUENCE {
+                        public Public
+                    }
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+
+        assert!(resolver.try_resolve_all().is_ok());
+    }
+
+    #[test]
+    pub fn test_ambiguous_import_fails() {
+        let mut resolver = MultiModuleResolver::default();
+        for name in &["ModuleA", "ModuleB"] {
+            resolver.push(
+                Model::try_from(Tokenizer::default().parse(&format!(
+                    r"{} DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                        Shared ::= BOOLEAN
+                    END",
+                    name
+                )))
+                .expect("Failed to parse module"),
+            );
+        }
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"InternalModule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Shared FROM ModuleA Shared FROM ModuleB;
+                    Container ::= SEQUENCE {
+                        shared Shared
+                    }
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+
+        assert_eq!(
+            Some(ResolveError::AmbiguousImport(
+                "Shared".to_string(),
+                "ModuleA".to_string(),
+                "ModuleB".to_string()
+            )),
+            resolver.try_resolve_all().err()
+        );
+    }
+
+    #[test]
+    pub fn test_import_is_satisfied_by_oid_before_name() {
+        let parse = |asn: &str| {
+            Model::try_from(Tokenizer::default().parse(asn)).expect("Failed to parse module")
+        };
+        let version_1 = parse(
+            r"Container { itu-t(0) identified-organization(4) 1 } DEFINITIONS ::= BEGIN
+                Flag ::= BOOLEAN
+            END",
+        );
+        let version_2 = parse(
+            r"Container { itu-t(0) identified-organization(4) 2 } DEFINITIONS ::= BEGIN
+                Flag ::= BOOLEAN
+            END",
+        );
+        let importer = parse(
+            r"Importer DEFINITIONS ::= BEGIN
+                IMPORTS Flag FROM Container { 0 4 2 };
+            END",
+        );
+
+        assert!(!importer.imports[0].is_satisfied_by(&version_1));
+        assert!(importer.imports[0].is_satisfied_by(&version_2));
+
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(importer.clone());
+        resolver.push(version_1.clone());
+        assert_eq!(vec![&importer.imports[0]], resolver.unsatisfied_imports());
+
+        resolver.push(version_2);
+        assert!(resolver.unsatisfied_imports().is_empty());
+        assert!(resolver.try_resolve_all().is_ok());
+    }
+
+    #[test]
+    pub fn test_import_of_ambiguous_module_fails() {
+        let parse = |asn: &str| {
+            Model::try_from(Tokenizer::default().parse(asn)).expect("Failed to parse module")
+        };
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(parse(
+            r"Importer DEFINITIONS ::= BEGIN
+                IMPORTS Flag FROM Container;
+            END",
+        ));
+        for version in 1..=2 {
+            resolver.push(parse(&format!(
+                r"Container {{ 0 4 {} }} DEFINITIONS ::= BEGIN
+                    Flag ::= BOOLEAN
+                END",
+                version
+            )));
+        }
+        assert_eq!(
+            Some(ResolveError::AmbiguousModule("Container".to_string())),
+            resolver.try_resolve_all().err()
+        );
+    }
+
+    #[test]
+    pub fn test_parser_recovers_at_definition_boundaries() {
+        let tokens = Tokenizer::default().parse(
+            r"Broken DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                First ::= SEQUENCE { a BOOLEAN b BOOLEAN }
+                Valid ::= INTEGER
+                Second ::: INTEGER
+                max-value INTEGER ::= 5
+                Third ::= ENUMERATED { one, two
+            END",
+        );
+
+        let errors = Model::try_from_recovering(tokens.clone()).unwrap_err();
+        assert_eq!(
+            vec![
+                Error::unexpected_token(Token::Text(Location::at(2, 48), "b".to_string())),
+                Error::expected_separator('=', Token::Separator(Location::at(4, 26), ':')),
+                Error::unexpected_token(Token::Text(Location::at(7, 13), "END".to_string())),
+            ],
+            errors
+        );
+        assert_eq!(
+            errors.into_iter().next(),
+            Model::try_from(tokens).err(),
+            "try_from must report the first error"
+        );
+    }
+
+    #[test]
+    pub fn test_definition_boundaries() {
+        assert_eq!(
+            vec![
+                Location::at(2, 17),
+                Location::at(3, 17),
+                Location::at(4, 17),
+                Location::at(5, 13)
+            ],
+            Model::definition_boundaries(&Tokenizer::default().parse(
+                r"Broken DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Valid ::= INTEGER
+                Broken ::= SEQUENCE { a }
+                max-value INTEGER ::= 5
+            END",
+            ))
+        );
+    }
+
+    #[test]
+    pub fn test_diagnostic_suggests_similar_names() {
+        let mut resolver = MultiModuleResolver::default();
+        resolver.push(
+            Model::try_from(Tokenizer::default().parse(
+                r"Typos DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Position ::= SEQUENCE {
+                        lat Latitude,
+                        lon INTEGR
+                    }
+                    Latitude ::= INTEGER (0..max-value)
+                    max-value INTEGER ::= 5
+                END",
+            ))
+            .expect("Failed to parse module"),
+        );
+
+        let error = resolver.try_resolve_all().unwrap_err();
+        assert_eq!(
+            &ResolveError::FailedToResolveType("INTEGR".to_string()),
+            error.kind()
+        );
+        assert_eq!(
+            Some(Location::at(2, 21)),
+            resolver.diagnostic(&error).location()
+        );
+        assert_eq!(
+            Some("did you mean `INTEGER`?"),
+            resolver.diagnostic(&error).help()
+        );
+
+        let error = ResolveError::FailedToResolveReference("maxvalue".to_string());
+        assert_eq!(
+            Some("did you mean `max-value`?"),
+            resolver.diagnostic(&error).help()
+        );
+
+        let error = ResolveError::FailedToResolveType("Unrelated".to_string());
+        assert_eq!(None, resolver.diagnostic(&error).help());
+    }
+
+    #[test]
+    pub fn test_comments_are_attached_to_definitions_fields_and_variants() {
+        let model = Model::try_from(Tokenizer::default().parse_with_comments(
+            r#"Comments DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            -- The position
+            -- of a vehicle
+            Position ::= SEQUENCE {
+                latitude  INTEGER (0..100), -- in 0.1 micro degree,
+                                            -- positive for north
+                inner SEQUENCE {
+                    -- the nested
+                    nested BOOLEAN
+                } OPTIONAL,
+                /** not aligned, therefore describing `list` */
+                list SEQUENCE OF INTEGER
+            }
+            Kind ::= ENUMERATED { car, -- a car
+                bus }
+            Choice ::= CHOICE {
+                -- the first
+                first NULL,
+                ..., -- not attached to `first`
+                -- the second
+                second NULL
+            }
+            -- not attached either
+            END"#,
+        ))
+        .unwrap();
+
+        let position = model.definitions[0].value();
+        assert_eq!(
+            Some("The position\nof a vehicle"),
+            position.comment.as_deref()
+        );
+        if let Type::Sequence(list) = &position.r#type {
+            assert_eq!(
+                Some("in 0.1 micro degree,\npositive for north"),
+                list.fields[0].role.comment.as_deref()
+            );
+            assert_eq!(None, list.fields[1].role.comment);
+            if let Type::Optional(inner) = &list.fields[1].role.r#type {
+                if let Type::Sequence(inner) = inner.as_ref() {
+                    assert_eq!(Some("the nested"), inner.fields[0].role.comment.as_deref());
+                } else {
+                    panic!("Unexpected type {:?}", inner);
+                }
+            } else {
+                panic!("Unexpected type {:?}", list.fields[1].role.r#type);
+            }
+            assert_eq!(
+                Some("not aligned, therefore describing `list`"),
+                list.fields[2].role.comment.as_deref()
+            );
+        } else {
+            panic!("Unexpected type {:?}", position.r#type);
+        }
+
+        if let Type::Enumerated(enumerated) = &model.definitions[1].value().r#type {
+            let comments = enumerated
+                .variants()
+                .map(|v| v.comment())
+                .collect::<Vec<_>>();
+            assert_eq!(vec![Some("a car"), None], comments);
+        } else {
+            panic!("Unexpected type {:?}", model.definitions[1].value());
+        }
+
+        if let Type::Choice(choice) = &model.definitions[2].value().r#type {
+            let comments = choice
+                .variants()
+                .map(|v| v.comment.as_deref())
+                .collect::<Vec<_>>();
+            assert_eq!(vec![Some("the first"), Some("the second")], comments);
+        } else {
+            panic!("Unexpected type {:?}", model.definitions[2].value());
+        }
+    }
+
+    #[test]
+    pub fn test_sources_are_carried_into_the_rust_model() {
+        let mut model = Model::try_from(Tokenizer::default().parse(
+            r#"Sources DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Position ::= SEQUENCE {
+                latitude INTEGER,
+                longitude INTEGER
+            }
+            Kind ::= ENUMERATED { car, bus }
+            Vehicle ::= CHOICE { position Position, kind Kind }
+            END"#,
+        ))
+        .unwrap();
+        model.set_source_file("sources.asn1");
+
+        let position = model.definitions[0].value();
+        assert_eq!("sources.asn1:2:13", position.source.to_string());
+        if let Type::Sequence(list) = &position.r#type {
+            assert_eq!(
+                Some(Location::at(4, 17)),
+                list.fields[1].role.source.location()
+            );
+        } else {
+            panic!("Unexpected type {:?}", position.r#type);
+        }
+
+        let rust = model.try_resolve().unwrap().to_rust();
+        let sources = rust
+            .definitions
+            .iter()
+            .map(|d| d.value().sources())
+            .collect::<Vec<_>>();
+        assert_eq!("sources.asn1:2:13", sources[0].definition().to_string());
+        assert_eq!("sources.asn1:3:17", sources[0].member(0).to_string());
+        assert_eq!("sources.asn1:6:40", sources[1].member(1).to_string());
+        assert_eq!("sources.asn1:7:53", sources[2].member(1).to_string());
+        assert_eq!(None, sources[2].member(2).location());
+    }
 }
@@ -1,21 +1,130 @@
+use crate::model::lor::{Error as ResolveError, Resolver};
+use crate::model::{LitOrRef, LiteralValue};
+use std::fmt::{Display, Formatter};
+
 /// The object-identifier is described in ITU-T X.680 | ISO/IEC 8824-1:2015
 /// in chapter 32. The XML-related definitions as well as'DefinedValue' is
 /// ignored by this implementation.
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ObjectIdentifier(pub Vec<ObjectIdentifierComponent>);
 
 impl ObjectIdentifier {
     pub fn iter(&self) -> impl Iterator<Item = &ObjectIdentifierComponent> {
         self.0.iter()
     }
+
+    /// Compares the components by their numbers or, if not both components have a number, by
+    /// their names. Therefore `{ iso(1) 3 }` matches `{ 1 3 }` as well as `{ iso 3 }`.
+    pub fn matches(&self, other: &ObjectIdentifier) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| match (a.number(), b.number()) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => a.name().is_some() && a.name() == b.name(),
+                })
+    }
+
+    /// The numeric value of each component, if known
+    pub fn arcs(&self) -> Option<Vec<u64>> {
+        self.iter().map(ObjectIdentifierComponent::number).collect()
+    }
+
+    /// Replaces a leading reference to another object identifier value with its components and
+    /// assigns the numbers of well-known names (ITU-T X.660 | ISO/IEC 9834-1, Annex A)
+    pub fn try_resolve<R: Resolver<LiteralValue>>(
+        &self,
+        resolver: &R,
+    ) -> Result<ObjectIdentifier, ResolveError> {
+        let mut components = Vec::with_capacity(self.0.len());
+        for (index, component) in self.iter().enumerate() {
+            match component {
+                ObjectIdentifierComponent::NameForm(name) => {
+                    let parent = components
+                        .first()
+                        .and_then(ObjectIdentifierComponent::number);
+                    match well_known_number(index, parent, name) {
+                        Some(number) => components.push(
+                            ObjectIdentifierComponent::NameAndNumberForm(name.clone(), number),
+                        ),
+                        None if index == 0 => {
+                            match resolver.resolve(&LitOrRef::Ref(name.clone()))? {
+                                LiteralValue::ObjectIdentifier(oid) => components.extend(oid.0),
+                                _ => return Err(ResolveError::FailedToResolveValue(name.clone())),
+                            }
+                        }
+                        None => return Err(ResolveError::FailedToResolveValue(name.clone())),
+                    }
+                }
+                component => components.push(component.clone()),
+            }
+        }
+        Ok(ObjectIdentifier(components))
+    }
+}
+
+/// Formats the object identifier like `{ iso(1) standard(0) 8824 }`
+impl Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for component in self.iter() {
+            match component {
+                ObjectIdentifierComponent::NameForm(name) => write!(f, " {}", name)?,
+                ObjectIdentifierComponent::NumberForm(number) => write!(f, " {}", number)?,
+                ObjectIdentifierComponent::NameAndNumberForm(name, number) => {
+                    write!(f, " {}({})", name, number)?
+                }
+            }
+        }
+        write!(f, " }}")
+    }
+}
+
+fn well_known_number(index: usize, parent: Option<u64>, name: &str) -> Option<u64> {
+    Some(match (index, parent, name) {
+        (0, _, "itu-t" | "ccitt") => 0,
+        (0, _, "iso") => 1,
+        (0, _, "joint-iso-itu-t" | "joint-iso-ccitt") => 2,
+        (1, Some(0), "recommendation") => 0,
+        (1, Some(0), "question") => 1,
+        (1, Some(0), "administration") => 2,
+        (1, Some(0), "network-operator") => 3,
+        (1, Some(0), "identified-organization") => 4,
+        (1, Some(1), "standard") => 0,
+        (1, Some(1), "registration-authority") => 1,
+        (1, Some(1), "member-body") => 2,
+        (1, Some(1), "identified-organization") => 3,
+        _ => return None,
+    })
 }
 
 /// The object-identifier is described in ITU-T X.680 | ISO/IEC 8824-1:2015
 /// in chapter 32. The XML-related definitions as well as'DefinedValue' is
 /// ignored by this implementation.
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjectIdentifierComponent {
     NameForm(String),
     NumberForm(u64),
     NameAndNumberForm(String, u64),
 }
+
+impl ObjectIdentifierComponent {
+    pub fn number(&self) -> Option<u64> {
+        match self {
+            ObjectIdentifierComponent::NameForm(_) => None,
+            ObjectIdentifierComponent::NumberForm(number) => Some(*number),
+            ObjectIdentifierComponent::NameAndNumberForm(_, number) => Some(*number),
+        }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            ObjectIdentifierComponent::NameForm(name) => Some(name),
+            ObjectIdentifierComponent::NumberForm(_) => None,
+            ObjectIdentifierComponent::NameAndNumberForm(name, _) => Some(name),
+        }
+    }
+}
@@ -42,7 +42,7 @@ impl ProtobufType {
             ProtobufType::UInt64 => RustType::U64(Range::none()),
             ProtobufType::SInt32 => RustType::I32(Range::inclusive(0, i32::MAX)),
             ProtobufType::SInt64 => RustType::I64(Range::inclusive(0, i64::MAX)),
-            ProtobufType::String => RustType::String(Size::Any, Charset::Utf8),
+            ProtobufType::String => RustType::String(Size::Any, Charset::Utf8, None),
             ProtobufType::Bytes => RustType::VecU8(Size::Any),
             ProtobufType::BitsReprByBytesAndBitsLen => RustType::BitVec(Size::Any),
             ProtobufType::Repeated(inner) => {
@@ -105,8 +105,9 @@ impl ToProtobufType for RustType {
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
 pub enum Protobuf {
-    Message(Vec<(String, ProtobufType)>),
-    Enum(Vec<String>),
+    /// The member [`Comments`] of a message that wraps a `oneof` refer to its `oneof` fields
+    Message(Vec<(String, ProtobufType)>, Comments),
+    Enum(Vec<String>, Comments),
 }
 
 impl Target for Protobuf {
@@ -118,8 +119,12 @@ impl Model<Protobuf> {
     pub fn convert_rust_to_protobuf(rust_model: &Model<Rust>) -> Model<Protobuf> {
         let mut model = Model {
             name: rust_model.name.clone(),
+            source: rust_model.source.clone(),
             oid: rust_model.oid.clone(),
             imports: rust_model.imports.clone(),
+            exports: rust_model.exports.clone(),
+            tag_default: rust_model.tag_default,
+            extensibility_implied: rust_model.extensibility_implied,
             definitions: Vec::with_capacity(rust_model.definitions.len()),
             value_references: Vec::default(),
         };
@@ -139,6 +144,9 @@ impl Model<Protobuf> {
                 tag: _,
                 extension_after: _,
                 ordering: _,
+                comments,
+                sources: _,
+                asn_name: _,
             } => {
                 let mut proto_fields = Vec::with_capacity(fields.len());
                 for field in fields.iter() {
@@ -148,11 +156,15 @@ impl Model<Protobuf> {
                     ));
                 }
 
-                Protobuf::Message(proto_fields)
-            }
-            Rust::Enum(r_enum) => {
-                Protobuf::Enum(r_enum.variants().map(|v| proto_variant_name(v)).collect())
+                Protobuf::Message(proto_fields, comments.clone())
             }
+            Rust::Enum(r_enum) => Protobuf::Enum(
+                r_enum
+                    .variants()
+                    .map(|v| proto_variant_name(v.name()))
+                    .collect(),
+                r_enum.comments().clone(),
+            ),
             Rust::DataEnum(enumeration) => {
                 let mut proto_enum = Vec::with_capacity(enumeration.len());
                 for variant in enumeration.variants() {
@@ -161,15 +173,25 @@ impl Model<Protobuf> {
                         Self::definition_type_to_protobuf_type(variant.r#type()),
                     ))
                 }
-                Protobuf::Message(vec![(
-                    DATAENUM_VARIABLE_NAME_REPLACEMENT.into(),
-                    ProtobufType::OneOf(proto_enum),
-                )])
+                Protobuf::Message(
+                    vec![(
+                        DATAENUM_VARIABLE_NAME_REPLACEMENT.into(),
+                        ProtobufType::OneOf(proto_enum),
+                    )],
+                    enumeration.comments().clone(),
+                )
             }
-            Rust::TupleStruct { r#type: inner, .. } => Protobuf::Message(vec![(
-                TUPLE_VARIABLE_NAME_REPLACEMENT.into(),
-                Self::definition_type_to_protobuf_type(inner),
-            )]),
+            Rust::TupleStruct {
+                r#type: inner,
+                comments,
+                ..
+            } => Protobuf::Message(
+                vec![(
+                    TUPLE_VARIABLE_NAME_REPLACEMENT.into(),
+                    Self::definition_type_to_protobuf_type(inner),
+                )],
+                comments.clone(),
+            ),
         }
     }
 
@@ -186,11 +208,13 @@ impl Model<Protobuf> {
             RustType::U64(_) => ProtobufType::UInt64,
             RustType::I64(_) => ProtobufType::SInt64,
             RustType::String(..) => ProtobufType::String,
-            RustType::VecU8(_) => ProtobufType::Bytes,
+            RustType::VecU8(_) | RustType::ByteArray(_) => ProtobufType::Bytes,
             RustType::BitVec(_) => ProtobufType::BitsReprByBytesAndBitsLen,
             RustType::Null => ProtobufType::Bytes,
 
             RustType::Complex(complex, _) => ProtobufType::Complex(complex.clone()),
+            RustType::Box(inner) => Self::definition_type_to_protobuf_type(inner),
+            RustType::Override(inner, _) => Self::definition_type_to_protobuf_type(inner),
 
             RustType::Option(inner) => {
                 // in protobuf everything is optional...
@@ -201,7 +225,7 @@ impl Model<Protobuf> {
                 Self::definition_type_to_protobuf_type(inner)
             }
 
-            RustType::Vec(inner, _size, _ordering) => {
+            RustType::Vec(inner, ..) | RustType::Array(inner, _) => {
                 ProtobufType::Repeated(Box::new(Self::definition_type_to_protobuf_type(inner)))
             }
         }
@@ -261,7 +285,10 @@ mod tests {
             )],
             &[Definition(
                 "Mine".into(),
-                Protobuf::Message(vec![("field".into(), ProtobufType::UInt32)]),
+                Protobuf::Message(
+                    vec![("field".into(), ProtobufType::UInt32)],
+                    Comments::default(),
+                ),
             )],
         );
     }
@@ -275,10 +302,13 @@ mod tests {
             )],
             &[Definition(
                 "SuchTuple".into(),
-                Protobuf::Message(vec![(
-                    TUPLE_VARIABLE_NAME_REPLACEMENT.into(),
-                    ProtobufType::Complex("VeryWow".into()),
-                )]),
+                Protobuf::Message(
+                    vec![(
+                        TUPLE_VARIABLE_NAME_REPLACEMENT.into(),
+                        ProtobufType::Complex("VeryWow".into()),
+                    )],
+                    Comments::default(),
+                ),
             )],
         );
     }
@@ -292,7 +322,10 @@ mod tests {
             )],
             &[Definition(
                 "SuchEnum".into(),
-                Protobuf::Enum(vec!["VeryWow".into(), "MuchGreat".into()]),
+                Protobuf::Enum(
+                    vec!["VeryWow".into(), "MuchGreat".into()],
+                    Comments::default(),
+                ),
             )],
         );
     }
@@ -304,12 +337,15 @@ mod tests {
                 "SuchStruct".into(),
                 Rust::struct_from_fields(vec![Field::from_name_type(
                     "very_optional",
-                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8))),
+                    RustType::Option(Box::new(RustType::String(Size::Any, Charset::Utf8, None))),
                 )]),
             )],
             &[Definition(
                 "SuchStruct".into(),
-                Protobuf::Message(vec![("very_optional".into(), ProtobufType::String)]),
+                Protobuf::Message(
+                    vec![("very_optional".into(), ProtobufType::String)],
+                    Comments::default(),
+                ),
             )],
         );
     }
@@ -322,17 +358,20 @@ mod tests {
                 Rust::DataEnum(
                     vec![DataVariant::from_name_type(
                         "MuchVariant",
-                        RustType::String(Size::Any, Charset::Utf8),
+                        RustType::String(Size::Any, Charset::Utf8, None),
                     )]
                     .into(),
                 ),
             )],
             &[Definition(
                 "SuchDataEnum".into(),
-                Protobuf::Message(vec![(
-                    DATAENUM_VARIABLE_NAME_REPLACEMENT.into(),
-                    ProtobufType::OneOf(vec![("much_variant".into(), ProtobufType::String)]),
-                )]),
+                Protobuf::Message(
+                    vec![(
+                        DATAENUM_VARIABLE_NAME_REPLACEMENT.into(),
+                        ProtobufType::OneOf(vec![("much_variant".into(), ProtobufType::String)]),
+                    )],
+                    Comments::default(),
+                ),
             )],
         );
     }
@@ -351,13 +390,16 @@ mod tests {
                 ),
             ],
             &[
-                Definition("First".into(), Protobuf::Enum(vec!["A".into(), "B".into()])),
+                Definition(
+                    "First".into(),
+                    Protobuf::Enum(vec!["A".into(), "B".into()], Comments::default()),
+                ),
                 Definition(
                     "Second".into(),
-                    Protobuf::Message(vec![(
-                        TUPLE_VARIABLE_NAME_REPLACEMENT.into(),
-                        ProtobufType::Bytes,
-                    )]),
+                    Protobuf::Message(
+                        vec![(TUPLE_VARIABLE_NAME_REPLACEMENT.into(), ProtobufType::Bytes)],
+                        Comments::default(),
+                    ),
                 ),
             ],
         )
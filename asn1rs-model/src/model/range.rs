@@ -1,4 +1,5 @@
 #[derive(Debug, Default, Clone, Copy, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Range<T>(pub T, pub T, pub bool);
 
 impl<T> Range<T> {
@@ -1,9 +1,15 @@
-use crate::model::lor::{Error, Resolved, Resolver, Unresolved};
-use crate::model::{Asn, Definition, LitOrRef, LiteralValue, Model, Target, Type, ValueReference};
+use crate::model::diagnostic::{Diagnostic, BUILTIN_TYPES};
+use crate::model::lor::{Error, Resolved, Resolver, TagEnvironment, Unresolved};
+use crate::model::search::SkippedFile;
+use crate::model::{
+    Asn, ComponentTypeList, Definition, Import, LitOrRef, LiteralValue, Model, TagDefault, Target,
+    Type, ValueReference,
+};
 
 #[derive(Default)]
 pub struct MultiModuleResolver {
     models: Vec<Model<Asn<Unresolved>>>,
+    pub(crate) skipped: Vec<SkippedFile>,
 }
 
 impl MultiModuleResolver {
@@ -11,15 +17,87 @@ impl MultiModuleResolver {
         self.models.push(model);
     }
 
+    /// The pushed (unresolved) modules in the order they were pushed
+    pub fn models(&self) -> &[Model<Asn<Unresolved>>] {
+        &self.models[..]
+    }
+
+    /// The files that [`MultiModuleResolver::push_imports_from`] skipped, because they cannot be
+    /// parsed, with their parse errors
+    pub fn skipped_files(&self) -> &[SkippedFile] {
+        &self.skipped[..]
+    }
+
+    /// Whether one of the pushed modules satisfies the given import
+    pub fn contains(&self, import: &Import) -> bool {
+        self.models
+            .iter()
+            .any(|model| import.is_satisfied_by(model))
+    }
+
+    /// The imports of the pushed modules, that refer to modules that have not been pushed
+    pub fn unsatisfied_imports(&self) -> Vec<&Import> {
+        let mut imports: Vec<&Import> = Vec::new();
+        for import in self.models.iter().flat_map(|model| model.imports.iter()) {
+            if !self.contains(import)
+                && !imports
+                    .iter()
+                    .any(|i| i.from.eq(&import.from) && i.from_oid.eq(&import.from_oid))
+            {
+                imports.push(import);
+            }
+        }
+        imports
+    }
+
+    /// Describes the given error and suggests similarly named definitions or value references
+    /// of the pushed modules, if the error is about an unknown name
+    pub fn diagnostic(&self, error: &Error) -> Diagnostic {
+        let diagnostic = Diagnostic::from(error);
+        let error = error.kind();
+        let definitions = self
+            .models
+            .iter()
+            .flat_map(|model| model.definitions.iter().map(|d| d.name()));
+        let values = self
+            .models
+            .iter()
+            .flat_map(|model| model.value_references.iter().map(|v| v.name.as_str()));
+        match error {
+            Error::FailedToResolveType(name) => {
+                diagnostic.with_suggestion(name, definitions.chain(BUILTIN_TYPES.iter().copied()))
+            }
+            Error::FailedToResolveReference(name) => diagnostic.with_suggestion(name, values),
+            Error::FailedToImportNotExported(name, module) => diagnostic.with_suggestion(
+                name,
+                self.models
+                    .iter()
+                    .filter(|model| model.name.eq(module))
+                    .flat_map(|model| {
+                        model
+                            .definitions
+                            .iter()
+                            .map(|d| d.name())
+                            .chain(model.value_references.iter().map(|v| v.name.as_str()))
+                            .filter(move |name| model.exports.exports(name))
+                    }),
+            ),
+            _ => diagnostic,
+        }
+    }
+
+    /// Resolves all pushed modules. In contrast to [`Model::try_resolve`], this also ensures
+    /// that each referenced type is either defined or imported by the referencing module.
     pub fn try_resolve_all(&self) -> Result<Vec<Model<Asn<Resolved>>>, Error> {
         self.models
             .iter()
             .map(|model| {
-                ResolveScope {
+                let scope = ResolveScope {
                     model,
                     scope: &self.models,
-                }
-                .try_resolve()
+                };
+                scope.check_type_references()?;
+                scope.try_resolve()
             })
             .collect::<_>()
     }
@@ -41,75 +119,181 @@ impl<'a> From<&'a Model<Asn<Unresolved>>> for ResolveScope<'a> {
 
 impl<'a> ResolveScope<'a> {
     pub(crate) fn try_resolve(&self) -> Result<Model<Asn<Resolved>>, Error> {
+        self.check_imports()?;
+
         let mut result = Model::<Asn<Resolved>> {
             name: self.model.name.clone(),
+            source: self.model.source.clone(),
             oid: self.model.oid.clone(),
             imports: self.model.imports.clone(),
+            exports: self.model.exports.clone(),
+            tag_default: self.model.tag_default,
+            extensibility_implied: self.model.extensibility_implied,
             definitions: Vec::with_capacity(self.model.definitions.len()),
             value_references: Vec::with_capacity(self.model.value_references.len()),
         };
 
         // copy over all value references
         for vr in &self.model.value_references {
+            let role = vr
+                .role
+                .try_resolve(self)
+                .map_err(|e| e.at(&vr.role.source))?;
             result.value_references.push(ValueReference {
                 name: vr.name.clone(),
-                role: vr.role.try_resolve(self)?,
-                value: vr.value.clone(),
+                value: vr
+                    .value
+                    .try_resolve(&role.r#type, self)
+                    .map_err(|e| e.at(&vr.role.source))?,
+                role,
             })
         }
 
         for Definition(name, asn) in &self.model.definitions {
-            result
-                .definitions
-                .push(Definition(name.clone(), asn.try_resolve(self)?))
+            let asn = asn.try_resolve(self).map_err(|e| e.at(&asn.source))?;
+            if contains_object_identifier(&asn.r#type) {
+                return Err(Error::UnsupportedObjectIdentifier(name.clone()).at(&asn.source));
+            }
+            result.definitions.push(Definition(name.clone(), asn))
         }
 
         Ok(result)
     }
 
+    /// Ensures that each imported symbol is exported by the module it is imported from (if that
+    /// module is in scope), that the module is unambiguous and that no symbol is imported from
+    /// more than one module, because references cannot be qualified with the module name.
+    fn check_imports(&self) -> Result<(), Error> {
+        for (index, import) in self.model.imports.iter().enumerate() {
+            for what in &import.what {
+                if let Some(other) = self.model.imports[..index]
+                    .iter()
+                    .find(|other| other.what.contains(what) && other.from.ne(&import.from))
+                {
+                    return Err(Error::AmbiguousImport(
+                        what.clone(),
+                        other.from.clone(),
+                        import.from.clone(),
+                    ));
+                }
+            }
+
+            if self
+                .scope
+                .iter()
+                .filter(|model| import.is_satisfied_by(model))
+                .nth(1)
+                .is_some()
+            {
+                return Err(Error::AmbiguousModule(import.from.clone()));
+            }
+
+            if let Some(model) = self.imported_model(import) {
+                if let Some(what) = import.what.iter().find(|w| !model.exports.exports(w)) {
+                    return Err(Error::FailedToImportNotExported(
+                        what.clone(),
+                        import.from.clone(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_type_references(&self) -> Result<(), Error> {
+        for Definition(_, asn) in &self.model.definitions {
+            for name in asn.r#type.type_references() {
+                let imported = self
+                    .model
+                    .imports
+                    .iter()
+                    .any(|i| i.what.iter().any(|what| what.eq(name)));
+                if !imported && self.definition(name).is_none() {
+                    return Err(Error::FailedToResolveType(name.to_string()).at(&asn.source));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn imported_model(&self, import: &Import) -> Option<&'a Model<Asn<Unresolved>>> {
+        self.scope.iter().find(|m| import.is_satisfied_by(m))
+    }
+
     fn model_with_imported_item(&self, item: &str) -> Option<&'a Model<Asn<Unresolved>>> {
         self.model
             .imports
             .iter()
             .find(|i| i.what.iter().any(|what| what.eq(item)))
-            .and_then(|import| {
-                self.scope.iter().find(|m| {
-                    (m.oid.is_some() && m.oid.eq(&import.from_oid)) || m.name.eq(&import.from)
-                })
-            })
+            .and_then(|import| self.imported_model(import))
     }
 
     fn value_reference(
         &self,
         name: &str,
     ) -> Option<&'a ValueReference<<Asn<Unresolved> as Target>::ValueReferenceType>> {
+        self.scoped_value_reference(name).map(|(_, vr)| vr)
+    }
+
+    /// Like [`ResolveScope::value_reference`] but also returns the scope of the module the
+    /// [`ValueReference`] is declared in
+    fn scoped_value_reference(
+        &self,
+        name: &str,
+    ) -> Option<(
+        ResolveScope<'a>,
+        &'a ValueReference<<Asn<Unresolved> as Target>::ValueReferenceType>,
+    )> {
         self.model
             .value_references
             .iter()
             .find(|vr| vr.name.eq(name))
+            .map(|vr| {
+                let scope = ResolveScope {
+                    model: self.model,
+                    scope: self.scope,
+                };
+                (scope, vr)
+            })
             .or_else(|| {
                 self.model_with_imported_item(name).and_then(|model| {
                     ResolveScope {
                         model,
                         scope: self.scope,
                     }
-                    .value_reference(name)
+                    .scoped_value_reference(name)
                 })
             })
     }
 
     fn definition(&self, name: &str) -> Option<&'a Definition<Asn<Unresolved>>> {
+        self.scoped_definition(name).map(|(_, def)| def)
+    }
+
+    /// Like [`ResolveScope::definition`] but also returns the scope of the module the
+    /// [`Definition`] is declared in
+    fn scoped_definition(
+        &self,
+        name: &str,
+    ) -> Option<(ResolveScope<'a>, &'a Definition<Asn<Unresolved>>)> {
         self.model
             .definitions
             .iter()
             .find(|def| def.name().eq(name))
+            .map(|def| {
+                let scope = ResolveScope {
+                    model: self.model,
+                    scope: self.scope,
+                };
+                (scope, def)
+            })
             .or_else(|| {
                 self.model_with_imported_item(name).and_then(|model| {
                     ResolveScope {
                         model,
                         scope: self.scope,
                     }
-                    .definition(name)
+                    .scoped_definition(name)
                 })
             })
     }
@@ -148,10 +332,13 @@ impl Resolver<LiteralValue> for ResolveScope<'_> {
     fn resolve(&self, lor: &LitOrRef<LiteralValue>) -> Result<LiteralValue, Error> {
         match lor {
             LitOrRef::Lit(lit) => Ok(lit.clone()),
-            LitOrRef::Ref(name) => self
-                .value_reference(name)
-                .map(|vr| vr.value.clone())
-                .ok_or_else(|| Error::FailedToResolveReference(name.clone())),
+            LitOrRef::Ref(name) => {
+                let (scope, vr) = self
+                    .scoped_value_reference(name)
+                    .ok_or_else(|| Error::FailedToResolveReference(name.clone()))?;
+                let role = vr.role.try_resolve(&scope)?;
+                vr.value.try_resolve(&role.r#type, &scope)
+            }
         }
     }
 }
@@ -167,3 +354,77 @@ impl Resolver<Type<Unresolved>> for ResolveScope<'_> {
         }
     }
 }
+
+impl Resolver<ComponentTypeList<Resolved>> for ResolveScope<'_> {
+    fn resolve(
+        &self,
+        lor: &LitOrRef<ComponentTypeList<Resolved>>,
+    ) -> Result<ComponentTypeList<Resolved>, Error> {
+        match lor {
+            LitOrRef::Lit(lit) => Ok(lit.clone()),
+            LitOrRef::Ref(name) => {
+                let (scope, definition) = self
+                    .scoped_definition(name)
+                    .ok_or_else(|| Error::FailedToResolveType(name.clone()))?;
+                match &definition.1.r#type {
+                    Type::Sequence(components) | Type::Set(components) => {
+                        components.try_resolve(&scope)
+                    }
+                    Type::TypeReference(other, _) => {
+                        scope.resolve(&LitOrRef::<ComponentTypeList<Resolved>>::Ref(other.clone()))
+                    }
+                    _ => Err(Error::FailedToExpandComponentsOf(name.clone())),
+                }
+            }
+        }
+    }
+}
+
+impl TagEnvironment for ResolveScope<'_> {
+    fn tag_default(&self) -> TagDefault {
+        self.model.tag_default
+    }
+}
+
+impl Resolver<Asn<Resolved>> for ResolveScope<'_> {
+    fn resolve(&self, lor: &LitOrRef<Asn<Resolved>>) -> Result<Asn<Resolved>, Error> {
+        match lor {
+            LitOrRef::Lit(lit) => Ok(lit.clone()),
+            LitOrRef::Ref(name) => {
+                let (scope, definition) = self
+                    .scoped_definition(name)
+                    .ok_or_else(|| Error::FailedToResolveType(name.clone()))?;
+                if let Type::TypeReference(other, _) = &definition.1.r#type {
+                    // follow the reference, but keep the outermost tag
+                    let inner = scope.resolve(&LitOrRef::<Asn<Resolved>>::Ref(other.clone()))?;
+                    Ok(Asn {
+                        tag: definition.1.tag.or(inner.tag),
+                        ..inner
+                    })
+                } else {
+                    definition.1.try_resolve(&scope)
+                }
+            }
+        }
+    }
+}
+
+/// Whether the type is or contains an `OBJECT IDENTIFIER`, see
+/// [`Error::UnsupportedObjectIdentifier`]
+fn contains_object_identifier(r#type: &Type<Resolved>) -> bool {
+    match r#type {
+        Type::ObjectIdentifier => true,
+        Type::Optional(inner)
+        | Type::Default(inner, _)
+        | Type::SequenceOf(inner, _)
+        | Type::SetOf(inner, _) => contains_object_identifier(inner),
+        Type::Sequence(components) | Type::Set(components) => components
+            .fields
+            .iter()
+            .any(|field| contains_object_identifier(&field.role.r#type)),
+        Type::Choice(choice) => choice
+            .variants()
+            .any(|variant| contains_object_identifier(&variant.r#type)),
+        _ => false,
+    }
+}
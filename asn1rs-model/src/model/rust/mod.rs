@@ -1,9 +1,12 @@
+pub mod visit;
+
 use crate::model::lor::{ResolveState, Resolved};
 use crate::model::rust::Field as RustField;
-use crate::model::{Asn, ChoiceVariant, Integer, LiteralValue, Target};
+use crate::model::{Asn, ChoiceVariant, Comments, Integer, LiteralValue, Target};
 use crate::model::{Charset, Range};
 use crate::model::{ComponentTypeList, ValueReference};
-use crate::model::{Definition, Type};
+use crate::model::{Definition, Exports, Type};
+use crate::model::{IgnoredInComparison, Source, Sources};
 use crate::model::{Import, Tag, TagProperty};
 use crate::model::{Model, Size};
 use crate::model::{TagResolver, Type as AsnType};
@@ -19,7 +22,6 @@ const U16_MAX: u64 = u16::MAX as u64;
 const U32_MAX: u64 = u32::MAX as u64;
 //const U64_MAX: u64 = u64::MAX as u64;
 
-pub type PlainVariant = String;
 pub type PlainEnum = Enumeration<PlainVariant>;
 pub type DataEnum = Enumeration<DataVariant>;
 
@@ -29,6 +31,7 @@ pub type DataEnum = Enumeration<DataVariant>;
 /// use ::from(..) to cast from b
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RustType {
     Bool,
     I8(Range<i8>),
@@ -39,15 +42,28 @@ pub enum RustType {
     U32(Range<u32>),
     I64(Range<i64>),
     U64(Range<Option<u64>>),
-    String(Size, Charset),
+    /// A string, of the charset and permitted alphabet of the ASN.1 type
+    String(Size, Charset, Option<String>),
     VecU8(Size),
     BitVec(Size),
     Vec(Box<RustType>, Size, EncodingOrdering),
+    /// `[u8; N]` for an `OCTET STRING` of the non-extensible fixed size `N`
+    ByteArray(usize),
+    /// `[T; N]` for a `SEQUENCE OF` of the non-extensible fixed size `N`
+    Array(Box<RustType>, usize),
     Null,
 
     Option(Box<RustType>),
     Default(Box<RustType>, LiteralValue),
 
+    /// Heap allocated indirection to a `Complex` type. Inserted for recursive definitions
+    /// that would otherwise result in infinitely sized types.
+    Box(Box<RustType>),
+
+    /// A user type that replaces the inner type in the generated code and that is converted
+    /// from and to the inner type when it is read or written, see [`TypeOverride`]
+    Override(Box<RustType>, TypeOverride),
+
     /// Indicates a complex, custom type that is
     /// not one of rusts known types. This can be
     /// thought of as a "ReferenceType"; declaring usage,
@@ -55,10 +71,52 @@ pub enum RustType {
     Complex(String, Option<Tag>),
 }
 
+/// Replaces the generated type of a definition or field by a user type, such as a newtype
+/// for `TimestampIts` or `[u8; 6]` for a MAC address. The encoding is not affected: values are
+/// read as the generated type and converted into the user type and vice versa by the
+/// `conversion`, which implements `asn1rs::syn::mapped::Conversion`. Without a `conversion`,
+/// the user type has to implement `TryFrom<T>` (or `From<T>`) and `T` has to implement
+/// `From<&UserType>`, where `T` is the generated type.
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TypeOverride {
+    rust_type: String,
+    conversion: Option<String>,
+}
+
+impl TypeOverride {
+    pub fn new<S: Into<String>>(rust_type: S) -> Self {
+        Self {
+            rust_type: rust_type.into(),
+            conversion: None,
+        }
+    }
+
+    /// Converts with the given type instead of `From`, which is required if neither the user
+    /// type nor the generated type are defined in the crate of the user (such as `[u8; 6]` and
+    /// `Vec<u8>`)
+    pub fn with_conversion<S: Into<String>>(mut self, conversion: S) -> Self {
+        self.conversion = Some(conversion.into());
+        self
+    }
+
+    pub fn rust_type(&self) -> &str {
+        &self.rust_type
+    }
+
+    pub fn conversion(&self) -> Option<&str> {
+        self.conversion.as_deref()
+    }
+}
+
 impl RustType {
     pub fn as_inner_type(&self) -> &RustType {
-        if let RustType::Vec(inner, ..) | RustType::Option(inner) | RustType::Default(inner, ..) =
-            self
+        if let RustType::Vec(inner, ..)
+        | RustType::Array(inner, _)
+        | RustType::Option(inner)
+        | RustType::Default(inner, ..)
+        | RustType::Box(inner)
+        | RustType::Override(inner, _) = self
         {
             inner.as_inner_type()
         } else {
@@ -67,8 +125,12 @@ impl RustType {
     }
 
     pub fn into_inner_type(self) -> RustType {
-        if let RustType::Vec(inner, ..) | RustType::Option(inner) | RustType::Default(inner, ..) =
-            self
+        if let RustType::Vec(inner, ..)
+        | RustType::Array(inner, _)
+        | RustType::Option(inner)
+        | RustType::Default(inner, ..)
+        | RustType::Box(inner)
+        | RustType::Override(inner, _) = self
         {
             inner.into_inner_type()
         } else {
@@ -96,6 +158,67 @@ impl RustType {
         }
     }
 
+    /// The name of the referenced `Complex` type, if it is stored inline and not behind the
+    /// indirection of a `Vec` or `Box`
+    fn unboxed_complex_name(&self) -> Option<&str> {
+        match self {
+            RustType::Option(inner) | RustType::Default(inner, ..) => inner.unboxed_complex_name(),
+            RustType::Complex(name, _) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Wraps the (inline) `Complex` type into a `Box`
+    fn box_complex(&mut self) {
+        match self {
+            RustType::Option(inner) | RustType::Default(inner, ..) => inner.box_complex(),
+            RustType::Complex(..) => {
+                let complex = std::mem::replace(self, RustType::Null);
+                *self = RustType::Box(Box::new(complex));
+            }
+            _ => {}
+        }
+    }
+
+    /// Replaces the type by the user type of the override. For `Option` and `Vec`, the inner
+    /// type is replaced instead, so that `Option<T>` becomes `Option<UserType>`. An existing
+    /// override is replaced.
+    pub fn override_with(&mut self, type_override: TypeOverride) {
+        match self {
+            RustType::Option(inner) | RustType::Vec(inner, ..) | RustType::Array(inner, _) => {
+                inner.override_with(type_override)
+            }
+            RustType::Override(_, current) => *current = type_override,
+            _ => {
+                let inner = std::mem::replace(self, RustType::Null);
+                *self = RustType::Override(Box::new(inner), type_override);
+            }
+        }
+    }
+
+    /// Converts an `OCTET STRING` or `SEQUENCE OF` with a non-extensible fixed size from
+    /// `Vec<u8>` or `Vec<T>` into `[u8; N]` or `[T; N]`, other types are returned unchanged
+    pub fn into_fixed_size_array(self) -> Self {
+        match self {
+            RustType::VecU8(Size::Fix(size, false)) => RustType::ByteArray(size),
+            RustType::Vec(inner, Size::Fix(size, false), EncodingOrdering::Keep) => {
+                RustType::Array(inner, size)
+            }
+            rust => rust,
+        }
+    }
+
+    /// Reverses [`RustType::into_fixed_size_array`]
+    pub fn into_vec(self) -> Self {
+        match self {
+            RustType::ByteArray(size) => RustType::VecU8(Size::Fix(size, false)),
+            RustType::Array(inner, size) => {
+                RustType::Vec(inner, Size::Fix(size, false), EncodingOrdering::Keep)
+            }
+            rust => rust,
+        }
+    }
+
     pub fn is_vec(&self) -> bool {
         matches!(self.as_no_option(), RustType::Vec(..))
     }
@@ -107,7 +230,11 @@ impl RustType {
 
     /// Values which might not be serialized according to ASN
     pub fn is_optional(&self) -> bool {
-        matches!(self, RustType::Option(..) | RustType::Default(..))
+        match self {
+            RustType::Option(..) | RustType::Default(..) => true,
+            RustType::Override(inner, _) => inner.is_optional(),
+            _ => false,
+        }
     }
 
     pub fn is_primitive(&self) -> bool {
@@ -159,9 +286,13 @@ impl RustType {
             RustType::VecU8(_) => None,
             RustType::BitVec(_) => None,
             RustType::Vec(inner, _size, _ordering) => inner.integer_range_str(),
+            RustType::ByteArray(_) => None,
+            RustType::Array(inner, _size) => inner.integer_range_str(),
             RustType::Null => None,
             RustType::Option(inner) => inner.integer_range_str(),
             RustType::Default(inner, ..) => inner.integer_range_str(),
+            RustType::Box(_) => None,
+            RustType::Override(..) => None,
             RustType::Complex(_, _) => None,
         }
     }
@@ -207,7 +338,7 @@ impl RustType {
                 range.max().map(|v| v as i64),
                 range.extensible(),
             )),
-            RustType::String(size, charset) => AsnType::String(size, charset),
+            RustType::String(size, charset, alphabet) => AsnType::String(size, charset, alphabet),
             RustType::VecU8(size) => AsnType::OctetString(size),
             RustType::BitVec(size) => AsnType::bit_vec_with_size(size),
             RustType::Vec(inner, size, EncodingOrdering::Keep) => {
@@ -216,11 +347,17 @@ impl RustType {
             RustType::Vec(inner, size, EncodingOrdering::Sort) => {
                 AsnType::SetOf(Box::new(inner.into_asn()), size)
             }
+            RustType::ByteArray(size) => AsnType::OctetString(Size::Fix(size, false)),
+            RustType::Array(inner, size) => {
+                AsnType::SequenceOf(Box::new(inner.into_asn()), Size::Fix(size, false))
+            }
             RustType::Null => AsnType::Null,
             RustType::Option(value) => AsnType::Optional(Box::new(value.into_asn())),
             RustType::Default(value, default) => {
                 AsnType::Default(Box::new(value.into_asn()), default)
             }
+            RustType::Box(inner) => inner.into_asn(),
+            RustType::Override(inner, _) => inner.into_asn(),
             RustType::Complex(name, tag) => AsnType::TypeReference(name, tag),
         }
     }
@@ -246,6 +383,10 @@ impl RustType {
                     false
                 }
             }
+            RustType::ByteArray(_) => matches!(other, RustType::ByteArray(_)),
+            RustType::Array(inner, _size) => {
+                matches!(other, RustType::Array(o, _) if o.similar(inner))
+            }
             RustType::Null => RustType::Null == *other,
             RustType::Option(inner) => {
                 matches!(other, RustType::Option(o) if o.similar(inner))
@@ -256,6 +397,10 @@ impl RustType {
                     || matches!(other, RustType::Default(o, ..) if o.similar(inner))
                     || matches!(other, RustType::Option(o, ..) if o.similar(inner))
             }
+            RustType::Box(inner) => matches!(other, RustType::Box(o) if o.similar(inner)),
+            RustType::Override(inner, type_override) => {
+                matches!(other, RustType::Override(o, t) if o.similar(inner) && t == type_override)
+            }
             RustType::Complex(inner_a, _tag) => {
                 if let RustType::Complex(inner_b, _tag) = other {
                     inner_a.eq(inner_b)
@@ -279,13 +424,17 @@ impl RustType {
             | RustType::I64(_)
             | RustType::U64(_) => Tag::DEFAULT_INTEGER,
             RustType::BitVec(_) => Tag::DEFAULT_BIT_STRING,
-            RustType::VecU8(_) => Tag::DEFAULT_OCTET_STRING,
-            RustType::String(_, charset) => charset.default_tag(),
-            RustType::Vec(_, _, EncodingOrdering::Keep) => Tag::DEFAULT_SEQUENCE_OF,
+            RustType::VecU8(_) | RustType::ByteArray(_) => Tag::DEFAULT_OCTET_STRING,
+            RustType::String(_, charset, _) => charset.default_tag(),
+            RustType::Vec(_, _, EncodingOrdering::Keep) | RustType::Array(..) => {
+                Tag::DEFAULT_SEQUENCE_OF
+            }
             RustType::Vec(_, _, EncodingOrdering::Sort) => Tag::DEFAULT_SET_OF,
             RustType::Null => Tag::DEFAULT_NULL,
             RustType::Option(inner) => return inner.tag(),
             RustType::Default(inner, ..) => return inner.tag(),
+            RustType::Box(inner) => return inner.tag(),
+            RustType::Override(inner, _) => return inner.tag(),
             // TODO this is wrong. This should resolve the tag from the referenced type instead, but atm the infrastructure is missing to do such a thing, see github#13
             RustType::Complex(_, tag) => return *tag,
         })
@@ -295,18 +444,23 @@ impl RustType {
 /// Describes whether the original declaration cares about (re-)ordering the elements or whether
 /// their encoding is to be applied in the order of definition (struct fields) or appearance (vec)
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EncodingOrdering {
     Sort,
     Keep,
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Rust {
     Struct {
         ordering: EncodingOrdering,
         fields: Vec<Field>,
         tag: Option<Tag>,
         extension_after: Option<usize>,
+        comments: Comments,
+        sources: IgnoredInComparison<Sources>,
+        asn_name: IgnoredInComparison<Option<String>>,
     },
     Enum(PlainEnum),
     DataEnum(DataEnum),
@@ -317,6 +471,9 @@ pub enum Rust {
         r#type: RustType,
         tag: Option<Tag>,
         constants: Vec<(String, String)>,
+        comments: Comments,
+        sources: IgnoredInComparison<Sources>,
+        asn_name: IgnoredInComparison<Option<String>>,
     },
 }
 
@@ -328,6 +485,9 @@ impl Rust {
             fields,
             tag: None,
             extension_after: None,
+            comments: Comments::default(),
+            sources: IgnoredInComparison::default(),
+            asn_name: IgnoredInComparison::default(),
         }
     }
 
@@ -336,6 +496,86 @@ impl Rust {
             r#type,
             tag: None,
             constants: Vec::default(),
+            comments: Comments::default(),
+            sources: IgnoredInComparison::default(),
+            asn_name: IgnoredInComparison::default(),
+        }
+    }
+
+    /// The comments of the definition and of its fields or variants
+    pub fn comments(&self) -> &Comments {
+        match self {
+            Rust::Struct { comments, .. } | Rust::TupleStruct { comments, .. } => comments,
+            Rust::Enum(enumeration) => enumeration.comments(),
+            Rust::DataEnum(enumeration) => enumeration.comments(),
+        }
+    }
+
+    fn comments_mut(&mut self) -> &mut Comments {
+        match self {
+            Rust::Struct { comments, .. } | Rust::TupleStruct { comments, .. } => comments,
+            Rust::Enum(enumeration) => &mut enumeration.comments,
+            Rust::DataEnum(enumeration) => &mut enumeration.comments,
+        }
+    }
+
+    /// Where the definition and its fields or variants are defined in the ASN.1 source
+    pub fn sources(&self) -> &Sources {
+        match self {
+            Rust::Struct { sources, .. } | Rust::TupleStruct { sources, .. } => sources,
+            Rust::Enum(enumeration) => enumeration.sources(),
+            Rust::DataEnum(enumeration) => enumeration.sources(),
+        }
+    }
+
+    fn sources_mut(&mut self) -> &mut Sources {
+        match self {
+            Rust::Struct { sources, .. } | Rust::TupleStruct { sources, .. } => sources,
+            Rust::Enum(enumeration) => &mut enumeration.sources,
+            Rust::DataEnum(enumeration) => &mut enumeration.sources,
+        }
+    }
+
+    /// The name of the ASN.1 definition, see [`Definition::asn_name`]
+    pub(crate) fn asn_name(&self) -> Option<&str> {
+        match self {
+            Rust::Struct { asn_name, .. } | Rust::TupleStruct { asn_name, .. } => asn_name,
+            Rust::Enum(enumeration) => &enumeration.asn_name,
+            Rust::DataEnum(enumeration) => &enumeration.asn_name,
+        }
+        .as_deref()
+    }
+
+    fn asn_name_mut(&mut self) -> &mut Option<String> {
+        match self {
+            Rust::Struct { asn_name, .. } | Rust::TupleStruct { asn_name, .. } => asn_name,
+            Rust::Enum(enumeration) => &mut enumeration.asn_name,
+            Rust::DataEnum(enumeration) => &mut enumeration.asn_name,
+        }
+    }
+
+    /// The types of all fields, variants or the inner type, in order of declaration
+    fn member_types(&self) -> Vec<&RustType> {
+        match self {
+            Rust::Struct { fields, .. } => fields.iter().map(Field::r#type).collect(),
+            Rust::Enum(_) => Vec::default(),
+            Rust::DataEnum(enumeration) => {
+                enumeration.variants().map(DataVariant::r#type).collect()
+            }
+            Rust::TupleStruct { r#type, .. } => vec![r#type],
+        }
+    }
+
+    fn member_types_mut(&mut self) -> Vec<&mut RustType> {
+        match self {
+            Rust::Struct { fields, .. } => fields.iter_mut().map(|f| &mut f.name_type.1).collect(),
+            Rust::Enum(_) => Vec::default(),
+            Rust::DataEnum(enumeration) => enumeration
+                .variants
+                .iter_mut()
+                .map(|v| &mut v.name_type.1)
+                .collect(),
+            Rust::TupleStruct { r#type, .. } => vec![r#type],
         }
     }
 }
@@ -395,11 +635,21 @@ impl RustType {
             RustType::Vec(inner, _size, _ordering) => {
                 return Cow::Owned(format!("&'static [{}]", inner.to_const_lit_string()))
             }
+            RustType::ByteArray(size) => return Cow::Owned(format!("[u8; {}]", size)),
+            RustType::Array(inner, size) => {
+                return Cow::Owned(format!("[{}; {}]", inner.to_const_lit_string(), size))
+            }
             RustType::Null => "Null",
             RustType::Option(inner) => {
                 return Cow::Owned(format!("Option<{}>", inner.to_const_lit_string()))
             }
             RustType::Default(inner, ..) => return inner.to_const_lit_string(),
+            RustType::Box(inner) => {
+                return Cow::Owned(format!("Box<{}>", inner.to_const_lit_string()))
+            }
+            RustType::Override(_, type_override) => {
+                return Cow::Owned(type_override.rust_type().to_string())
+            }
             RustType::Complex(name, _) => return Cow::Owned(name.clone()),
         })
     }
@@ -421,9 +671,13 @@ impl ToString for RustType {
             RustType::VecU8(_) => "Vec<u8>",
             RustType::BitVec(_) => "BitVec",
             RustType::Vec(inner, _size, _ordering) => return format!("Vec<{}>", inner.to_string()),
+            RustType::ByteArray(size) => return format!("[u8; {}]", size),
+            RustType::Array(inner, size) => return format!("[{}; {}]", inner.to_string(), size),
             RustType::Null => "Null",
             RustType::Option(inner) => return format!("Option<{}>", inner.to_string()),
             RustType::Default(inner, ..) => return inner.to_string(),
+            RustType::Box(inner) => return format!("Box<{}>", inner.to_string()),
+            RustType::Override(_, type_override) => return type_override.rust_type().to_string(),
             RustType::Complex(name, _) => return name.clone(),
         }
         .into()
@@ -431,10 +685,12 @@ impl ToString for RustType {
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Field {
     pub(crate) name_type: (String, RustType),
     pub(crate) tag: Option<Tag>,
     pub(crate) constants: Vec<(String, String)>,
+    pub(crate) asn_name: IgnoredInComparison<Option<String>>,
 }
 
 impl Field {
@@ -443,6 +699,7 @@ impl Field {
             name_type: (name.to_string(), r#type),
             tag: None,
             constants: Vec::default(),
+            asn_name: IgnoredInComparison::default(),
         }
     }
 
@@ -458,6 +715,10 @@ impl Field {
         &self.name_type.1
     }
 
+    pub fn r#type_mut(&mut self) -> &mut RustType {
+        &mut self.name_type.1
+    }
+
     pub fn constants(&self) -> &[(String, String)] {
         &self.constants[..]
     }
@@ -466,6 +727,18 @@ impl Field {
         self.constants = constants;
         self
     }
+
+    /// The name of the field in the ASN.1 module, which differs from [`Field::name`] if it was
+    /// changed to be a valid Rust identifier
+    pub fn asn_name(&self) -> &str {
+        self.asn_name.as_deref().unwrap_or_else(|| self.name())
+    }
+
+    /// Remembers the name in the ASN.1 module, which is not compared
+    pub fn with_asn_name<T: ToString>(mut self, asn_name: T) -> Self {
+        self.asn_name = Some(asn_name.to_string()).into();
+        self
+    }
 }
 
 impl TagProperty for Field {
@@ -483,10 +756,14 @@ impl TagProperty for Field {
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enumeration<T> {
     variants: Vec<T>,
     tag: Option<Tag>,
     extended_after_index: Option<usize>,
+    comments: Comments,
+    sources: IgnoredInComparison<Sources>,
+    asn_name: IgnoredInComparison<Option<String>>,
 }
 
 impl<T> From<Vec<T>> for Enumeration<T> {
@@ -495,6 +772,9 @@ impl<T> From<Vec<T>> for Enumeration<T> {
             variants,
             tag: None,
             extended_after_index: None,
+            comments: Comments::default(),
+            sources: IgnoredInComparison::default(),
+            asn_name: IgnoredInComparison::default(),
         }
     }
 }
@@ -517,6 +797,10 @@ impl<T> Enumeration<T> {
         self.variants.iter()
     }
 
+    pub fn variants_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.variants.iter_mut()
+    }
+
     pub fn extension_after_index(&self) -> Option<usize> {
         self.extended_after_index
     }
@@ -529,6 +813,24 @@ impl<T> Enumeration<T> {
     pub fn is_extensible(&self) -> bool {
         self.extended_after_index.is_some()
     }
+
+    pub fn comments(&self) -> &Comments {
+        &self.comments
+    }
+
+    pub fn with_comments(mut self, comments: Comments) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    pub fn sources(&self) -> &Sources {
+        &self.sources
+    }
+
+    pub fn with_sources(mut self, sources: Sources) -> Self {
+        self.sources = sources.into();
+        self
+    }
 }
 
 impl<T> TagProperty for Enumeration<T> {
@@ -547,14 +849,54 @@ impl<T> TagProperty for Enumeration<T> {
 
 impl PlainEnum {
     pub fn from_names(names: impl Iterator<Item = impl ToString>) -> Self {
-        Self::from(names.map(|n| n.to_string()).collect::<Vec<_>>())
+        Self::from(names.map(PlainVariant::from_name).collect::<Vec<_>>())
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlainVariant {
+    name: String,
+    asn_name: IgnoredInComparison<Option<String>>,
+}
+
+impl PlainVariant {
+    pub fn from_name<T: ToString>(name: T) -> Self {
+        Self {
+            name: name.to_string(),
+            asn_name: IgnoredInComparison::default(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name of the variant in the ASN.1 module, which differs from the Rust name if it was
+    /// changed to be a valid Rust identifier
+    pub fn asn_name(&self) -> &str {
+        self.asn_name.as_deref().unwrap_or_else(|| self.name())
+    }
+
+    /// Remembers the name in the ASN.1 module, which is not compared
+    pub fn with_asn_name<T: ToString>(mut self, asn_name: T) -> Self {
+        self.asn_name = Some(asn_name.to_string()).into();
+        self
+    }
+}
+
+impl From<&str> for PlainVariant {
+    fn from(name: &str) -> Self {
+        Self::from_name(name)
     }
 }
 
 #[derive(Debug, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataVariant {
     name_type: (String, RustType),
     tag: Option<Tag>,
+    asn_name: IgnoredInComparison<Option<String>>,
 }
 
 impl DataVariant {
@@ -562,6 +904,7 @@ impl DataVariant {
         Self {
             name_type: (name.to_string(), r#type),
             tag: None,
+            asn_name: IgnoredInComparison::default(),
         }
     }
 
@@ -576,6 +919,22 @@ impl DataVariant {
     pub fn r#type(&self) -> &RustType {
         &self.name_type.1
     }
+
+    pub fn r#type_mut(&mut self) -> &mut RustType {
+        &mut self.name_type.1
+    }
+
+    /// The name of the variant in the ASN.1 module, which differs from the Rust name if it was
+    /// changed to be a valid Rust identifier
+    pub fn asn_name(&self) -> &str {
+        self.asn_name.as_deref().unwrap_or_else(|| self.name())
+    }
+
+    /// Remembers the name in the ASN.1 module, which is not compared
+    pub fn with_asn_name<T: ToString>(mut self, asn_name: T) -> Self {
+        self.asn_name = Some(asn_name.to_string()).into();
+        self
+    }
 }
 
 impl TagProperty for DataVariant {
@@ -592,6 +951,25 @@ impl TagProperty for DataVariant {
     }
 }
 
+fn member_comments<'a>(comments: impl Iterator<Item = &'a Option<String>>) -> Comments {
+    comments
+        .enumerate()
+        .fold(Comments::default(), |comments, (index, comment)| {
+            comments.with_member(index, comment.clone())
+        })
+}
+
+fn member_sources<'a>(
+    sources: impl Iterator<Item = &'a IgnoredInComparison<Source>>,
+) -> IgnoredInComparison<Sources> {
+    sources
+        .enumerate()
+        .fold(Sources::default(), |sources, (index, source)| {
+            sources.with_member(index, Source::clone(source))
+        })
+        .into()
+}
+
 impl Model<Rust> {
     pub fn convert_asn_to_rust(
         asn_model: &Model<Asn>,
@@ -602,26 +980,53 @@ impl Model<Rust> {
         let mut ctxt = Context {
             resolver: TagResolver::new(asn_model, scope),
             target: &mut definitions,
+            value_references: Vec::default(),
             make_names_nice,
+            qualified_imports: Vec::default(),
         };
+        ctxt.qualified_imports = Self::qualified_imports(asn_model, &ctxt);
         let mut model = Model {
             name: ctxt.module_name(&asn_model.name),
+            source: asn_model.source.clone(),
             oid: asn_model.oid.clone(),
             imports: asn_model
                 .imports
                 .iter()
                 .map(|i| Import {
-                    what: i.what.iter().map(|w| ctxt.struct_or_enum_name(w)).collect(),
+                    what: i.what.iter().map(|w| ctxt.type_reference_name(w)).collect(),
                     from: ctxt.module_name(&i.from),
                     from_oid: i.from_oid.clone(),
                 })
                 .collect(),
+            exports: match &asn_model.exports {
+                Exports::All => Exports::All,
+                Exports::Symbols(symbols) => Exports::Symbols(
+                    symbols
+                        .iter()
+                        .map(|symbol| {
+                            if asn_model.value_references.iter().any(|v| v.name.eq(symbol)) {
+                                ctxt.constant_name(symbol)
+                            } else {
+                                ctxt.struct_or_enum_name(symbol)
+                            }
+                        })
+                        .collect(),
+                ),
+            },
+            tag_default: asn_model.tag_default,
+            extensibility_implied: asn_model.extensibility_implied,
             definitions: Vec::default(),
             value_references: Vec::with_capacity(asn_model.value_references.len()),
         };
         for Definition(name, asn) in &asn_model.definitions {
             let rust_name = ctxt.struct_or_enum_name(name);
             Self::definition_to_rust(&rust_name, &asn.r#type, asn.tag, &mut ctxt);
+            ctxt.set_definition_origin(
+                &rust_name,
+                name,
+                asn.comment.clone(),
+                Source::clone(&asn.source),
+            );
         }
         for vref in &asn_model.value_references {
             if let Some(rust_type) = Self::map_asn_type_to_rust_type_flat(&vref.role.r#type) {
@@ -635,10 +1040,108 @@ impl Model<Rust> {
                 println!("Ignoring ValueReference {}", vref.name);
             }
         }
+        model.value_references.append(&mut ctxt.value_references);
         model.definitions = definitions;
+        Self::box_recursive_references(&mut model.definitions);
         model
     }
 
+    /// The imported types whose Rust names collide with the Rust name of a definition or a type
+    /// imported from another module (such as `Foo-Bar` and `FooBar`), with their names qualified
+    /// by the Rust name of the module they are imported from (`module::FooBar`)
+    fn qualified_imports(asn_model: &Model<Asn>, ctxt: &Context) -> Vec<(String, String)> {
+        let mut qualified = Vec::new();
+        for import in &asn_model.imports {
+            for what in &import.what {
+                let name = ctxt.struct_or_enum_name(what);
+                let collides = asn_model
+                    .definitions
+                    .iter()
+                    .any(|Definition(other, _)| ctxt.struct_or_enum_name(other) == name)
+                    || asn_model
+                        .imports
+                        .iter()
+                        .filter(|other| other.from != import.from)
+                        .flat_map(|other| other.what.iter())
+                        .any(|other| ctxt.struct_or_enum_name(other) == name);
+                if collides {
+                    qualified.push((
+                        what.clone(),
+                        format!("{}::{}", ctxt.module_name(&import.from), name),
+                    ));
+                }
+            }
+        }
+        qualified
+    }
+
+    /// Recursive definitions (a definition referring to itself, directly or through other
+    /// definitions, without the indirection of a `Vec`) would result in infinitely sized types.
+    /// This searches the reference graph for cycles and breaks each one by boxing the
+    /// reference that closes it. Cycles spanning multiple modules are not detected.
+    fn box_recursive_references(definitions: &mut [Definition<Rust>]) {
+        #[derive(Copy, Clone, PartialEq)]
+        enum State {
+            Unvisited,
+            Active,
+            Done,
+        }
+
+        fn visit(
+            index: usize,
+            references: &[Vec<Option<usize>>],
+            states: &mut [State],
+            to_box: &mut Vec<(usize, usize)>,
+        ) {
+            states[index] = State::Active;
+            for (member, target) in references[index].iter().enumerate() {
+                if let Some(target) = *target {
+                    match states[target] {
+                        State::Unvisited => visit(target, references, states, to_box),
+                        State::Active => to_box.push((index, member)),
+                        State::Done => {}
+                    }
+                }
+            }
+            states[index] = State::Done;
+        }
+
+        let references = definitions
+            .iter()
+            .map(|Definition(_, rust)| {
+                rust.member_types()
+                    .into_iter()
+                    .map(|r#type| {
+                        r#type.unboxed_complex_name().and_then(|name| {
+                            definitions
+                                .iter()
+                                .position(|Definition(other, _)| other == name)
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut states = vec![State::Unvisited; definitions.len()];
+        let mut to_box = Vec::new();
+        for index in 0..definitions.len() {
+            if states[index] == State::Unvisited {
+                visit(index, &references, &mut states, &mut to_box);
+            }
+        }
+
+        for (index, member) in to_box {
+            if let Some(r#type) = definitions[index]
+                .1
+                .member_types_mut()
+                .into_iter()
+                .nth(member)
+            {
+                r#type.box_complex();
+            }
+        }
+    }
+
     fn map_asn_type_to_rust_type_flat(r#type: &Type) -> Option<RustType> {
         Some(match &r#type {
             Type::Boolean => RustType::Bool,
@@ -646,7 +1149,9 @@ impl Model<Rust> {
                 Self::asn_extensible_integer_to_rust(int)
             }
             Type::Integer(int) => Self::asn_fixed_integer_to_rust_type(int),
-            Type::String(size, charset) => RustType::String(size.clone(), *charset),
+            Type::String(size, charset, alphabet) => {
+                RustType::String(size.clone(), *charset, alphabet.clone())
+            }
             Type::OctetString(size) => RustType::VecU8(size.clone()),
             Type::BitString(bs) => RustType::BitVec(bs.size.clone()),
             Type::Null => RustType::Null,
@@ -658,6 +1163,12 @@ impl Model<Rust> {
                 default.clone(),
             ),
             Type::TypeReference(name, tag) => RustType::Complex(name.clone(), *tag),
+            Type::ConstrainedTypeReference(_, applied) => match *applied {},
+            Type::ObjectIdentifier => RustType::Vec(
+                Box::new(RustType::U64(Range::none())),
+                Size::Any,
+                EncodingOrdering::Keep,
+            ),
             Type::Sequence(_)
             | Type::SequenceOf(_, _)
             | Type::Set(_)
@@ -694,6 +1205,10 @@ impl Model<Rust> {
                     Rust::tuple_struct_from_type(rust_type).with_tag_opt(*tag),
                 ));
             }
+            AsnType::ObjectIdentifier => {
+                unreachable!("OBJECT IDENTIFIER definitions are rejected while resolving")
+            }
+            AsnType::ConstrainedTypeReference(_, applied) => match *applied {},
 
             me @ AsnType::Integer(_) => {
                 let rust_type = Self::definition_type_to_rust_type(name, asn, tag, ctxt);
@@ -704,6 +1219,9 @@ impl Model<Rust> {
                         r#type: rust_type,
                         tag,
                         constants,
+                        comments: Comments::default(),
+                        sources: IgnoredInComparison::default(),
+                        asn_name: IgnoredInComparison::default(),
                     },
                 ));
             }
@@ -732,7 +1250,10 @@ impl Model<Rust> {
             AsnType::Sequence(ComponentTypeList {
                 fields,
                 extension_after,
+                ..
             }) => {
+                let comments = member_comments(fields.iter().map(|f| &f.role.comment));
+                let sources = member_sources(fields.iter().map(|f| &f.role.source));
                 let fields = Self::asn_fields_to_rust_fields(name, fields, *extension_after, ctxt);
                 ctxt.add_definition(Definition(
                     name.into(),
@@ -741,6 +1262,9 @@ impl Model<Rust> {
                         fields,
                         tag,
                         extension_after: *extension_after,
+                        comments,
+                        sources,
+                        asn_name: IgnoredInComparison::default(),
                     },
                 ));
             }
@@ -748,7 +1272,10 @@ impl Model<Rust> {
             AsnType::Set(ComponentTypeList {
                 fields,
                 extension_after,
+                ..
             }) => {
+                let comments = member_comments(fields.iter().map(|f| &f.role.comment));
+                let sources = member_sources(fields.iter().map(|f| &f.role.source));
                 let fields = Self::asn_fields_to_rust_fields(name, fields, *extension_after, ctxt);
                 ctxt.add_definition(Definition(
                     name.into(),
@@ -757,6 +1284,9 @@ impl Model<Rust> {
                         fields,
                         tag,
                         extension_after: *extension_after,
+                        comments,
+                        sources,
+                        asn_name: IgnoredInComparison::default(),
                     },
                 ));
             }
@@ -787,12 +1317,16 @@ impl Model<Rust> {
                     variants: Vec::with_capacity(choice.len()),
                     tag,
                     extended_after_index: choice.extension_after_index(),
+                    comments: member_comments(choice.variants().map(|v| &v.comment)),
+                    sources: member_sources(choice.variants().map(|v| &v.source)),
+                    asn_name: IgnoredInComparison::default(),
                 };
 
                 for ChoiceVariant {
                     name: variant_name,
                     r#type,
                     tag,
+                    ..
                 } in choice.variants()
                 {
                     let rust_name = format!("{}{}", name, ctxt.struct_or_enum_name(variant_name));
@@ -800,7 +1334,9 @@ impl Model<Rust> {
                         Self::definition_type_to_rust_type(&rust_name, r#type, *tag, ctxt);
                     let rust_field_name = ctxt.variant_name(variant_name);
                     enumeration.variants.push(
-                        DataVariant::from_name_type(rust_field_name, rust_role).with_tag_opt(*tag),
+                        DataVariant::from_name_type(rust_field_name, rust_role)
+                            .with_asn_name(variant_name)
+                            .with_tag_opt(*tag),
                     );
                 }
 
@@ -812,10 +1348,16 @@ impl Model<Rust> {
                     variants: Vec::with_capacity(enumerated.len()),
                     tag,
                     extended_after_index: enumerated.extension_after_index(),
+                    comments: member_comments(enumerated.variants().map(|v| &v.comment)),
+                    sources: member_sources(enumerated.variants().map(|v| &v.source)),
+                    asn_name: IgnoredInComparison::default(),
                 };
 
                 for variant in enumerated.variants() {
-                    rust_enum.variants.push(ctxt.variant_name(variant.name()));
+                    rust_enum.variants.push(
+                        PlainVariant::from_name(ctxt.variant_name(variant.name()))
+                            .with_asn_name(variant.name()),
+                    );
                 }
 
                 ctxt.add_definition(Definition(name.into(), Rust::Enum(rust_enum)));
@@ -837,7 +1379,20 @@ impl Model<Rust> {
             let rust_role =
                 Self::definition_type_to_rust_type(&rust_name, &field.role.r#type, tag, ctxt);
             let rust_role = if let Some(def) = &field.role.default {
-                RustType::Default(Box::new(rust_role.no_option()), def.clone())
+                let def = if let LiteralValue::Sequence(_) | LiteralValue::Choice(..) = def {
+                    // structured values cannot be written inline, but need a constant
+                    let const_name =
+                        rust_constant_name(&format!("{}-{}-default", name, field.name));
+                    ctxt.value_references.push(ValueReference {
+                        name: const_name.clone(),
+                        role: rust_role.clone().no_option(),
+                        value: def.clone(),
+                    });
+                    LiteralValue::Reference(const_name)
+                } else {
+                    def.clone()
+                };
+                RustType::Default(Box::new(rust_role.no_option()), def)
             } else if extension_after.map(|e| index > e).unwrap_or(false)
                 && !rust_role.is_optional()
             {
@@ -849,6 +1404,7 @@ impl Model<Rust> {
             let constants = ctxt.to_rust_constants(&field.role.r#type);
             rust_fields.push(
                 RustField::from_name_type(rust_field_name, rust_role)
+                    .with_asn_name(&field.name)
                     .with_constants(constants)
                     .with_tag_opt(tag),
             );
@@ -871,7 +1427,9 @@ impl Model<Rust> {
             }
             AsnType::Integer(int) => Self::asn_fixed_integer_to_rust_type(int),
 
-            AsnType::String(size, charset) => RustType::String(size.clone(), *charset),
+            AsnType::String(size, charset, alphabet) => {
+                RustType::String(size.clone(), *charset, alphabet.clone())
+            }
             AsnType::OctetString(size) => RustType::VecU8(size.clone()),
             AsnType::BitString(bitstring) => RustType::BitVec(bitstring.size.clone()),
             Type::Optional(inner) => {
@@ -920,9 +1478,13 @@ impl Model<Rust> {
                 RustType::Complex(name, tag.or_else(|| ctxt.resolver().resolve_type_tag(ty)))
             }
             AsnType::TypeReference(name, tag) => RustType::Complex(
-                ctxt.struct_or_enum_name(name),
+                ctxt.type_reference_name(name),
                 (*tag).or_else(|| ctxt.resolver().resolve_tag(name)),
             ),
+            AsnType::ObjectIdentifier => {
+                unreachable!("OBJECT IDENTIFIER definitions are rejected while resolving")
+            }
+            AsnType::ConstrainedTypeReference(_, applied) => match *applied {},
         }
     }
 
@@ -983,7 +1545,11 @@ impl Model<Rust> {
 struct Context<'a> {
     resolver: TagResolver<'a>,
     target: &'a mut Vec<Definition<Rust>>,
+    value_references: Vec<ValueReference<RustType>>,
     make_names_nice: bool,
+    /// The ASN.1 names of imported types that are referred to by a qualified Rust name, see
+    /// [`Model::qualified_imports`]
+    qualified_imports: Vec<(String, String)>,
 }
 
 impl Context<'_> {
@@ -1012,7 +1578,9 @@ impl Context<'_> {
             | Type::SetOf(..)
             | Type::Enumerated(_)
             | Type::Choice(_)
-            | Type::TypeReference(_, _) => Vec::default(),
+            | Type::TypeReference(_, _)
+            | Type::ObjectIdentifier => Vec::default(),
+            Type::ConstrainedTypeReference(_, applied) => match *applied {},
         }
     }
 
@@ -1024,6 +1592,15 @@ impl Context<'_> {
         }
     }
 
+    /// The Rust name to refer to the (possibly imported) type with the given ASN.1 name
+    pub fn type_reference_name(&self, name: &str) -> String {
+        self.qualified_imports
+            .iter()
+            .find(|(asn_name, _)| asn_name == name)
+            .map(|(_, qualified)| qualified.clone())
+            .unwrap_or_else(|| self.struct_or_enum_name(name))
+    }
+
     pub fn constant_name(&self, name: &str) -> String {
         if self.make_names_nice {
             rust_constant_name(name)
@@ -1060,6 +1637,22 @@ impl Context<'_> {
         self.target.push(def)
     }
 
+    /// Sets the name, comment and source of the ASN.1 definition the Rust definition of the given
+    /// name was converted from
+    pub fn set_definition_origin(
+        &mut self,
+        name: &str,
+        asn_name: &str,
+        comment: Option<String>,
+        source: Source,
+    ) {
+        if let Some(Definition(_, rust)) = self.target.iter_mut().rev().find(|d| d.0.eq(name)) {
+            *rust.asn_name_mut() = Some(asn_name.to_string());
+            rust.comments_mut().set_definition(comment);
+            rust.sources_mut().set_definition(source);
+        }
+    }
+
     pub fn resolver(&self) -> &TagResolver<'_> {
         &self.resolver
     }
@@ -1147,26 +1740,36 @@ pub fn rust_constant_name(name: &str) -> String {
     rust_module_name(name, true).to_uppercase()
 }
 
+/// The name of the referenced type without the module that an imported type with a colliding
+/// name is qualified with (`module::Foo` becomes `Foo`)
+pub fn unqualified_type_name(name: &str) -> &str {
+    name.rsplit("::").next().unwrap_or(name)
+}
+
 impl LiteralValue {
     pub fn as_rust_const_literal_expect<F: FnOnce(&Self) -> bool>(
         &self,
         make_names_nice: bool,
         probe: F,
     ) -> impl std::fmt::Display + '_ {
-        if probe(self) {
-            self.as_rust_const_literal(make_names_nice)
-        } else {
-            panic!("Invalid string literal {:?}", self)
+        match self.as_rust_const_literal(make_names_nice) {
+            Some(literal) if probe(self) => literal,
+            _ => panic!("Invalid string literal {:?}", self),
         }
     }
 
-    pub fn as_rust_const_literal(&self, make_names_nice: bool) -> impl std::fmt::Display + '_ {
+    /// The rust literal of this value or [`None`] for a structured (SEQUENCE, SET or CHOICE)
+    /// value, which can only be expressed with the type information of the generator
+    pub fn as_rust_const_literal(
+        &self,
+        make_names_nice: bool,
+    ) -> Option<impl std::fmt::Display + '_> {
         struct Ref<'a>(&'a LiteralValue, bool);
         impl std::fmt::Display for Ref<'_> {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 match self.0 {
                     LiteralValue::Boolean(v) => write!(f, "{}", v),
-                    LiteralValue::String(v) => write!(f, "\"{}\"", v),
+                    LiteralValue::String(v) => write!(f, "{:?}", v),
                     LiteralValue::Integer(v) => write!(f, "{}", v),
                     LiteralValue::OctetString(v) => {
                         write!(f, "[")?;
@@ -1191,10 +1794,36 @@ impl LiteralValue {
                             }
                         )
                     }
+                    LiteralValue::ObjectIdentifier(oid) => {
+                        write!(f, "&[")?;
+                        for arc in oid.arcs().expect("Unresolved OBJECT IDENTIFIER") {
+                            write!(f, "{}, ", arc)?;
+                        }
+                        write!(f, "]")
+                    }
+                    LiteralValue::Reference(name) => write!(f, "{}", name),
+                    // not constructed, see below
+                    LiteralValue::Sequence(_) | LiteralValue::Choice(..) => Ok(()),
                 }
             }
         }
-        Ref(self, make_names_nice)
+        match self {
+            LiteralValue::Sequence(_) | LiteralValue::Choice(..) => None,
+            _ => Some(Ref(self, make_names_nice)),
+        }
+    }
+
+    /// Like [`LiteralValue::as_rust_const_literal`], but a structured value is written as
+    /// `compile_error!`, so that the generated code fails to compile with a description of the
+    /// value instead of the generator failing without any hint where the value came from
+    pub fn to_rust_const_literal_string(&self, make_names_nice: bool) -> String {
+        match self.as_rust_const_literal(make_names_nice) {
+            Some(literal) => literal.to_string(),
+            None => format!(
+                "compile_error!({:?})",
+                format!("The structured value {} is not supported here", self)
+            ),
+        }
     }
 }
 
@@ -1205,7 +1834,7 @@ mod tests {
     use crate::gen::RustCodeGenerator;
     use crate::model::tag::tests::test_property;
     use crate::model::tests::*;
-    use crate::model::{Choice, Enumerated, EnumeratedVariant, Field, Tag, Type};
+    use crate::model::{Choice, Enumerated, EnumeratedVariant, Field, Tag, TagDefault, Type};
     use crate::parser::Tokenizer;
 
     #[test]
@@ -1592,7 +2221,7 @@ mod tests {
                     vec![
                         DataVariant::from_name_type(
                             "BerndDasBrot",
-                            RustType::String(Size::Any, Charset::Utf8),
+                            RustType::String(Size::Any, Charset::Utf8, None),
                         ),
                         DataVariant::from_name_type("NochSoEinBrot", RustType::VecU8(Size::Any)),
                     ]
@@ -1638,7 +2267,7 @@ mod tests {
                         DataVariant::from_name_type(
                             "NormalList",
                             RustType::Vec(
-                                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                                Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                                 Size::Any,
                                 EncodingOrdering::Keep
                             ),
@@ -1680,7 +2309,7 @@ mod tests {
             Definition(
                 "TupleTest".into(),
                 Rust::tuple_struct_from_type(RustType::Vec(
-                    Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                    Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                     Size::Any,
                     EncodingOrdering::Keep
                 )),
@@ -1713,7 +2342,7 @@ mod tests {
                 "NestedTupleTest".into(),
                 Rust::tuple_struct_from_type(RustType::Vec(
                     Box::new(RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                         Size::Any,
                         EncodingOrdering::Keep
                     )),
@@ -1749,7 +2378,7 @@ mod tests {
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
                     RustType::Option(Box::new(RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                         Size::Any,
                         EncodingOrdering::Keep
                     ))),
@@ -1782,7 +2411,7 @@ mod tests {
                 Rust::struct_from_fields(vec![RustField::from_name_type(
                     "strings",
                     RustType::Vec(
-                        Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                        Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                         Size::Any,
                         EncodingOrdering::Keep
                     ),
@@ -1822,7 +2451,7 @@ mod tests {
                     "strings",
                     RustType::Vec(
                         Box::new(RustType::Vec(
-                            Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                            Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                             Size::Any,
                             EncodingOrdering::Keep
                         )),
@@ -1880,6 +2509,8 @@ mod tests {
                         name: "ghi".to_string(),
                         tag: Some(Tag::Universal(4)),
                         r#type: Type::Boolean,
+                        comment: None,
+                        source: IgnoredInComparison::default(),
                     },
                 ])
                 .with_extension_after(2),
@@ -1917,6 +2548,9 @@ mod tests {
             fields: Vec::default(),
             tag: None,
             extension_after: None,
+            comments: Comments::default(),
+            sources: IgnoredInComparison::default(),
+            asn_name: IgnoredInComparison::default(),
         });
     }
 
@@ -1932,7 +2566,7 @@ mod tests {
         test_property(Rust::DataEnum(DataEnum::from(vec![
             DataVariant::from_name_type(
                 "SomeName".to_string(),
-                RustType::String(Size::Any, Charset::Visible),
+                RustType::String(Size::Any, Charset::Visible, None),
             ),
         ])));
     }
@@ -1943,6 +2577,9 @@ mod tests {
             r#type: RustType::VecU8(Size::Any),
             tag: None,
             constants: Vec::default(),
+            comments: Comments::default(),
+            sources: IgnoredInComparison::default(),
+            asn_name: IgnoredInComparison::default(),
         });
     }
 
@@ -1971,8 +2608,12 @@ mod tests {
     pub fn test_value_reference_to_rust() {
         let asn = Model::<Asn<Resolved>> {
             name: "SomeGreatName".to_string(),
+            source: IgnoredInComparison::default(),
             oid: None,
             imports: Vec::default(),
+            exports: Exports::default(),
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             definitions: Vec::default(),
             value_references: vec![
                 ValueReference {
@@ -2009,12 +2650,167 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_recursive_references_are_boxed() {
+        let model = Model::try_from(Tokenizer::default().parse(
+            r"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Node ::= SEQUENCE {
+                    children SEQUENCE OF Node
+                }
+                List ::= SEQUENCE {
+                    next List OPTIONAL
+                }
+                Expression ::= CHOICE {
+                    literal INTEGER,
+                    sum Sum
+                }
+                Sum ::= SEQUENCE {
+                    left Expression,
+                    right Expression
+                }
+                END",
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve")
+        .to_rust();
+
+        let field_types = |index: usize| match &model.definitions[index].1 {
+            Rust::Struct { fields, .. } => fields
+                .iter()
+                .map(|f| f.r#type().to_string())
+                .collect::<Vec<_>>(),
+            Rust::DataEnum(enumeration) => enumeration
+                .variants()
+                .map(|v| v.r#type().to_string())
+                .collect::<Vec<_>>(),
+            r => panic!("Unexpected definition {:?}", r),
+        };
+
+        assert_eq!(vec!["Vec<Node>"], field_types(0));
+        assert_eq!(vec!["Option<Box<List>>"], field_types(1));
+        assert_eq!(vec!["u64", "Sum"], field_types(2));
+        assert_eq!(vec!["Box<Expression>", "Box<Expression>"], field_types(3));
+    }
+
+    #[test]
+    pub fn test_structured_value_reference_to_rust() {
+        let asn = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Shape ::= CHOICE {
+                    alt INTEGER (0..255),
+                    name UTF8String
+                }
+                altitude Shape ::= alt : 5
+                named Shape ::= name : "circle"
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            pub const ALTITUDE: Shape = Shape::Alt(5);
+            pub static NAMED: std::sync::LazyLock<Shape> = std::sync::LazyLock::new(|| Shape::Name(String::from("circle")));
+
+        "#,
+            &RustCodeGenerator::from(asn.to_rust())
+                .to_string_without_generators()
+                .into_iter()
+                .map(|(_f, c)| c)
+                .next()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    pub fn test_structured_value_reference_with_bit_string() {
+        let asn = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Flagged ::= SEQUENCE {
+                    flags BIT STRING (SIZE(8)),
+                    lat INTEGER (0..10)
+                }
+                flagged Flagged ::= { flags 'A0'H, lat 1 }
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            pub static FLAGGED: std::sync::LazyLock<Flagged> = std::sync::LazyLock::new(|| Flagged { flags: BitVec::from_all_bytes(vec![0xa0, ]), lat: 1 });
+
+        "#,
+            &RustCodeGenerator::from(asn.to_rust())
+                .to_string_without_generators()
+                .into_iter()
+                .map(|(_f, c)| c)
+                .next()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    pub fn test_inexpressible_structured_value_reference_is_an_error() {
+        let asn = Model::try_from(Tokenizer::default().parse(
+            r#"SomeName DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Flagged ::= SEQUENCE {
+                    flags BIT STRING (SIZE(8)),
+                    lat INTEGER (0..10)
+                }
+                flagged Flagged ::= { flags 'A0'H, lat 1 }
+                END"#,
+        ))
+        .expect("Failed to load model")
+        .try_resolve()
+        .expect("Failed to resolve");
+        let mut rust = asn.to_rust();
+        // the resolver rejects a missing mandatory component, but the Rust model is public
+        rust.value_references[0].value =
+            LiteralValue::Sequence(vec![("lat".to_string(), LiteralValue::Integer(1))]);
+        let generator = RustCodeGenerator::from(rust);
+
+        assert_eq!(
+            Err(crate::gen::rust::Error::InexpressibleValue(
+                "FLAGGED".to_string(),
+                "{ lat 1 }".to_string(),
+                "Flagged".to_string()
+            )),
+            generator.check_values()
+        );
+        assert_starts_with_lines(
+            r#"
+            use asn1rs::prelude::*;
+
+            compile_error!("The value { lat 1 } of FLAGGED cannot be expressed as Flagged");
+
+        "#,
+            &generator
+                .to_string_without_generators()
+                .into_iter()
+                .map(|(_f, c)| c)
+                .next()
+                .unwrap(),
+        );
+    }
+
     #[test]
     fn test_to_rust_coherent_complex_reference_renaming() {
         let asn = Model::<Asn<Resolved>> {
             name: "CoherentComplexRenaming".to_string(),
+            source: IgnoredInComparison::default(),
             oid: None,
             imports: vec![],
+            exports: Exports::All,
+            tag_default: TagDefault::Automatic,
+            extensibility_implied: false,
             definitions: vec![
                 Definition("Some-Name-WithID".to_string(), Type::Boolean.untagged()),
                 Definition(
@@ -2032,6 +2828,7 @@ mod tests {
                             },
                         ],
                         extension_after: None,
+                        components_of: Vec::default(),
                     })
                     .untagged(),
                 ),
@@ -2045,7 +2842,10 @@ mod tests {
                     Rust::TupleStruct {
                         r#type: RustType::Bool,
                         tag: None,
-                        constants: vec![]
+                        constants: vec![],
+                        comments: Comments::default(),
+                        sources: IgnoredInComparison::default(),
+                        asn_name: IgnoredInComparison::default(),
                     }
                 ),
                 Definition(
@@ -2066,11 +2866,71 @@ mod tests {
                             ),
                         ],
                         tag: None,
-                        extension_after: None
+                        extension_after: None,
+                        comments: Comments::default(),
+                        sources: IgnoredInComparison::default(),
+                        asn_name: IgnoredInComparison::default(),
                     }
                 ),
             ],
             asn.to_rust().definitions
         );
     }
+
+    #[test]
+    fn test_to_rust_keeps_asn_names() {
+        let model_rust = Model::try_from(Tokenizer::default().parse(
+            r"KeepNames DEFINITIONS AUTOMATIC TAGS ::=
+            BEGIN
+            Some-Container ::= SEQUENCE {
+                the-decision ENUMERATED { ABORT, go-on },
+                the-choice CHOICE { some-value INTEGER }
+            }
+            END",
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        let container = model_rust
+            .definitions
+            .iter()
+            .find(|d| d.0.eq("SomeContainer"))
+            .unwrap();
+        assert_eq!("Some-Container", container.asn_name());
+
+        let fields = match &container.1 {
+            Rust::Struct { fields, .. } => fields,
+            r => panic!("Unexpected {:?}", r),
+        };
+        assert_eq!("the_decision", fields[0].name());
+        assert_eq!("the-decision", fields[0].asn_name());
+        assert_eq!("the-choice", fields[1].asn_name());
+
+        let decision = model_rust
+            .definitions
+            .iter()
+            .find_map(|d| match &d.1 {
+                Rust::Enum(plain) => Some(plain),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!("Abort", decision.variants().next().unwrap().name());
+        assert_eq!("ABORT", decision.variants().next().unwrap().asn_name());
+        assert_eq!("go-on", decision.variants().nth(1).unwrap().asn_name());
+
+        let choice = model_rust
+            .definitions
+            .iter()
+            .find_map(|d| match &d.1 {
+                Rust::DataEnum(data) => Some(data),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!("some-value", choice.variants().next().unwrap().asn_name());
+
+        // an unconverted model knows no other name
+        assert_eq!("Abort", PlainVariant::from_name("Abort").asn_name());
+    }
 }
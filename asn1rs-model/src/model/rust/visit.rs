@@ -0,0 +1,267 @@
+//! Traversal of the Rust model, the counterpart of [`crate::model::visit`] for transformations
+//! that are easier to express on the types of the generated code, such as replacing the
+//! representation of a type. As there, each method by default descends into the children of the
+//! node by calling the free function of the same name.
+
+use crate::model::rust::{DataVariant, Field};
+use crate::model::{Definition, Import, Model, Rust, RustType, ValueReference};
+
+pub trait Visit {
+    fn visit_model(&mut self, model: &Model<Rust>) {
+        visit_model(self, model)
+    }
+
+    fn visit_import(&mut self, _import: &Import) {}
+
+    fn visit_definition(&mut self, definition: &Definition<Rust>) {
+        visit_definition(self, definition)
+    }
+
+    fn visit_value_reference(&mut self, value_reference: &ValueReference<RustType>) {
+        visit_value_reference(self, value_reference)
+    }
+
+    fn visit_rust(&mut self, rust: &Rust) {
+        visit_rust(self, rust)
+    }
+
+    fn visit_field(&mut self, field: &Field) {
+        visit_field(self, field)
+    }
+
+    fn visit_data_variant(&mut self, variant: &DataVariant) {
+        visit_data_variant(self, variant)
+    }
+
+    fn visit_rust_type(&mut self, r#type: &RustType) {
+        visit_rust_type(self, r#type)
+    }
+}
+
+pub fn visit_model<V: Visit + ?Sized>(visitor: &mut V, model: &Model<Rust>) {
+    for import in &model.imports {
+        visitor.visit_import(import);
+    }
+    for definition in &model.definitions {
+        visitor.visit_definition(definition);
+    }
+    for value_reference in &model.value_references {
+        visitor.visit_value_reference(value_reference);
+    }
+}
+
+pub fn visit_definition<V: Visit + ?Sized>(visitor: &mut V, definition: &Definition<Rust>) {
+    visitor.visit_rust(&definition.1)
+}
+
+pub fn visit_value_reference<V: Visit + ?Sized>(
+    visitor: &mut V,
+    value_reference: &ValueReference<RustType>,
+) {
+    visitor.visit_rust_type(&value_reference.role)
+}
+
+pub fn visit_rust<V: Visit + ?Sized>(visitor: &mut V, rust: &Rust) {
+    match rust {
+        Rust::Struct { fields, .. } => {
+            for field in fields {
+                visitor.visit_field(field);
+            }
+        }
+        Rust::Enum(_) => {}
+        Rust::DataEnum(enumeration) => {
+            for variant in enumeration.variants() {
+                visitor.visit_data_variant(variant);
+            }
+        }
+        Rust::TupleStruct { r#type, .. } => visitor.visit_rust_type(r#type),
+    }
+}
+
+pub fn visit_field<V: Visit + ?Sized>(visitor: &mut V, field: &Field) {
+    visitor.visit_rust_type(field.r#type())
+}
+
+pub fn visit_data_variant<V: Visit + ?Sized>(visitor: &mut V, variant: &DataVariant) {
+    visitor.visit_rust_type(variant.r#type())
+}
+
+pub fn visit_rust_type<V: Visit + ?Sized>(visitor: &mut V, r#type: &RustType) {
+    if let RustType::Vec(inner, ..)
+    | RustType::Array(inner, _)
+    | RustType::Option(inner)
+    | RustType::Default(inner, ..)
+    | RustType::Box(inner)
+    | RustType::Override(inner, _) = r#type
+    {
+        visitor.visit_rust_type(inner)
+    }
+}
+
+pub trait VisitMut {
+    fn visit_model_mut(&mut self, model: &mut Model<Rust>) {
+        visit_model_mut(self, model)
+    }
+
+    fn visit_import_mut(&mut self, _import: &mut Import) {}
+
+    fn visit_definition_mut(&mut self, definition: &mut Definition<Rust>) {
+        visit_definition_mut(self, definition)
+    }
+
+    fn visit_value_reference_mut(&mut self, value_reference: &mut ValueReference<RustType>) {
+        visit_value_reference_mut(self, value_reference)
+    }
+
+    fn visit_rust_mut(&mut self, rust: &mut Rust) {
+        visit_rust_mut(self, rust)
+    }
+
+    fn visit_field_mut(&mut self, field: &mut Field) {
+        visit_field_mut(self, field)
+    }
+
+    fn visit_data_variant_mut(&mut self, variant: &mut DataVariant) {
+        visit_data_variant_mut(self, variant)
+    }
+
+    fn visit_rust_type_mut(&mut self, r#type: &mut RustType) {
+        visit_rust_type_mut(self, r#type)
+    }
+}
+
+pub fn visit_model_mut<V: VisitMut + ?Sized>(visitor: &mut V, model: &mut Model<Rust>) {
+    for import in &mut model.imports {
+        visitor.visit_import_mut(import);
+    }
+    for definition in &mut model.definitions {
+        visitor.visit_definition_mut(definition);
+    }
+    for value_reference in &mut model.value_references {
+        visitor.visit_value_reference_mut(value_reference);
+    }
+}
+
+pub fn visit_definition_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    definition: &mut Definition<Rust>,
+) {
+    visitor.visit_rust_mut(&mut definition.1)
+}
+
+pub fn visit_value_reference_mut<V: VisitMut + ?Sized>(
+    visitor: &mut V,
+    value_reference: &mut ValueReference<RustType>,
+) {
+    visitor.visit_rust_type_mut(&mut value_reference.role)
+}
+
+pub fn visit_rust_mut<V: VisitMut + ?Sized>(visitor: &mut V, rust: &mut Rust) {
+    match rust {
+        Rust::Struct { fields, .. } => {
+            for field in fields {
+                visitor.visit_field_mut(field);
+            }
+        }
+        Rust::Enum(_) => {}
+        Rust::DataEnum(enumeration) => {
+            for variant in enumeration.variants_mut() {
+                visitor.visit_data_variant_mut(variant);
+            }
+        }
+        Rust::TupleStruct { r#type, .. } => visitor.visit_rust_type_mut(r#type),
+    }
+}
+
+pub fn visit_field_mut<V: VisitMut + ?Sized>(visitor: &mut V, field: &mut Field) {
+    visitor.visit_rust_type_mut(field.r#type_mut())
+}
+
+pub fn visit_data_variant_mut<V: VisitMut + ?Sized>(visitor: &mut V, variant: &mut DataVariant) {
+    visitor.visit_rust_type_mut(variant.r#type_mut())
+}
+
+pub fn visit_rust_type_mut<V: VisitMut + ?Sized>(visitor: &mut V, r#type: &mut RustType) {
+    if let RustType::Vec(inner, ..)
+    | RustType::Array(inner, _)
+    | RustType::Option(inner)
+    | RustType::Default(inner, ..)
+    | RustType::Box(inner)
+    | RustType::Override(inner, _) = r#type
+    {
+        visitor.visit_rust_type_mut(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::rust::EncodingOrdering;
+    use crate::model::{Charset, Size};
+    use crate::parser::Tokenizer;
+
+    /// Replaces all `Vec<u8>` by `String`s
+    struct BytesToString;
+
+    impl VisitMut for BytesToString {
+        fn visit_rust_type_mut(&mut self, r#type: &mut RustType) {
+            if let RustType::VecU8(size) = r#type {
+                *r#type = RustType::String(size.clone(), Charset::Utf8, None);
+            }
+            visit_rust_type_mut(self, r#type)
+        }
+    }
+
+    #[derive(Default)]
+    struct CountTypes(usize);
+
+    impl Visit for CountTypes {
+        fn visit_rust_type(&mut self, r#type: &RustType) {
+            self.0 += 1;
+            visit_rust_type(self, r#type)
+        }
+    }
+
+    #[test]
+    pub fn test_visit_mut_replaces_nested_types() {
+        let mut model = Model::try_from(Tokenizer::default().parse(
+            r"Visit DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                Data ::= SEQUENCE {
+                    raw OCTET STRING OPTIONAL,
+                    chunks SEQUENCE OF OCTET STRING
+                }
+                Either ::= CHOICE { raw OCTET STRING, number INTEGER }
+            END",
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+
+        BytesToString.visit_model_mut(&mut model);
+
+        let mut count = CountTypes::default();
+        count.visit_model(&model);
+        assert_eq!(6, count.0);
+
+        let string = RustType::String(Size::Any, Charset::Utf8, None);
+        let fields = match &model.definitions[0].1 {
+            Rust::Struct { fields, .. } => fields,
+            r => panic!("Unexpected {:?}", r),
+        };
+        assert_eq!(
+            &RustType::Option(Box::new(string.clone())),
+            fields[0].r#type()
+        );
+        assert_eq!(
+            &RustType::Vec(Box::new(string.clone()), Size::Any, EncodingOrdering::Keep),
+            fields[1].r#type()
+        );
+        match &model.definitions[1].1 {
+            Rust::DataEnum(enumeration) => {
+                assert_eq!(&string, enumeration.variants().next().unwrap().r#type())
+            }
+            r => panic!("Unexpected {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::model::lor::Unresolved;
+use crate::model::{Asn, Error, Import, Model, MultiModuleResolver};
+use crate::parser::Tokenizer;
+use std::io::Error as IoError;
+use std::path::{Path, PathBuf};
+
+/// File extensions of ASN.1 modules that are considered when searching for imported modules
+const MODULE_FILE_EXTENSIONS: [&str; 2] = ["asn", "asn1"];
+
+type ModuleFile = (PathBuf, Model<Asn<Unresolved>>);
+
+/// A file that was skipped while searching for imported modules and why it cannot be parsed
+pub type SkippedFile = (PathBuf, Vec<Error>);
+
+#[derive(Debug)]
+pub enum SearchError {
+    Io(IoError),
+    /// No module in the searched directories satisfies the import
+    ModuleNotFound(Import),
+    /// More than one module in the searched directories satisfies the import
+    ConflictingModules(Import, Vec<PathBuf>),
+    /// Modules with the name of the import were found, but their `OBJECT IDENTIFIER`s differ
+    ModuleVersionMismatch(Import, Vec<PathBuf>),
+}
+
+impl From<IoError> for SearchError {
+    fn from(e: IoError) -> Self {
+        SearchError::Io(e)
+    }
+}
+
+impl MultiModuleResolver {
+    /// Searches the given directories for the modules that are (transitively) imported by the
+    /// pushed modules but have not been pushed yet, pushes them and returns their files. Modules
+    /// are identified by their `OBJECT IDENTIFIER` or, if the import or the module does not
+    /// specify one, by their name. The directories are only read once an import is missing, the
+    /// already `loaded` files are not read again and files that cannot be parsed are skipped, so
+    /// that only broken modules that are actually imported fail the search. The skipped files are
+    /// listed by [`MultiModuleResolver::skipped_files`].
+    pub fn push_imports_from<D: AsRef<Path>>(
+        &mut self,
+        directories: &[D],
+        loaded: &[PathBuf],
+    ) -> Result<Vec<PathBuf>, SearchError> {
+        let mut pushed = Vec::new();
+        let mut candidates = None;
+        loop {
+            let missing = self
+                .unsatisfied_imports()
+                .into_iter()
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if missing.is_empty() {
+                return Ok(pushed);
+            }
+
+            let candidates: &Vec<ModuleFile> = match &mut candidates {
+                Some(candidates) => candidates,
+                None => {
+                    candidates.insert(candidate_modules(directories, loaded, &mut self.skipped)?)
+                }
+            };
+
+            for import in missing {
+                if self.contains(&import) {
+                    // pushed in the meantime for a previous import
+                    continue;
+                }
+
+                let found = candidates
+                    .iter()
+                    .filter(|(_, model)| import.is_satisfied_by(model))
+                    .collect::<Vec<_>>();
+
+                match &found[..] {
+                    [(path, model)] => {
+                        self.push(model.clone());
+                        pushed.push(path.clone());
+                    }
+                    [] => {
+                        let same_name = candidates
+                            .iter()
+                            .filter(|(_, model)| model.name.eq(&import.from))
+                            .map(|(path, _)| path.clone())
+                            .collect::<Vec<_>>();
+                        return Err(if same_name.is_empty() {
+                            SearchError::ModuleNotFound(import)
+                        } else {
+                            SearchError::ModuleVersionMismatch(import, same_name)
+                        });
+                    }
+                    found => {
+                        return Err(SearchError::ConflictingModules(
+                            import,
+                            found.iter().map(|(path, _)| path.clone()).collect(),
+                        ))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parses the module files of the given directories, except the `loaded` ones, and adds the
+/// files that cannot be parsed to `skipped`
+fn candidate_modules<D: AsRef<Path>>(
+    directories: &[D],
+    loaded: &[PathBuf],
+    skipped: &mut Vec<SkippedFile>,
+) -> Result<Vec<ModuleFile>, IoError> {
+    let mut modules = Vec::new();
+    for directory in directories {
+        for file in module_files(directory)? {
+            if loaded.contains(&file) {
+                continue;
+            }
+            let input = std::fs::read_to_string(&file)?;
+            match Model::try_from_recovering(Tokenizer.parse_with_comments(&input)) {
+                Ok(mut model) => {
+                    model.set_source_file(&file.to_string_lossy());
+                    modules.push((file, model));
+                }
+                // a broken module only fails the search if it is actually imported, which is
+                // reported as ModuleNotFound
+                Err(errors) => {
+                    if !skipped.iter().any(|(skipped, _)| skipped.eq(&file)) {
+                        skipped.push((file, errors));
+                    }
+                }
+            }
+        }
+    }
+    Ok(modules)
+}
+
+/// The ASN.1 module files in the given directory, in alphabetical order
+pub fn module_files<D: AsRef<Path>>(directory: D) -> Result<Vec<PathBuf>, IoError> {
+    let mut files = std::fs::read_dir(directory)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    files.sort();
+    files.retain(|file| {
+        file.is_file()
+            && file
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(|e| {
+                    MODULE_FILE_EXTENSIONS
+                        .iter()
+                        .any(|m| m.eq_ignore_ascii_case(e))
+                })
+                .unwrap_or(false)
+    });
+    Ok(files)
+}
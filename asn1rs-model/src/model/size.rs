@@ -8,6 +8,7 @@ use std::fmt::{Debug, Display};
 use std::iter::Peekable;
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Size<T: Display + Debug + Clone = usize> {
     Any,
     Fix(T, bool),
@@ -0,0 +1,229 @@
+use crate::model::lor::{ResolveState, Unresolved};
+use crate::model::{Asn, Model, Type};
+use crate::parser::Location;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+
+/// The position of a definition, field or variant in the ASN.1 source, which is unknown for
+/// nodes that were not parsed (see [`Source::default`]).
+#[derive(Debug, Default, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Source {
+    file: Option<String>,
+    location: Option<Location>,
+}
+
+impl Source {
+    pub const fn new(location: Location) -> Self {
+        Self {
+            file: None,
+            location: Some(location),
+        }
+    }
+
+    pub fn with_file<S: Into<String>>(mut self, file: S) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// The name of the file the node was parsed from, if known
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub const fn location(&self) -> Option<Location> {
+        self.location
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.file, &self.location) {
+            (Some(file), Some(location)) => {
+                write!(f, "{}:{}:{}", file, location.line(), location.column())
+            }
+            (None, Some(location)) => write!(f, "{}:{}", location.line(), location.column()),
+            (Some(file), None) => write!(f, "{}", file),
+            (None, None) => write!(f, "<unknown>"),
+        }
+    }
+}
+
+/// The [`Source`]s of a definition and of its fields or variants (members), which are referred
+/// to by their index
+#[derive(Debug, Default, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sources {
+    definition: Source,
+    members: Vec<Source>,
+}
+
+impl Sources {
+    pub fn with_definition(mut self, source: Source) -> Self {
+        self.definition = source;
+        self
+    }
+
+    pub fn set_definition(&mut self, source: Source) {
+        self.definition = source;
+    }
+
+    pub fn with_member(mut self, index: usize, source: Source) -> Self {
+        if self.members.len() <= index {
+            self.members.resize(index + 1, Source::default());
+        }
+        self.members[index] = source;
+        self
+    }
+
+    pub fn definition(&self) -> &Source {
+        &self.definition
+    }
+
+    /// The source of the member at the given index, unknown if there is no such member
+    pub fn member(&self, index: usize) -> &Source {
+        const UNKNOWN: Source = Source {
+            file: None,
+            location: None,
+        };
+        self.members.get(index).unwrap_or(&UNKNOWN)
+    }
+}
+
+/// Wraps a value that is not part of the identity of the model node it is stored in, such as
+/// its [`Source`]. It is ignored when comparing or hashing the node, so that the same definitions
+/// parsed from different files or lines are still considered equal.
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct IgnoredInComparison<T>(T);
+
+impl<T> IgnoredInComparison<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for IgnoredInComparison<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for IgnoredInComparison<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for IgnoredInComparison<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Debug> Debug for IgnoredInComparison<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> PartialEq for IgnoredInComparison<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for IgnoredInComparison<T> {}
+
+impl<T> PartialOrd for IgnoredInComparison<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for IgnoredInComparison<T> {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<T> Hash for IgnoredInComparison<T> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl Model<Asn<Unresolved>> {
+    /// Sets the file name of all [`Source`]s of the module, its definitions, fields, variants and
+    /// value references
+    pub fn set_source_file(&mut self, file: &str) {
+        set_file(&mut self.source, file);
+        for definition in &mut self.definitions {
+            set_file_of_asn(&mut definition.1, file);
+        }
+        for value_reference in &mut self.value_references {
+            set_file_of_asn(&mut value_reference.role, file);
+        }
+    }
+}
+
+fn set_file(source: &mut Source, file: &str) {
+    source.file = Some(file.to_string());
+}
+
+fn set_file_of_asn<RS: ResolveState>(asn: &mut Asn<RS>, file: &str) {
+    set_file(&mut asn.source, file);
+    set_file_of_type(&mut asn.r#type, file);
+}
+
+fn set_file_of_type<RS: ResolveState>(r#type: &mut Type<RS>, file: &str) {
+    match r#type {
+        Type::Optional(inner)
+        | Type::Default(inner, _)
+        | Type::SequenceOf(inner, _)
+        | Type::SetOf(inner, _) => set_file_of_type(inner, file),
+        Type::Sequence(list) | Type::Set(list) => {
+            for field in &mut list.fields {
+                set_file_of_asn(&mut field.role, file);
+            }
+        }
+        Type::Choice(choice) => {
+            for variant in choice.variants_mut() {
+                set_file(&mut variant.source, file);
+                set_file_of_type(&mut variant.r#type, file);
+            }
+        }
+        Type::Enumerated(enumerated) => {
+            for variant in enumerated.variants_mut() {
+                set_file(&mut variant.source, file);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sources_are_ignored_when_comparing() {
+        let a = Source::new(Location::at(1, 2)).with_file("a.asn1");
+        let b = Source::new(Location::at(3, 4));
+        assert_ne!(a, b);
+        assert_eq!("a.asn1:1:2", a.to_string());
+        assert_eq!("3:4", b.to_string());
+        assert_eq!("<unknown>", Source::default().to_string());
+        assert_eq!(
+            IgnoredInComparison::from(a),
+            IgnoredInComparison::from(b.clone())
+        );
+        assert_eq!(
+            IgnoredInComparison::from(Source::default()),
+            IgnoredInComparison::from(b)
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::gen::RustCodeGenerator;
+use crate::model::rust::{unqualified_type_name, Field, PlainEnum};
 use crate::model::rust::{DataEnum, DataVariant, EncodingOrdering};
-use crate::model::rust::{Field, PlainEnum};
 use crate::model::Rust;
 use crate::model::RustType;
 use crate::model::{Charset, Model};
@@ -77,7 +77,7 @@ impl SqlType {
             SqlType::BigInt => RustType::I64(Range::inclusive(0, i64::MAX)),
             SqlType::Serial => RustType::I32(Range::inclusive(0, i32::MAX)),
             SqlType::Boolean => RustType::Bool,
-            SqlType::Text => RustType::String(Size::Any, Charset::Utf8),
+            SqlType::Text => RustType::String(Size::Any, Charset::Utf8, None),
             SqlType::Array(inner) => {
                 RustType::Vec(Box::new(inner.to_rust()), Size::Any, EncodingOrdering::Keep)
             }
@@ -136,6 +136,24 @@ pub enum Constraint {
     OneNotNull(Vec<String>),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommentTarget {
+    Table(String),
+    Type(String),
+    /// Table and column
+    Column(String, String),
+}
+
+impl CommentTarget {
+    pub fn table_or_type(&self) -> &str {
+        match self {
+            CommentTarget::Table(name)
+            | CommentTarget::Type(name)
+            | CommentTarget::Column(name, _) => name,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Sql {
     Table(Vec<Column>, Vec<Constraint>),
@@ -144,6 +162,7 @@ pub enum Sql {
     /// Table being affected to ->
     AbandonChildrenFunction(String, Vec<(String, String, String)>),
     SilentlyPreventAnyDelete(String),
+    Comment(CommentTarget, String),
 }
 
 impl Target for Sql {
@@ -155,8 +174,12 @@ impl Model<Sql> {
     pub fn convert_rust_to_sql(rust_model: &Model<Rust>) -> Model<Sql> {
         let mut model = Model {
             name: rust_model.name.clone(),
+            source: rust_model.source.clone(),
             oid: rust_model.oid.clone(),
-            imports: Default::default(), // ignored in SQL
+            imports: Default::default(),     // ignored in SQL
+            exports: Default::default(),     // ignored in SQL
+            tag_default: Default::default(), // ignored in SQL
+            extensibility_implied: false,    // ignored in SQL
             definitions: Vec::with_capacity(rust_model.definitions.len()),
             value_references: Vec::default(),
         };
@@ -212,6 +235,9 @@ impl Model<Sql> {
             | Sql::SilentlyPreventAnyDelete(name) => {
                 walk_from_name(name.as_str());
             }
+            Sql::Comment(target, _) => {
+                walk_from_name(target.table_or_type());
+            }
         }
     }
 
@@ -222,6 +248,9 @@ impl Model<Sql> {
                 tag: _,
                 extension_after: _,
                 ordering: _,
+                comments: _,
+                sources: _,
+                asn_name: _,
             } => Self::rust_struct_to_sql_table(name, fields, definitions),
             Rust::Enum(rust_enum) => Self::rust_enum_to_sql_enum(name, rust_enum, definitions),
             Rust::DataEnum(enumeration) => {
@@ -231,6 +260,52 @@ impl Model<Sql> {
                 Self::rust_tuple_struct_to_sql_table(name, rust, definitions)
             }
         }
+        Self::add_comments(name, rust, definitions);
+    }
+
+    fn add_comments(name: &str, rust: &Rust, definitions: &mut Vec<Definition<Sql>>) {
+        let comments = rust.comments();
+        if let Some(comment) = comments.definition() {
+            let target = if let Rust::Enum(_) = rust {
+                CommentTarget::Type(name.into())
+            } else {
+                CommentTarget::Table(name.into())
+            };
+            Self::add_comment(target, comment, definitions);
+        }
+        let members = match rust {
+            Rust::Struct { fields, .. } => fields
+                .iter()
+                .map(|field| (field.name(), field.r#type().is_vec()))
+                .collect::<Vec<_>>(),
+            Rust::DataEnum(enumeration) => enumeration
+                .variants()
+                .map(|variant| (variant.name(), false))
+                .collect(),
+            // there is no way to comment on the values of an enum type
+            Rust::Enum(_) | Rust::TupleStruct { .. } => Vec::default(),
+        };
+        for (index, (member, is_vec)) in members.into_iter().enumerate() {
+            if let Some(comment) = comments.member(index) {
+                let target = if is_vec {
+                    CommentTarget::Table(Self::struct_list_entry_table_name(name, member))
+                } else {
+                    CommentTarget::Column(name.into(), Self::sql_column_name(member))
+                };
+                Self::add_comment(target, comment, definitions);
+            }
+        }
+    }
+
+    fn add_comment(target: CommentTarget, comment: &str, definitions: &mut Vec<Definition<Sql>>) {
+        let name = match &target {
+            CommentTarget::Table(name) | CommentTarget::Type(name) => format!("{}_Comment", name),
+            CommentTarget::Column(table, column) => format!("{}_Comment_{}", table, column),
+        };
+        definitions.push(Definition(
+            Self::sql_definition_name(&name),
+            Sql::Comment(target, comment.into()),
+        ));
     }
 
     pub fn rust_struct_to_sql_table(
@@ -341,7 +416,10 @@ impl Model<Sql> {
         enumeration: &PlainEnum,
         definitions: &mut Vec<Definition<Sql>>,
     ) {
-        let variants = enumeration.variants().map(String::clone).collect();
+        let variants = enumeration
+            .variants()
+            .map(|v| v.name().to_string())
+            .collect();
         definitions.push(Definition(name.into(), Sql::Enum(variants)));
         Self::add_silently_prevent_any_delete(name, definitions);
     }
@@ -437,6 +515,8 @@ impl Model<Sql> {
     }
 
     pub fn sql_definition_name(name: &str) -> String {
+        // the tables of all modules share one namespace
+        let name = unqualified_type_name(name);
         if name.len() > TYPENAME_LENGTH_LIMIT_PSQL {
             // carve out lower case segments at the beginning until it fits into the limit
             let mut result = String::with_capacity(TYPENAME_LENGTH_LIMIT_PSQL);
@@ -506,6 +586,7 @@ impl Model<Sql> {
         match rust.as_inner_type() {
             RustType::String(..) => true,
             RustType::VecU8(_) => true,
+            RustType::ByteArray(_) => true,
             RustType::BitVec(_) => true,
             RustType::Null => true,
             r => r.is_primitive(),
@@ -547,12 +628,16 @@ impl ToSql for RustType {
             RustType::U32(Range(_, upper, _)) if *upper <= i32::MAX as u32 => SqlType::Integer,
             RustType::U16(_) | RustType::I32(_) => SqlType::Integer,
             RustType::U32(_) | RustType::U64(_) | RustType::I64(_) => SqlType::BigInt,
-            RustType::String(_size, _charset) => SqlType::Text,
-            RustType::VecU8(_) => SqlType::ByteArray,
+            RustType::String(..) => SqlType::Text,
+            RustType::VecU8(_) | RustType::ByteArray(_) => SqlType::ByteArray,
             RustType::BitVec(_) => SqlType::BitsReprByByteArrayAndBitsLen,
-            RustType::Vec(inner, _size, _ordering) => SqlType::Array(inner.to_sql().into()),
+            RustType::Vec(inner, ..) | RustType::Array(inner, _) => {
+                SqlType::Array(inner.to_sql().into())
+            }
             RustType::Option(inner) => return inner.to_sql().nullable(),
             RustType::Default(inner, ..) => return inner.to_sql(),
+            RustType::Box(inner) => return inner.to_sql(),
+            RustType::Override(inner, _) => return inner.to_sql(),
             RustType::Complex(name, _tag) => SqlType::References(
                 name.clone(),
                 FOREIGN_KEY_DEFAULT_COLUMN.into(),
@@ -605,7 +690,7 @@ mod tests {
             definitions: vec![Definition(
                 "Person".into(),
                 Rust::struct_from_fields(vec![
-                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8)),
+                    Field::from_name_type("name", RustType::String(Size::Any, Charset::Utf8, None)),
                     Field::from_name_type("birth", RustType::Complex("City".into(), None)),
                 ]),
             )],
@@ -678,7 +763,7 @@ mod tests {
                     vec![
                         DataVariant::from_name_type(
                             "DeadSince",
-                            RustType::String(Size::Any, Charset::Utf8),
+                            RustType::String(Size::Any, Charset::Utf8, None),
                         ),
                         DataVariant::from_name_type(
                             "Alive",
@@ -786,7 +871,7 @@ mod tests {
                     Field::from_name_type(
                         "list_of_primitive",
                         RustType::Vec(
-                            Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                            Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                             Size::Any,
                             EncodingOrdering::Keep,
                         ),
@@ -922,7 +1007,7 @@ mod tests {
             definitions: vec![
                 Definition(
                     "Whatever".into(),
-                    Rust::tuple_struct_from_type(RustType::String(Size::Any, Charset::Utf8)),
+                    Rust::tuple_struct_from_type(RustType::String(Size::Any, Charset::Utf8, None)),
                 ),
                 Definition(
                     "Whatelse".into(),
@@ -1084,7 +1169,7 @@ mod tests {
                 "City".into(),
                 Rust::struct_from_fields(vec![Field::from_name_type(
                     "id",
-                    RustType::String(Size::Any, Charset::Utf8),
+                    RustType::String(Size::Any, Charset::Utf8, None),
                 )]),
             )],
             ..Default::default()
@@ -1184,10 +1269,10 @@ mod tests {
         );
 
         assert_eq!(
-            RustType::String(Size::Any, Charset::Utf8)
+            RustType::String(Size::Any, Charset::Utf8, None)
                 .to_sql()
                 .to_rust(),
-            RustType::String(Size::Any, Charset::Utf8),
+            RustType::String(Size::Any, Charset::Utf8, None),
         );
         assert_eq!(
             RustType::VecU8(Size::Any).to_sql().to_rust(),
@@ -1195,14 +1280,14 @@ mod tests {
         );
         assert_eq!(
             RustType::Vec(
-                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                 Size::Any,
                 EncodingOrdering::Keep
             )
             .to_sql()
             .to_rust(),
             RustType::Vec(
-                Box::new(RustType::String(Size::Any, Charset::Utf8)),
+                Box::new(RustType::String(Size::Any, Charset::Utf8, None)),
                 Size::Any,
                 EncodingOrdering::Keep
             ),
@@ -1290,4 +1375,72 @@ mod tests {
             .to_string()
         );
     }
+
+    #[test]
+    fn test_conversion_comments() {
+        use crate::gen::sql::SqlDefGenerator;
+        use crate::gen::Generator;
+        use crate::parser::Tokenizer;
+
+        let model = Model::try_from(Tokenizer::default().parse_with_comments(
+            r#"Comments DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            -- The kind
+            Kind ::= ENUMERATED { car, bus }
+            -- The person's vehicle
+            Vehicle ::= SEQUENCE {
+                kind Kind, -- the kind
+                wheels SEQUENCE OF INTEGER (0..255) -- per axis
+            }
+            END"#,
+        ))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust()
+        .to_sql();
+
+        assert_eq!(
+            vec![
+                Definition(
+                    "Kind_Comment".to_string(),
+                    Sql::Comment(CommentTarget::Type("Kind".into()), "The kind".into())
+                ),
+                Definition(
+                    "Vehicle_Comment".to_string(),
+                    Sql::Comment(
+                        CommentTarget::Table("Vehicle".into()),
+                        "The person's vehicle".into()
+                    )
+                ),
+                Definition(
+                    "Vehicle_Comment_kind".to_string(),
+                    Sql::Comment(
+                        CommentTarget::Column("Vehicle".into(), "kind".into()),
+                        "the kind".into()
+                    )
+                ),
+                Definition(
+                    "Vehicle_Wheels_Comment".to_string(),
+                    Sql::Comment(
+                        CommentTarget::Table("Vehicle_Wheels".into()),
+                        "per axis".into()
+                    )
+                ),
+            ],
+            model
+                .definitions
+                .iter()
+                .filter(|d| matches!(d.value(), Sql::Comment(..)))
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+
+        let mut generator = SqlDefGenerator::default();
+        generator.add_model(model);
+        let (_file, content) = generator.to_string().unwrap().remove(0);
+        assert!(content.contains("COMMENT ON TYPE Kind IS 'The kind';"));
+        assert!(content.contains("COMMENT ON TABLE Vehicle IS 'The person''s vehicle';"));
+        assert!(content.contains("COMMENT ON COLUMN Vehicle.kind IS 'the kind';"));
+        assert!(content.contains("COMMENT ON TABLE Vehicle_Wheels IS 'per axis';"));
+    }
 }
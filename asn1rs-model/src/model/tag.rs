@@ -1,6 +1,7 @@
 use crate::model::{Error, PeekableTokens};
 use crate::parser::Token;
 use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
 use std::iter::Peekable;
 
 ///ITU-T X.680 | ISO/IEC 8824-1, chapter 8
@@ -31,6 +32,7 @@ use std::iter::Peekable;
 /// ]);
 /// ```
 #[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Ord, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Tag {
     Universal(usize),
     Application(usize),
@@ -44,6 +46,7 @@ impl Tag {
     pub const DEFAULT_BIT_STRING: Tag = Tag::Universal(3);
     pub const DEFAULT_OCTET_STRING: Tag = Tag::Universal(4);
     pub const DEFAULT_NULL: Tag = Tag::Universal(5);
+    pub const DEFAULT_OBJECT_IDENTIFIER: Tag = Tag::Universal(6);
     pub const DEFAULT_ENUMERATED: Tag = Tag::Universal(10);
     pub const DEFAULT_UTF8_STRING: Tag = Tag::Universal(12);
     pub const DEFAULT_SEQUENCE: Tag = Tag::Universal(16);
@@ -101,6 +104,18 @@ impl<T: Iterator<Item = Token>> TryFrom<&mut Peekable<T>> for Tag {
     }
 }
 
+/// Formats the tag as in the ASN.1 notation, like `[APPLICATION 7]` or `[3]`
+impl Display for Tag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tag::Universal(number) => write!(f, "[UNIVERSAL {}]", number),
+            Tag::Application(number) => write!(f, "[APPLICATION {}]", number),
+            Tag::ContextSpecific(number) => write!(f, "[{}]", number),
+            Tag::Private(number) => write!(f, "[PRIVATE {}]", number),
+        }
+    }
+}
+
 pub trait TagProperty {
     fn tag(&self) -> Option<Tag>;
 
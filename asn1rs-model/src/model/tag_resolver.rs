@@ -23,22 +23,38 @@ impl TagResolver<'_> {
     /// ITU-T X.680 | ISO/IEC 8824-1, 8.6
     /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
     pub fn resolve_tag(&self, ty: &str) -> Option<Tag> {
+        self.resolve_tag_visiting(ty, &mut Vec::default())
+    }
+
+    /// `visiting` holds the names of the definitions currently being resolved to detect
+    /// recursive definitions
+    fn resolve_tag_visiting(&self, ty: &str, visiting: &mut Vec<String>) -> Option<Tag> {
         self.model
             .imports
             .iter()
             .find(|import| import.what.iter().any(|what| what.eq(ty)))
-            .map(|import| &import.from)
-            .and_then(|model_name| self.scope.iter().find(|model| model.name.eq(model_name)))
+            .and_then(|import| {
+                self.scope
+                    .iter()
+                    .find(|model| import.is_satisfied_by(model))
+            })
             .and_then(|model| {
                 TagResolver {
                     model,
                     scope: self.scope,
                 }
-                .resolve_tag(ty)
+                .resolve_tag_visiting(ty, visiting)
             })
             .or_else(|| {
                 self.model.definitions.iter().find(|d| d.0.eq(ty)).and_then(
-                    |Definition(_name, asn)| asn.tag.or_else(|| self.resolve_type_tag(&asn.r#type)),
+                    |Definition(_name, asn)| {
+                        asn.tag.or_else(|| {
+                            visiting.push(ty.to_string());
+                            let tag = self.resolve_type_tag_visiting(&asn.r#type, visiting);
+                            visiting.pop();
+                            tag
+                        })
+                    },
                 )
             })
     }
@@ -54,20 +70,25 @@ impl TagResolver<'_> {
     /// ITU-T X.680 | ISO/IEC 8824-1, 8.6
     /// ITU-T X.680 | ISO/IEC 8824-1, 41, table 8
     pub fn resolve_type_tag(&self, ty: &Type) -> Option<Tag> {
+        self.resolve_type_tag_visiting(ty, &mut Vec::default())
+    }
+
+    fn resolve_type_tag_visiting(&self, ty: &Type, visiting: &mut Vec<String>) -> Option<Tag> {
         match ty {
             Type::Boolean => Some(Tag::DEFAULT_BOOLEAN),
             Type::Integer(_) => Some(Tag::DEFAULT_INTEGER),
             Type::BitString(_) => Some(Tag::DEFAULT_BIT_STRING),
             Type::OctetString(_) => Some(Tag::DEFAULT_OCTET_STRING),
             Type::Enumerated(_) => Some(Tag::DEFAULT_ENUMERATED),
-            Type::String(_, Charset::Numeric) => Some(Tag::DEFAULT_NUMERIC_STRING),
-            Type::String(_, Charset::Printable) => Some(Tag::DEFAULT_PRINTABLE_STRING),
-            Type::String(_, Charset::Visible) => Some(Tag::DEFAULT_VISIBLE_STRING),
-            Type::String(_, Charset::Utf8) => Some(Tag::DEFAULT_UTF8_STRING),
-            Type::String(_, Charset::Ia5) => Some(Tag::DEFAULT_IA5_STRING),
+            Type::String(_, Charset::Numeric, _) => Some(Tag::DEFAULT_NUMERIC_STRING),
+            Type::String(_, Charset::Printable, _) => Some(Tag::DEFAULT_PRINTABLE_STRING),
+            Type::String(_, Charset::Visible, _) => Some(Tag::DEFAULT_VISIBLE_STRING),
+            Type::String(_, Charset::Utf8, _) => Some(Tag::DEFAULT_UTF8_STRING),
+            Type::String(_, Charset::Ia5, _) => Some(Tag::DEFAULT_IA5_STRING),
             Type::Null => Some(Tag::DEFAULT_NULL),
-            Type::Optional(inner) => self.resolve_type_tag(inner),
-            Type::Default(inner, ..) => self.resolve_type_tag(inner),
+            Type::ObjectIdentifier => Some(Tag::DEFAULT_OBJECT_IDENTIFIER),
+            Type::Optional(inner) => self.resolve_type_tag_visiting(inner, visiting),
+            Type::Default(inner, ..) => self.resolve_type_tag_visiting(inner, visiting),
             Type::Sequence(_) => Some(Tag::DEFAULT_SEQUENCE),
             Type::SequenceOf(_, _) => Some(Tag::DEFAULT_SEQUENCE_OF),
             Type::Set(_) => Some(Tag::DEFAULT_SET),
@@ -81,7 +102,13 @@ impl TagResolver<'_> {
                             .map(|extension_after| extension_after + 1)
                             .unwrap_or_else(|| choice.len()),
                     )
-                    .map(|v| v.tag().or_else(|| self.resolve_type_tag(v.r#type())))
+                    // a recursive variant cannot contribute any tag that the other variants
+                    // do not already contribute
+                    .filter_map(|v| match (v.tag(), v.r#type()) {
+                        (Some(tag), _) => Some(Some(tag)),
+                        (None, Type::TypeReference(name, None)) if visiting.contains(name) => None,
+                        (None, r#type) => Some(self.resolve_type_tag_visiting(r#type, visiting)),
+                    })
                     .collect::<Option<Vec<Tag>>>()?;
                 tags.sort();
                 if cfg!(feature = "debug-proc-macro") {
@@ -90,12 +117,13 @@ impl TagResolver<'_> {
                 tags.into_iter().next()
             }
             Type::TypeReference(inner, tag) => {
-                let tag = (*tag).or_else(|| self.resolve_tag(inner.as_str()));
+                let tag = (*tag).or_else(|| self.resolve_tag_visiting(inner.as_str(), visiting));
                 if cfg!(feature = "debug-proc-macro") {
                     println!("resolved :: {}::Tag = {:?}", inner, tag);
                 }
                 tag
             }
+            Type::ConstrainedTypeReference(_, applied) => match *applied {},
         }
     }
 }
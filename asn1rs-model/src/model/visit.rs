@@ -0,0 +1,378 @@
+//! Traversal of the ASN.1 model, for example to rename types, drop definitions or add defaults
+//! before any code is generated. Each method of [`Visit`] and [`VisitMut`] is invoked for every
+//! node of its kind and by default descends into the children of the node by calling the free
+//! function of the same name. An overriding method that still wants to visit the children has to
+//! call that function itself.
+//!
+//! ```
+//! use asn1rs_model::model::visit::{self, VisitMut};
+//! use asn1rs_model::model::{Asn, Definition};
+//!
+//! /// Prefixes all definitions with `Vendor`
+//! struct Prefix;
+//!
+//! impl VisitMut for Prefix {
+//!     fn visit_definition_mut(&mut self, definition: &mut Definition<Asn>) {
+//!         definition.0 = format!("Vendor{}", definition.0);
+//!         visit::visit_definition_mut(self, definition);
+//!     }
+//! }
+//! ```
+
+use crate::model::lor::{ResolveState, Resolved};
+use crate::model::{Asn, ChoiceVariant, Definition, EnumeratedVariant, Field, Import, Model};
+use crate::model::{Type, ValueReference};
+
+pub trait Visit<RS: ResolveState = Resolved> {
+    fn visit_model(&mut self, model: &Model<Asn<RS>>) {
+        visit_model(self, model)
+    }
+
+    fn visit_import(&mut self, _import: &Import) {}
+
+    fn visit_definition(&mut self, definition: &Definition<Asn<RS>>) {
+        visit_definition(self, definition)
+    }
+
+    fn visit_value_reference(&mut self, value_reference: &ValueReference<Asn<RS>>) {
+        visit_value_reference(self, value_reference)
+    }
+
+    fn visit_asn(&mut self, asn: &Asn<RS>) {
+        visit_asn(self, asn)
+    }
+
+    fn visit_type(&mut self, r#type: &Type<RS>) {
+        visit_type(self, r#type)
+    }
+
+    fn visit_field(&mut self, field: &Field<Asn<RS>>) {
+        visit_field(self, field)
+    }
+
+    fn visit_choice_variant(&mut self, variant: &ChoiceVariant<RS>) {
+        visit_choice_variant(self, variant)
+    }
+
+    fn visit_enumerated_variant(&mut self, _variant: &EnumeratedVariant) {}
+}
+
+pub fn visit_model<RS: ResolveState, V: Visit<RS> + ?Sized>(
+    visitor: &mut V,
+    model: &Model<Asn<RS>>,
+) {
+    for import in &model.imports {
+        visitor.visit_import(import);
+    }
+    for definition in &model.definitions {
+        visitor.visit_definition(definition);
+    }
+    for value_reference in &model.value_references {
+        visitor.visit_value_reference(value_reference);
+    }
+}
+
+pub fn visit_definition<RS: ResolveState, V: Visit<RS> + ?Sized>(
+    visitor: &mut V,
+    definition: &Definition<Asn<RS>>,
+) {
+    visitor.visit_asn(&definition.1)
+}
+
+pub fn visit_value_reference<RS: ResolveState, V: Visit<RS> + ?Sized>(
+    visitor: &mut V,
+    value_reference: &ValueReference<Asn<RS>>,
+) {
+    visitor.visit_asn(&value_reference.role)
+}
+
+pub fn visit_asn<RS: ResolveState, V: Visit<RS> + ?Sized>(visitor: &mut V, asn: &Asn<RS>) {
+    visitor.visit_type(&asn.r#type)
+}
+
+pub fn visit_type<RS: ResolveState, V: Visit<RS> + ?Sized>(visitor: &mut V, r#type: &Type<RS>) {
+    match r#type {
+        Type::Optional(inner)
+        | Type::Default(inner, _)
+        | Type::SequenceOf(inner, _)
+        | Type::SetOf(inner, _) => visitor.visit_type(inner),
+        Type::Sequence(components) | Type::Set(components) => {
+            for field in &components.fields {
+                visitor.visit_field(field);
+            }
+        }
+        Type::Choice(choice) => {
+            for variant in choice.variants() {
+                visitor.visit_choice_variant(variant);
+            }
+        }
+        Type::Enumerated(enumerated) => {
+            for variant in enumerated.variants() {
+                visitor.visit_enumerated_variant(variant);
+            }
+        }
+        Type::Boolean
+        | Type::Integer(_)
+        | Type::String(..)
+        | Type::OctetString(_)
+        | Type::BitString(_)
+        | Type::Null
+        | Type::ObjectIdentifier
+        | Type::TypeReference(..)
+        | Type::ConstrainedTypeReference(..) => {}
+    }
+}
+
+pub fn visit_field<RS: ResolveState, V: Visit<RS> + ?Sized>(
+    visitor: &mut V,
+    field: &Field<Asn<RS>>,
+) {
+    visitor.visit_asn(&field.role)
+}
+
+pub fn visit_choice_variant<RS: ResolveState, V: Visit<RS> + ?Sized>(
+    visitor: &mut V,
+    variant: &ChoiceVariant<RS>,
+) {
+    visitor.visit_type(&variant.r#type)
+}
+
+pub trait VisitMut<RS: ResolveState = Resolved> {
+    fn visit_model_mut(&mut self, model: &mut Model<Asn<RS>>) {
+        visit_model_mut(self, model)
+    }
+
+    fn visit_import_mut(&mut self, _import: &mut Import) {}
+
+    fn visit_definition_mut(&mut self, definition: &mut Definition<Asn<RS>>) {
+        visit_definition_mut(self, definition)
+    }
+
+    fn visit_value_reference_mut(&mut self, value_reference: &mut ValueReference<Asn<RS>>) {
+        visit_value_reference_mut(self, value_reference)
+    }
+
+    fn visit_asn_mut(&mut self, asn: &mut Asn<RS>) {
+        visit_asn_mut(self, asn)
+    }
+
+    fn visit_type_mut(&mut self, r#type: &mut Type<RS>) {
+        visit_type_mut(self, r#type)
+    }
+
+    fn visit_field_mut(&mut self, field: &mut Field<Asn<RS>>) {
+        visit_field_mut(self, field)
+    }
+
+    fn visit_choice_variant_mut(&mut self, variant: &mut ChoiceVariant<RS>) {
+        visit_choice_variant_mut(self, variant)
+    }
+
+    fn visit_enumerated_variant_mut(&mut self, _variant: &mut EnumeratedVariant) {}
+}
+
+pub fn visit_model_mut<RS: ResolveState, V: VisitMut<RS> + ?Sized>(
+    visitor: &mut V,
+    model: &mut Model<Asn<RS>>,
+) {
+    for import in &mut model.imports {
+        visitor.visit_import_mut(import);
+    }
+    for definition in &mut model.definitions {
+        visitor.visit_definition_mut(definition);
+    }
+    for value_reference in &mut model.value_references {
+        visitor.visit_value_reference_mut(value_reference);
+    }
+}
+
+pub fn visit_definition_mut<RS: ResolveState, V: VisitMut<RS> + ?Sized>(
+    visitor: &mut V,
+    definition: &mut Definition<Asn<RS>>,
+) {
+    visitor.visit_asn_mut(&mut definition.1)
+}
+
+pub fn visit_value_reference_mut<RS: ResolveState, V: VisitMut<RS> + ?Sized>(
+    visitor: &mut V,
+    value_reference: &mut ValueReference<Asn<RS>>,
+) {
+    visitor.visit_asn_mut(&mut value_reference.role)
+}
+
+pub fn visit_asn_mut<RS: ResolveState, V: VisitMut<RS> + ?Sized>(
+    visitor: &mut V,
+    asn: &mut Asn<RS>,
+) {
+    visitor.visit_type_mut(&mut asn.r#type)
+}
+
+pub fn visit_type_mut<RS: ResolveState, V: VisitMut<RS> + ?Sized>(
+    visitor: &mut V,
+    r#type: &mut Type<RS>,
+) {
+    match r#type {
+        Type::Optional(inner)
+        | Type::Default(inner, _)
+        | Type::SequenceOf(inner, _)
+        | Type::SetOf(inner, _) => visitor.visit_type_mut(inner),
+        Type::Sequence(components) | Type::Set(components) => {
+            for field in &mut components.fields {
+                visitor.visit_field_mut(field);
+            }
+        }
+        Type::Choice(choice) => {
+            for variant in choice.variants_mut() {
+                visitor.visit_choice_variant_mut(variant);
+            }
+        }
+        Type::Enumerated(enumerated) => {
+            for variant in enumerated.variants_mut() {
+                visitor.visit_enumerated_variant_mut(variant);
+            }
+        }
+        Type::Boolean
+        | Type::Integer(_)
+        | Type::String(..)
+        | Type::OctetString(_)
+        | Type::BitString(_)
+        | Type::Null
+        | Type::ObjectIdentifier
+        | Type::TypeReference(..)
+        | Type::ConstrainedTypeReference(..) => {}
+    }
+}
+
+pub fn visit_field_mut<RS: ResolveState, V: VisitMut<RS> + ?Sized>(
+    visitor: &mut V,
+    field: &mut Field<Asn<RS>>,
+) {
+    visitor.visit_asn_mut(&mut field.role)
+}
+
+pub fn visit_choice_variant_mut<RS: ResolveState, V: VisitMut<RS> + ?Sized>(
+    visitor: &mut V,
+    variant: &mut ChoiceVariant<RS>,
+) {
+    visitor.visit_type_mut(&mut variant.r#type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::lor::Unresolved;
+    use crate::parser::Tokenizer;
+
+    const MODULE: &str = r"Visit DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        VendorExtension ::= SEQUENCE { data OCTET STRING }
+        Color ::= ENUMERATED { red, green }
+        Message ::= SEQUENCE {
+            color Color,
+            extension VendorExtension OPTIONAL,
+            items SEQUENCE OF CHOICE { color Color, number INTEGER }
+        }
+    END";
+
+    /// Drops the `Vendor*` definitions and all fields referring to them
+    struct DropVendor;
+
+    impl VisitMut for DropVendor {
+        fn visit_model_mut(&mut self, model: &mut Model<Asn>) {
+            model
+                .definitions
+                .retain(|Definition(name, _)| !name.starts_with("Vendor"));
+            visit_model_mut(self, model)
+        }
+
+        fn visit_type_mut(&mut self, r#type: &mut Type) {
+            if let Type::Sequence(components) = r#type {
+                components.fields.retain(|field| {
+                    !matches!(
+                        &field.role.r#type,
+                        Type::Optional(inner) if matches!(&**inner, Type::TypeReference(name, _) if name.starts_with("Vendor"))
+                    )
+                });
+            }
+            visit_type_mut(self, r#type)
+        }
+    }
+
+    /// Collects the names of all referenced types
+    #[derive(Default)]
+    struct References(Vec<String>);
+
+    impl<RS: ResolveState> Visit<RS> for References {
+        fn visit_type(&mut self, r#type: &Type<RS>) {
+            if let Type::TypeReference(name, _) = r#type {
+                self.0.push(name.clone());
+            }
+            visit_type(self, r#type)
+        }
+    }
+
+    fn unresolved() -> Model<Asn<Unresolved>> {
+        Model::try_from(Tokenizer::default().parse(MODULE)).unwrap()
+    }
+
+    #[test]
+    pub fn test_visit_finds_nested_references() {
+        let mut references = References::default();
+        references.visit_model(&unresolved());
+        assert_eq!(
+            vec![
+                "Color".to_string(),
+                "VendorExtension".to_string(),
+                "Color".to_string()
+            ],
+            references.0
+        );
+
+        let mut resolved = References::default();
+        resolved.visit_model(&unresolved().try_resolve().unwrap());
+        assert_eq!(references.0, resolved.0);
+    }
+
+    #[test]
+    pub fn test_visit_mut_drops_definitions_and_fields() {
+        let mut model = unresolved().try_resolve().unwrap();
+        DropVendor.visit_model_mut(&mut model);
+
+        assert_eq!(
+            vec!["Color", "Message"],
+            model
+                .definitions
+                .iter()
+                .map(|Definition(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        let mut references = References::default();
+        references.visit_model(&model);
+        assert_eq!(vec!["Color".to_string(), "Color".to_string()], references.0);
+    }
+
+    #[test]
+    pub fn test_visit_mut_renames_enumerated_variants() {
+        struct Upper;
+
+        impl VisitMut for Upper {
+            fn visit_enumerated_variant_mut(&mut self, variant: &mut EnumeratedVariant) {
+                variant.name = variant.name.to_uppercase();
+            }
+        }
+
+        let mut model = unresolved().try_resolve().unwrap();
+        Upper.visit_model_mut(&mut model);
+
+        match &model.definitions[1].1.r#type {
+            Type::Enumerated(enumerated) => assert_eq!(
+                vec!["RED", "GREEN"],
+                enumerated
+                    .variants()
+                    .map(EnumeratedVariant::name)
+                    .collect::<Vec<_>>()
+            ),
+            t => panic!("Unexpected {:?}", t),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Default, Copy, Clone, PartialOrd, PartialEq, Eq)]
+#[derive(Debug, Default, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Location {
     line: usize,
     column: usize,
@@ -24,6 +25,9 @@ impl Location {
 pub enum Token {
     Text(Location, String),
     Separator(Location, char),
+    /// Only emitted by [`Tokenizer::parse_with_comments`], the location is the start of the
+    /// comment and the text is without the comment delimiters
+    Comment(Location, String),
 }
 
 impl From<char> for Token {
@@ -43,6 +47,7 @@ impl Display for Token {
         match self {
             Token::Text(_, text) => write!(f, "\"{}\"", text),
             Token::Separator(_, separator) => write!(f, "\'{}\'", separator),
+            Token::Comment(_, comment) => write!(f, "/* {} */", comment),
         }
     }
 }
@@ -65,6 +70,7 @@ impl Token {
         match self {
             Token::Text(location, _) => *location,
             Token::Separator(location, _) => *location,
+            Token::Comment(location, _) => *location,
         }
     }
 
@@ -104,6 +110,17 @@ impl Token {
         self.separator().is_some()
     }
 
+    pub fn comment(&self) -> Option<&str> {
+        match self {
+            Token::Comment(_, comment) => Some(comment),
+            _ => None,
+        }
+    }
+
+    pub fn is_comment(&self) -> bool {
+        self.comment().is_some()
+    }
+
     pub fn into_text(self) -> Option<String> {
         if let Token::Text(_, text) = self {
             Some(text)
@@ -138,8 +155,20 @@ impl Tokenizer {
     /// Ignore multi-line comments defined with /*  */.
     /// Comment terminates when a matching "*/" has been found for each "/*"
     pub fn parse(&self, asn: &str) -> Vec<Token> {
+        self.tokenize(asn, false)
+    }
+
+    /// Like [`Tokenizer::parse`], but keeps the comments as [`Token::Comment`]s, so that they
+    /// can be attached to the definitions, fields and variants they describe
+    pub fn parse_with_comments(&self, asn: &str) -> Vec<Token> {
+        self.tokenize(asn, true)
+    }
+
+    fn tokenize(&self, asn: &str, keep_comments: bool) -> Vec<Token> {
         let mut previous = None;
         let mut tokens = Vec::new();
+        let mut comments = Vec::new();
+        let mut comment: Option<(Location, String)> = None; // Content of the block comment
         let mut nest_lvl = 0; // Nest level of the comments
 
         for (line_0, line) in asn.lines().enumerate() {
@@ -149,25 +178,31 @@ impl Tokenizer {
             while let Some((column_0, char)) = content_iterator.next() {
                 if nest_lvl > 0 {
                     match char {
-                        '*' => {
-                            if let Some((_, '/')) = content_iterator.peek() {
-                                nest_lvl -= 1;
-                                content_iterator.next(); // remove closing '/'
+                        '*' if matches!(content_iterator.peek(), Some((_, '/'))) => {
+                            nest_lvl -= 1;
+                            content_iterator.next(); // remove closing '/'
+                            if nest_lvl == 0 {
+                                if let Some((location, text)) = comment.take() {
+                                    comments.push(Token::Comment(location, comment_text(&text)));
+                                }
+                            } else if let Some((_, text)) = comment.as_mut() {
+                                text.push_str("*/");
                             }
                         }
-                        '/' => {
-                            if let Some((_, '*')) = content_iterator.peek() {
-                                nest_lvl += 1;
-                                content_iterator.next(); // remove opening '*'
+                        '/' if matches!(content_iterator.peek(), Some((_, '*'))) => {
+                            nest_lvl += 1;
+                            content_iterator.next(); // remove opening '*'
+                            if let Some((_, text)) = comment.as_mut() {
+                                text.push_str("/*");
                             }
                         }
-                        _ => {
+                        c => {
                             if content_iterator.peek().is_none()
                                 && line_0 == asn.lines().count() - 1
                             {
                                 panic!("The file has unclosed comment blocks. Nested comment blocks are counted.");
-                            } else {
-                                continue;
+                            } else if let Some((_, text)) = comment.as_mut() {
+                                text.push(c);
                             }
                         }
                     }
@@ -179,12 +214,18 @@ impl Tokenizer {
                     && content_iterator.peek().map(|&(_, ch)| ch) == Some('-')
                 {
                     content_iterator.next(); // remove second '-'
+                    let text = content_iterator.map(|(_, c)| c).collect::<String>();
+                    comments.push(Token::Comment(
+                        Location::at(line_0 + 1, column_0 + 1),
+                        comment_text(text.trim_end().trim_end_matches("--")),
+                    ));
                     break; // ignore rest of the line
                 }
                 match char {
                     '/' if content_iterator.peek().map(|&(_, ch)| ch) == Some('*') => {
                         content_iterator.next(); // remove opening '*'
                         nest_lvl += 1;
+                        comment = Some((Location::at(line_0 + 1, column_0 + 1), String::new()));
                     }
                     // asn syntax
                     ':' | ';' | '=' | '(' | ')' | '{' | '}' | '.' | ',' | '[' | ']' | '\''
@@ -233,16 +274,46 @@ impl Tokenizer {
             if let Some(token) = previous.take() {
                 tokens.push(token);
             }
+
+            if let Some((_, text)) = comment.as_mut() {
+                text.push('\n');
+            }
         }
 
         if let Some(token) = previous {
             tokens.push(token);
         }
 
+        if keep_comments && !comments.is_empty() {
+            tokens.extend(comments);
+            tokens.sort_by_key(Token::location);
+        }
+
         tokens
     }
 }
 
+/// Removes the surrounding whitespace of each line and the leading `*` of block comments that
+/// are decorated like `/** .. */`
+fn comment_text(text: &str) -> String {
+    let lines = text
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').map(str::trim).unwrap_or(line)
+        })
+        .collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|l| !l.is_empty())
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(start, |e| e + 1);
+    lines[start..end].join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -491,4 +562,36 @@ mod tests {
             Token::Text(Location::default(), String::default()).into_separator_or_else(|_| ())
         );
     }
+
+    #[test]
+    pub fn test_parse_with_comments() {
+        let tokens = Tokenizer::default().parse_with_comments(
+            "-- line comment --\n\
+             A ::= /* block /* nested */\n  * second line */ NULL -- trailing",
+        );
+        assert_eq!(
+            vec![
+                Token::Comment(Location::at(1, 1), "line comment".to_string()),
+                Token::Text(Location::at(2, 1), "A".to_string()),
+                Token::Separator(Location::at(2, 3), ':'),
+                Token::Separator(Location::at(2, 4), ':'),
+                Token::Separator(Location::at(2, 5), '='),
+                Token::Comment(
+                    Location::at(2, 7),
+                    "block /* nested */\nsecond line".to_string()
+                ),
+                Token::Text(Location::at(3, 20), "NULL".to_string()),
+                Token::Comment(Location::at(3, 25), "trailing".to_string()),
+            ],
+            tokens
+        );
+    }
+
+    #[test]
+    pub fn test_parse_drops_comments() {
+        assert!(Tokenizer::default()
+            .parse("A ::= NULL -- comment\n/* another */")
+            .iter()
+            .all(|t| !t.is_comment()));
+    }
 }
@@ -0,0 +1,215 @@
+//! Generates Rust code from ASN.1 modules in build scripts. The generated files are written to
+//! `OUT_DIR` together with a root file that declares a module for each of them, so that the
+//! generated code can be included with
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/asn1rs.rs"));
+//! ```
+//!
+//! A `build.rs` that generates the code for all modules in the `asn/` directory, including the
+//! modules they import from there, is as short as
+//!
+//! ```no_run
+//! asn1rs::build::Builder::new()
+//!     .include_dir("asn")
+//!     .derive("Serialize")
+//!     .derive("Deserialize")
+//!     .compile()
+//!     .unwrap();
+//! ```
+
+use crate::converter::{Converter, Error as ConverterError, Pass};
+use crate::gen::rust::RustCodeGenerator;
+use crate::model::module_files;
+use std::fmt::{Display, Formatter};
+use std::io::Error as IoError;
+use std::path::{Path, PathBuf};
+
+/// The name of the root file in the output directory, see [`Builder::root_file`]
+pub const DEFAULT_ROOT_FILE: &str = "asn1rs.rs";
+
+#[derive(Debug)]
+pub enum Error {
+    /// Neither was an output directory given nor is `OUT_DIR` set, which cargo only sets for
+    /// build scripts
+    OutDirNotSet,
+    Io(IoError),
+    /// Loading or converting the modules failed, `diagnostics` is the human readable form of the
+    /// error, see [`Converter::diagnostics`]
+    Converter {
+        error: Box<ConverterError>,
+        diagnostics: String,
+    },
+}
+
+impl From<IoError> for Error {
+    fn from(e: IoError) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Error::OutDirNotSet => write!(f, "error: OUT_DIR is not set"),
+            Error::Io(e) => write!(f, "error: {}", e),
+            Error::Converter { diagnostics, .. } => write!(f, "{}", diagnostics.trim_end()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Default)]
+pub struct Builder {
+    directories: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+    search_paths: Vec<PathBuf>,
+    derives: Vec<String>,
+    nest_modules_by_oid: bool,
+    passes: Vec<Pass>,
+    out_dir: Option<PathBuf>,
+    root_file: Option<String>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generates the code for all ASN.1 modules (`.asn` and `.asn1` files) in the directory,
+    /// which is also searched for imported modules
+    pub fn include_dir<P: Into<PathBuf>>(mut self, directory: P) -> Self {
+        self.directories.push(directory.into());
+        self
+    }
+
+    /// Generates the code for the ASN.1 module in the file
+    pub fn file<P: Into<PathBuf>>(mut self, file: P) -> Self {
+        self.files.push(file.into());
+        self
+    }
+
+    /// Searches the directory for imported modules, see [`Converter::add_search_path`]
+    pub fn search_path<P: Into<PathBuf>>(mut self, directory: P) -> Self {
+        self.search_paths.push(directory.into());
+        self
+    }
+
+    /// Adds the derive to all generated types, see
+    /// [`crate::gen::rust::RustCodeGenerator::add_global_derive`]
+    pub fn derive<S: Into<String>>(mut self, derive: S) -> Self {
+        self.derives.push(derive.into());
+        self
+    }
+
+    /// Nests the modules by their `OBJECT IDENTIFIER`, see
+    /// [`crate::gen::rust::RustCodeGenerator::set_nest_modules_by_oid`]
+    pub fn nest_modules_by_oid(mut self) -> Self {
+        self.nest_modules_by_oid = true;
+        self
+    }
+
+    /// Transforms the modules before the code is generated, see [`Converter::add_pass`]
+    pub fn pass(mut self, pass: Pass) -> Self {
+        self.passes.push(pass);
+        self
+    }
+
+    /// Writes to the given directory instead of `OUT_DIR`
+    pub fn out_dir<P: Into<PathBuf>>(mut self, directory: P) -> Self {
+        self.out_dir = Some(directory.into());
+        self
+    }
+
+    /// Names the root file other than [`DEFAULT_ROOT_FILE`]
+    pub fn root_file<S: Into<String>>(mut self, name: S) -> Self {
+        self.root_file = Some(name.into());
+        self
+    }
+
+    /// Generates the code and the root file and returns the path of the latter. Cargo is
+    /// instructed to rerun the build script if any loaded file or included directory changes.
+    pub fn compile(mut self) -> Result<PathBuf, Error> {
+        let out_dir = self
+            .out_dir
+            .clone()
+            .or_else(|| std::env::var_os("OUT_DIR").map(PathBuf::from))
+            .ok_or(Error::OutDirNotSet)?;
+
+        let mut converter = Converter::default();
+        for pass in std::mem::take(&mut self.passes) {
+            converter.add_pass(pass);
+        }
+        let result = self.convert(&mut converter, &out_dir);
+
+        for directory in &self.directories {
+            println!("cargo:rerun-if-changed={}", directory.display());
+        }
+        for file in converter.loaded_files() {
+            println!("cargo:rerun-if-changed={}", file.display());
+        }
+
+        let mut modules = result.map_err(|error| Error::Converter {
+            diagnostics: converter.diagnostics(&error),
+            error: Box::new(error),
+        })?;
+        modules.sort();
+
+        let root_file = out_dir.join(self.root_file.as_deref().unwrap_or(DEFAULT_ROOT_FILE));
+        std::fs::write(&root_file, Self::root(&out_dir, &modules))?;
+        Ok(root_file)
+    }
+
+    /// Loads the modules and writes the Rust code, returns the names of the written files
+    fn convert(
+        &self,
+        converter: &mut Converter,
+        out_dir: &Path,
+    ) -> Result<Vec<String>, ConverterError> {
+        for directory in self.directories.iter().chain(&self.search_paths) {
+            converter.add_search_path(directory);
+        }
+        for directory in &self.directories {
+            for file in module_files(directory)? {
+                converter.load_file(file)?;
+            }
+        }
+        for file in &self.files {
+            converter.load_file(file)?;
+        }
+        converter.load_imports()?;
+
+        let files = converter.to_rust(out_dir, |generator| {
+            for derive in &self.derives {
+                generator.add_global_derive(derive.clone());
+            }
+            generator.set_nest_modules_by_oid(self.nest_modules_by_oid);
+        })?;
+        Ok(files.into_values().flatten().collect())
+    }
+
+    /// Declares a module for each file, named like the file and nested like the directories of
+    /// the file, see [`RustCodeGenerator::declare_modules`]. As the generated code refers to
+    /// other modules relative to itself with `super::`, the modules have to be declared in the
+    /// same tree as the files. The `#[path]` attributes point to the files in the output
+    /// directory.
+    fn root(out_dir: &Path, files: &[String]) -> String {
+        let modules = files
+            .iter()
+            .map(|file| {
+                file.trim_end_matches(".rs")
+                    .split('/')
+                    .map(str::to_string)
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        RustCodeGenerator::declare_modules(&modules, |path| {
+            let file = format!("{}.rs", path.join("/"));
+            vec![format!(
+                "#[path = {:?}]",
+                out_dir.join(file).to_string_lossy()
+            )]
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use clap::AppSettings;
-use clap::{App, Arg};
+use clap::{App, Arg, SubCommand};
 
 const ARG_RUST_FIELDS_NOT_PUBLIC: [&str; 5] = [
     "RUST_FIELDS_NOT_PUBLIC",
@@ -17,6 +17,30 @@ const ARG_RUST_GETTER_AND_SETTER: [&str; 5] = [
     "Whether to generate getter and setter for the fields of the generated rust structs",
 ];
 
+const ARG_RUST_MODULE_ROOT: [&str; 5] = [
+    "RUST_MODULE_ROOT",
+    "RUST_MODULE_ROOT",
+    "m",
+    "rust-module-root",
+    "The path of the module the generated rust modules are declared in, like 'crate::asn'",
+];
+
+const ARG_RUST_ROOT_FILE: [&str; 5] = [
+    "RUST_ROOT_FILE",
+    "RUST_ROOT_FILE",
+    "r",
+    "rust-root-file",
+    "The file to declare the generated rust modules in, like 'mod.rs' or 'lib.rs'",
+];
+
+const ARG_RUST_NEST_BY_OID: [&str; 5] = [
+    "RUST_NEST_BY_OID",
+    "RUST_NEST_BY_OID",
+    "O",
+    "rust-nest-by-oid",
+    "Whether to nest the generated rust modules by their OBJECT IDENTIFIER",
+];
+
 const ARG_CONVERSION_TARGET: [&str; 5] = [
     "CONVERT_TO",
     "CONVERT_TO",
@@ -25,22 +49,60 @@ const ARG_CONVERSION_TARGET: [&str; 5] = [
     "The target to convert the input files to",
 ];
 
+const ARG_SEARCH_PATH: [&str; 5] = [
+    "SEARCH_PATH",
+    "SEARCH_PATH",
+    "I",
+    "search-path",
+    "Directory to search for modules imported by the source files, by module name or OID",
+];
+
+pub const SUBCOMMAND_COMPAT: &str = "compat";
+pub const SUBCOMMAND_LINT: &str = "lint";
+
+const ARG_COMPAT_OLD: [&str; 5] = ["OLD", "OLD", "o", "old", "A module of the old version"];
+
+const ARG_COMPAT_NEW: [&str; 5] = ["NEW", "NEW", "n", "new", "A module of the new version"];
+
 pub const CONVERSION_TARGET_RUST: &str = "rust";
 pub const CONVERSION_TARGET_PROTO: &str = "proto";
 pub const CONVERSION_TARGET_SQL: &str = "sql";
-pub const CONVERSION_TARGET_POSSIBLE_VALUES: [&str; 3] = [
+pub const CONVERSION_TARGET_FMT: &str = "fmt";
+pub const CONVERSION_TARGET_JSON: &str = "json";
+pub const CONVERSION_TARGET_POSSIBLE_VALUES: [&str; 5] = [
     CONVERSION_TARGET_RUST,
     CONVERSION_TARGET_PROTO,
     CONVERSION_TARGET_SQL,
+    CONVERSION_TARGET_FMT,
+    CONVERSION_TARGET_JSON,
 ];
 
 #[derive(Debug)]
 pub struct Parameters {
     pub rust_fields_not_public: bool,
     pub rust_getter_and_setter: bool,
+    pub rust_module_root: Option<String>,
+    pub rust_root_file: Option<String>,
+    pub rust_nest_by_oid: bool,
     pub conversion_target: String,
+    pub search_paths: Vec<String>,
     pub source_files: Vec<String>,
     pub destination_dir: String,
+    /// Set if the modules of two versions are to be compared instead of converted
+    pub compat: Option<CompatParameters>,
+    /// Set if the modules are to be checked for modeling mistakes instead of converted
+    pub lint: Option<LintParameters>,
+}
+
+#[derive(Debug)]
+pub struct CompatParameters {
+    pub old_files: Vec<String>,
+    pub new_files: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct LintParameters {
+    pub source_files: Vec<String>,
 }
 
 pub fn arg<'a>(values: [&'a str; 5], default: Option<&'a str>) -> Arg<'a, 'a> {
@@ -65,13 +127,22 @@ pub fn create_argument_parser<'a, 'b>() -> App<'a, 'b> {
         .author(env!("CARGO_PKG_AUTHORS"))
         .about(env!("CARGO_PKG_DESCRIPTION"))
         .setting(AppSettings::ColoredHelp)
+        .setting(AppSettings::SubcommandsNegateReqs)
         .arg(arg(ARG_RUST_FIELDS_NOT_PUBLIC, None).takes_value(false))
         .arg(arg(ARG_RUST_GETTER_AND_SETTER, None).takes_value(false))
+        .arg(arg(ARG_RUST_MODULE_ROOT, None))
+        .arg(arg(ARG_RUST_ROOT_FILE, None))
+        .arg(arg(ARG_RUST_NEST_BY_OID, None).takes_value(false))
         .arg(
             arg(ARG_CONVERSION_TARGET, Some(CONVERSION_TARGET_RUST))
                 .possible_values(&CONVERSION_TARGET_POSSIBLE_VALUES)
                 .next_line_help(true),
         )
+        .arg(
+            arg(ARG_SEARCH_PATH, None)
+                .multiple(true)
+                .number_of_values(1),
+        )
         .arg(
             Arg::with_name("DESTINATION_DIR")
                 .required(true)
@@ -84,6 +155,40 @@ pub fn create_argument_parser<'a, 'b>() -> App<'a, 'b> {
                 .multiple(true)
                 .value_name("SOURCE_FILES"),
         )
+        .subcommand(
+            SubCommand::with_name(SUBCOMMAND_COMPAT)
+                .about("Compares two versions of modules under the PER extension rules")
+                .arg(
+                    Arg::with_name(ARG_COMPAT_OLD[0])
+                        .value_name(ARG_COMPAT_OLD[1])
+                        .short(ARG_COMPAT_OLD[2])
+                        .long(ARG_COMPAT_OLD[3])
+                        .help(ARG_COMPAT_OLD[4])
+                        .required(true)
+                        .multiple(true)
+                        .number_of_values(1),
+                )
+                .arg(
+                    Arg::with_name(ARG_COMPAT_NEW[0])
+                        .value_name(ARG_COMPAT_NEW[1])
+                        .short(ARG_COMPAT_NEW[2])
+                        .long(ARG_COMPAT_NEW[3])
+                        .help(ARG_COMPAT_NEW[4])
+                        .required(true)
+                        .multiple(true)
+                        .number_of_values(1),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name(SUBCOMMAND_LINT)
+                .about("Warns about common modeling mistakes in the modules")
+                .arg(
+                    Arg::with_name("SOURCE_FILES")
+                        .required(true)
+                        .multiple(true)
+                        .value_name("SOURCE_FILES"),
+                ),
+        )
 }
 
 pub fn parse_parameters() -> Parameters {
@@ -92,16 +197,41 @@ pub fn parse_parameters() -> Parameters {
     Parameters {
         rust_fields_not_public: matches.is_present(ARG_RUST_FIELDS_NOT_PUBLIC[0]),
         rust_getter_and_setter: matches.is_present(ARG_RUST_GETTER_AND_SETTER[0]),
+        rust_module_root: matches
+            .value_of_lossy(ARG_RUST_MODULE_ROOT[0])
+            .map(|root| root.to_string()),
+        rust_root_file: matches
+            .value_of_lossy(ARG_RUST_ROOT_FILE[0])
+            .map(|file| file.to_string()),
+        rust_nest_by_oid: matches.is_present(ARG_RUST_NEST_BY_OID[0]),
         conversion_target: matches
             .value_of_lossy(ARG_CONVERSION_TARGET[0])
             .expect("Missing conversion target")
             .to_string(),
-        source_files: matches
-            .values_of_lossy("SOURCE_FILES")
-            .expect("Missing source files"),
+        search_paths: matches
+            .values_of_lossy(ARG_SEARCH_PATH[0])
+            .unwrap_or_default(),
+        source_files: matches.values_of_lossy("SOURCE_FILES").unwrap_or_default(),
         destination_dir: matches
             .value_of_lossy("DESTINATION_DIR")
-            .expect("Missing destination directory")
-            .to_string(),
+            .map(|dir| dir.to_string())
+            .unwrap_or_default(),
+        compat: matches
+            .subcommand_matches(SUBCOMMAND_COMPAT)
+            .map(|compat| CompatParameters {
+                old_files: compat
+                    .values_of_lossy(ARG_COMPAT_OLD[0])
+                    .expect("Missing old files"),
+                new_files: compat
+                    .values_of_lossy(ARG_COMPAT_NEW[0])
+                    .expect("Missing new files"),
+            }),
+        lint: matches
+            .subcommand_matches(SUBCOMMAND_LINT)
+            .map(|lint| LintParameters {
+                source_files: lint
+                    .values_of_lossy("SOURCE_FILES")
+                    .expect("Missing source files"),
+            }),
     }
 }
@@ -1,27 +1,51 @@
+use crate::gen::asn::AsnDefGenerator as AsnGenerator;
+use crate::gen::asn::Error as AsnGeneratorError;
 use crate::gen::protobuf::Error as ProtobufGeneratorError;
 use crate::gen::protobuf::ProtobufDefGenerator as ProtobufGenerator;
+use crate::gen::rust::Error as RustGeneratorError;
 use crate::gen::rust::RustCodeGenerator as RustGenerator;
 use crate::gen::sql::Error as SqlGeneratorError;
 use crate::gen::sql::SqlDefGenerator as SqlGenerator;
 use crate::gen::Generator;
+use crate::model::compat::{self, Report};
+use crate::model::lint::{self, Lint};
 use crate::model::lor::Error as ResolveError;
+use crate::model::lor::Unresolved;
 use crate::model::protobuf::ToProtobufModel;
+use crate::model::rust::visit::VisitMut as RustVisitMut;
 use crate::model::sql::ToSqlModel;
-use crate::model::Model;
-use crate::model::{Error as ModelError, MultiModuleResolver};
+use crate::model::visit::VisitMut;
+use crate::model::{Asn, Diagnostic, Import, Model, Rust, Source};
+use crate::model::{Error as ModelError, MultiModuleResolver, SearchError, SkippedFile};
 use crate::parser::Tokenizer;
 use std::collections::HashMap;
 use std::io::Error as IoError;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub enum Error {
-    RustGenerator,
+    RustGenerator(RustGeneratorError),
+    AsnGenerator(AsnGeneratorError),
     ProtobufGenerator(ProtobufGeneratorError),
     SqlGenerator(SqlGeneratorError),
     Model(ModelError),
+    /// The file (first) with the content (second) could not be parsed because of the errors
+    Parse(PathBuf, String, Vec<ModelError>),
     Io(IoError),
     ResolveError(ResolveError),
+    Json(serde_json::Error),
+    /// No module in the search paths satisfies the import
+    ModuleNotFound(Import),
+    /// More than one module in the search paths satisfies the import
+    ConflictingModules(Import, Vec<PathBuf>),
+    /// Modules with the name of the import were found, but their `OBJECT IDENTIFIER`s differ
+    ModuleVersionMismatch(Import, Vec<PathBuf>),
+}
+
+impl From<RustGeneratorError> for Error {
+    fn from(e: RustGeneratorError) -> Self {
+        Error::RustGenerator(e)
+    }
 }
 
 impl From<ProtobufGeneratorError> for Error {
@@ -30,6 +54,12 @@ impl From<ProtobufGeneratorError> for Error {
     }
 }
 
+impl From<AsnGeneratorError> for Error {
+    fn from(e: AsnGeneratorError) -> Self {
+        Error::AsnGenerator(e)
+    }
+}
+
 impl From<SqlGeneratorError> for Error {
     fn from(e: SqlGeneratorError) -> Self {
         Error::SqlGenerator(e)
@@ -48,46 +78,245 @@ impl From<IoError> for Error {
     }
 }
 
+impl From<SearchError> for Error {
+    fn from(e: SearchError) -> Self {
+        match e {
+            SearchError::Io(e) => Error::Io(e),
+            SearchError::ModuleNotFound(import) => Error::ModuleNotFound(import),
+            SearchError::ConflictingModules(import, files) => {
+                Error::ConflictingModules(import, files)
+            }
+            SearchError::ModuleVersionMismatch(import, files) => {
+                Error::ModuleVersionMismatch(import, files)
+            }
+        }
+    }
+}
+
 impl From<ResolveError> for Error {
     fn from(e: ResolveError) -> Self {
         Error::ResolveError(e)
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+/// A transformation of the models, such as renaming types, dropping definitions or adding
+/// defaults, that runs after the modules are resolved and before any code is generated, see
+/// [`Converter::add_pass`]. Passes must be `Send` and `Sync`, so that a [`Converter`] with passes
+/// can still be moved or shared between threads.
+pub enum Pass {
+    /// Transforms the resolved ASN.1 model of each module. These passes run before any Rust pass,
+    /// so changes are also visible to the Rust models of the other modules.
+    Asn(Box<dyn VisitMut + Send + Sync>),
+    /// Transforms the Rust model of each module, which is also the base of the Protobuf and SQL
+    /// models
+    Rust(Box<dyn RustVisitMut + Send + Sync>),
+}
+
+impl Pass {
+    pub fn asn<V: VisitMut + Send + Sync + 'static>(visitor: V) -> Self {
+        Pass::Asn(Box::new(visitor))
+    }
+
+    pub fn rust<V: RustVisitMut + Send + Sync + 'static>(visitor: V) -> Self {
+        Pass::Rust(Box::new(visitor))
+    }
+}
+
 #[derive(Default)]
 pub struct Converter {
     models: MultiModuleResolver,
+    files: Vec<PathBuf>,
+    search_paths: Vec<PathBuf>,
+    passes: Vec<Pass>,
 }
 
 impl Converter {
     pub fn load_file<F: AsRef<Path>>(&mut self, file: F) -> Result<(), Error> {
-        let input = ::std::fs::read_to_string(file)?;
-        let tokens = Tokenizer.parse(&input);
-        let model = Model::try_from(tokens)?;
+        let model = Self::read_file(&file)?;
         self.models.push(model);
+        self.files.push(file.as_ref().to_path_buf());
         Ok(())
     }
 
+    /// The files of all loaded modules, including the ones loaded by [`Converter::load_imports`]
+    pub fn loaded_files(&self) -> &[PathBuf] {
+        &self.files[..]
+    }
+
+    /// The files that [`Converter::load_imports`] skipped, because they cannot be parsed, with
+    /// their parse errors
+    pub fn skipped_files(&self) -> &[SkippedFile] {
+        self.models.skipped_files()
+    }
+
+    /// Adds a transformation pass, which is applied by every conversion except [`Converter::to_asn`]
+    /// in the order the passes were added
+    pub fn add_pass(&mut self, pass: Pass) {
+        self.passes.push(pass);
+    }
+
+    /// Adds a directory in which [`Converter::load_imports`] searches for imported modules
+    pub fn add_search_path<P: Into<PathBuf>>(&mut self, path: P) {
+        self.search_paths.push(path.into());
+    }
+
+    /// Loads the given root module and all modules it (transitively) imports from the
+    /// search paths, see [`Converter::load_imports`]
+    pub fn load_file_with_imports<F: AsRef<Path>>(&mut self, file: F) -> Result<(), Error> {
+        self.load_file(file)?;
+        self.load_imports()
+    }
+
+    /// Searches the search paths for the modules that are imported by the loaded modules but
+    /// have not been loaded yet, see [`MultiModuleResolver::push_imports_from`]
+    pub fn load_imports(&mut self) -> Result<(), Error> {
+        let files = self
+            .models
+            .push_imports_from(&self.search_paths, &self.files)?;
+        self.files.extend(files);
+        Ok(())
+    }
+
+    fn read_file<F: AsRef<Path>>(file: F) -> Result<Model<Asn<Unresolved>>, Error> {
+        let input = ::std::fs::read_to_string(&file)?;
+        let tokens = Tokenizer.parse_with_comments(&input);
+        let mut model = Model::try_from_recovering(tokens)
+            .map_err(|errors| Error::Parse(file.as_ref().to_path_buf(), input, errors))?;
+        model.set_source_file(&file.as_ref().to_string_lossy());
+        Ok(model)
+    }
+
+    /// Renders the given error in a human readable form. Parse errors and resolve errors show the
+    /// offending lines of the source file and unresolvable names are accompanied by similar names
+    /// of the loaded modules.
+    pub fn diagnostics(&self, error: &Error) -> String {
+        match error {
+            Error::Parse(file, source, errors) => errors
+                .iter()
+                .map(|e| Diagnostic::from(e).render(&file.to_string_lossy(), source))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Error::ResolveError(e) => {
+                let diagnostic = self.models.diagnostic(e);
+                let file = e.origin().and_then(Source::file).and_then(|name| {
+                    self.files
+                        .iter()
+                        .find(|file| file.to_string_lossy() == name)
+                });
+                if let Some(file) = file {
+                    return diagnostic.render(
+                        &file.to_string_lossy(),
+                        &std::fs::read_to_string(file).unwrap_or_default(),
+                    );
+                }
+                let mut rendered = format!("error: {}\n", diagnostic.message());
+                if let Some(help) = diagnostic.help() {
+                    rendered.push_str(&format!("  = help: {}\n", help));
+                }
+                rendered
+            }
+            Error::Model(e) => format!("error: {}\n", e),
+            Error::RustGenerator(e) => format!("error: {}\n", e),
+            Error::Io(e) => format!("error: {}\n", e),
+            e => format!("error: {:?}\n", e),
+        }
+    }
+
+    /// Resolves the loaded modules and applies the [`Pass::Asn`] passes
+    fn resolve(&mut self) -> Result<Vec<Model<Asn>>, Error> {
+        let mut models = self.models.try_resolve_all()?;
+        for pass in self.passes.iter_mut() {
+            if let Pass::Asn(pass) = pass {
+                for model in &mut models {
+                    pass.visit_model_mut(model);
+                }
+            }
+        }
+        Ok(models)
+    }
+
+    /// Converts the resolved module to the Rust model and applies the [`Pass::Rust`] passes
+    fn rust_model_of(&mut self, model: &Model<Asn>, scope: &[&Model<Asn>]) -> Model<Rust> {
+        let mut rust = model.to_rust_with_scope(scope);
+        for pass in self.passes.iter_mut() {
+            if let Pass::Rust(pass) = pass {
+                pass.visit_model_mut(&mut rust);
+            }
+        }
+        rust
+    }
+
+    /// Generates the Rust code of all modules with one generator, so that modules can refer to
+    /// each other, and returns the written files per module. The root file, if enabled with
+    /// [`RustGenerator::set_root_file`], is listed under its own name.
     pub fn to_rust<D: AsRef<Path>, A: Fn(&mut RustGenerator)>(
-        &self,
+        &mut self,
         directory: D,
         custom_adjustments: A,
     ) -> Result<HashMap<String, Vec<String>>, Error> {
-        let models = self.models.try_resolve_all()?;
+        let models = self.resolve()?;
         let scope = models.iter().collect::<Vec<_>>();
         let mut files = HashMap::with_capacity(models.len());
 
+        let mut generator = RustGenerator::default();
         for model in &models {
-            let mut generator = RustGenerator::default();
-            generator.add_model(model.to_rust_with_scope(&scope[..]));
+            generator.add_model(self.rust_model_of(model, &scope[..]));
+        }
+
+        custom_adjustments(&mut generator);
+
+        let mut generated = generator.to_string()?.into_iter();
+
+        // one file per model, followed by the root file, if any
+        for model in &models {
+            if let Some((file, content)) = generated.next() {
+                // modules of the same name but with different OIDs share their entry
+                files
+                    .entry(model.name.clone())
+                    .or_insert_with(Vec::new)
+                    .push(Self::write_file(directory.as_ref(), file, content)?);
+            }
+        }
+        for (file, content) in generated {
+            let file = Self::write_file(directory.as_ref(), file, content)?;
+            files.insert(file.clone(), vec![file]);
+        }
+
+        Ok(files)
+    }
+
+    /// Writes the file, whose name might contain subdirectories, to the directory
+    fn write_file(directory: &Path, file: String, content: String) -> Result<String, Error> {
+        let path = directory.join(&file);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, content)?;
+        Ok(file)
+    }
 
-            custom_adjustments(&mut generator);
+    pub fn to_protobuf<D: AsRef<Path>>(
+        &mut self,
+        directory: D,
+    ) -> Result<HashMap<String, Vec<String>>, Error> {
+        let models = self.resolve()?;
+        let scope = models.iter().collect::<Vec<_>>();
+        let mut files = HashMap::with_capacity(models.len());
+
+        for model in &models {
+            let mut generator = ProtobufGenerator::default();
+            generator.add_model(self.rust_model_of(model, &scope[..]).to_protobuf());
 
             files.insert(
                 model.name.clone(),
                 generator
-                    .to_string()
-                    .map_err(|_| Error::RustGenerator)?
+                    .to_string()?
                     .into_iter()
                     .map(|(file, content)| {
                         ::std::fs::write(directory.as_ref().join(&file), content)?;
@@ -100,17 +329,30 @@ impl Converter {
         Ok(files)
     }
 
-    pub fn to_protobuf<D: AsRef<Path>>(
+    /// Compares the loaded modules, as the old version, with the modules loaded by the given
+    /// converter, as the new version, see [`compat::compare`]
+    pub fn check_compatibility(&self, new: &Converter) -> Result<Report, Error> {
+        let old = self.models.try_resolve_all()?;
+        let new = new.models.try_resolve_all()?;
+        Ok(compat::compare(&old, &new))
+    }
+
+    /// Checks the loaded modules for common modeling mistakes, see [`lint::lint`]
+    pub fn lint(&self) -> Result<Vec<Lint>, Error> {
+        Ok(lint::lint(&self.models.try_resolve_all()?))
+    }
+
+    /// Prints the loaded modules in a canonical form, see [`AsnGenerator`]. The modules are not
+    /// resolved, so that value references and constraints are kept as they are written.
+    pub fn to_asn<D: AsRef<Path>>(
         &self,
         directory: D,
     ) -> Result<HashMap<String, Vec<String>>, Error> {
-        let models = self.models.try_resolve_all()?;
-        let scope = models.iter().collect::<Vec<_>>();
-        let mut files = HashMap::with_capacity(models.len());
+        let mut files = HashMap::with_capacity(self.models.models().len());
 
-        for model in &models {
-            let mut generator = ProtobufGenerator::default();
-            generator.add_model(model.to_rust_with_scope(&scope[..]).to_protobuf());
+        for model in self.models.models() {
+            let mut generator = AsnGenerator::default();
+            generator.add_model(model.clone());
 
             files.insert(
                 model.name.clone(),
@@ -128,25 +370,46 @@ impl Converter {
         Ok(files)
     }
 
+    /// Dumps each loaded module as resolved model (`Model<Asn>`) to a JSON file, for tooling
+    /// that wants to inspect the modules without parsing ASN.1 itself.
+    pub fn to_json<D: AsRef<Path>>(
+        &mut self,
+        directory: D,
+    ) -> Result<HashMap<String, Vec<String>>, Error> {
+        let models = self.resolve()?;
+        let mut files = HashMap::with_capacity(models.len());
+
+        for model in &models {
+            let file = format!("{}.json", model.name);
+            ::std::fs::write(
+                directory.as_ref().join(&file),
+                serde_json::to_string_pretty(model)?,
+            )?;
+            files.insert(model.name.clone(), vec![file]);
+        }
+
+        Ok(files)
+    }
+
     pub fn to_sql<D: AsRef<Path>>(
-        &self,
+        &mut self,
         directory: D,
     ) -> Result<HashMap<String, Vec<String>>, Error> {
         self.to_sql_with(directory, SqlGenerator::default())
     }
 
     pub fn to_sql_with<D: AsRef<Path>>(
-        &self,
+        &mut self,
         directory: D,
         mut generator: SqlGenerator,
     ) -> Result<HashMap<String, Vec<String>>, Error> {
-        let models = self.models.try_resolve_all()?;
+        let models = self.resolve()?;
         let scope = models.iter().collect::<Vec<_>>();
         let mut files = HashMap::with_capacity(models.len());
 
         for model in &models {
             generator.reset();
-            generator.add_model(model.to_rust_with_scope(&scope[..]).to_sql());
+            generator.add_model(self.rust_model_of(model, &scope[..]).to_sql());
 
             files.insert(
                 model.name.clone(),
@@ -172,14 +435,14 @@ pub fn convert_to_rust<F: AsRef<Path>, D: AsRef<Path>, A: Fn(&mut RustGenerator)
     custom_adjustments: A,
 ) -> Result<Vec<String>, Error> {
     let input = ::std::fs::read_to_string(file)?;
-    let tokens = Tokenizer.parse(&input);
+    let tokens = Tokenizer.parse_with_comments(&input);
     let model = Model::try_from(tokens)?.try_resolve()?;
     let mut generator = RustGenerator::default();
     generator.add_model(model.to_rust());
 
     custom_adjustments(&mut generator);
 
-    let output = generator.to_string().map_err(|_| Error::RustGenerator)?;
+    let output = generator.to_string()?;
 
     let mut files = Vec::new();
     for (file, content) in output {
@@ -195,7 +458,7 @@ pub fn convert_to_proto<F: AsRef<Path>, D: AsRef<Path>>(
     dir: D,
 ) -> Result<Vec<String>, Error> {
     let input = ::std::fs::read_to_string(file)?;
-    let tokens = Tokenizer.parse(&input);
+    let tokens = Tokenizer.parse_with_comments(&input);
     let model = Model::try_from(tokens)?.try_resolve()?;
     let mut generator = ProtobufGenerator::default();
     generator.add_model(model.to_rust().to_protobuf());
@@ -225,7 +488,7 @@ pub fn convert_to_sql_with<F: AsRef<Path>, D: AsRef<Path>>(
     mut generator: SqlGenerator,
 ) -> Result<Vec<String>, Error> {
     let input = ::std::fs::read_to_string(file)?;
-    let tokens = Tokenizer.parse(&input);
+    let tokens = Tokenizer.parse_with_comments(&input);
     let model = Model::try_from(tokens)?.try_resolve()?;
 
     generator.add_model(model.to_rust().to_sql());
@@ -0,0 +1,191 @@
+//! Helpers to (de-)serialize values with serde as described by the JSON Encoding Rules
+//! (ITU-T X.697), which are referred to by the `#[serde(with = "..")]` attributes of the
+//! `SerdeSupplement` of the code generator.
+
+use crate::syn::BitVec;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
+fn from_hex<E: Error>(hex: &str) -> Result<Vec<u8>, E> {
+    let digits = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| E::custom(format!("{:?} is not a hexadecimal string", hex)))?;
+    if digits.len() % 2 != 0 {
+        return Err(E::custom(format!(
+            "{:?} does not consist of whole octets",
+            hex
+        )));
+    }
+    Ok(digits
+        .chunks(2)
+        .map(|pair| (pair[0] << 4) | pair[1])
+        .collect())
+}
+
+/// `OCTET STRING`s are encoded as string of hexadecimal digits (ITU-T X.697, chapter 24)
+pub mod octet_string {
+    use super::*;
+
+    struct Hex<'a>(&'a [u8]);
+
+    impl Serialize for Hex<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&to_hex(self.0))
+        }
+    }
+
+    fn from_bytes<T: TryFrom<Vec<u8>>, E: Error>(bytes: Vec<u8>) -> Result<T, E> {
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| E::invalid_length(len, &"an octet string of a fixed size"))
+    }
+
+    pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Hex(value.as_ref()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: TryFrom<Vec<u8>>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        from_bytes(from_hex(&String::deserialize(deserializer)?)?)
+    }
+
+    /// For optional `OCTET STRING`s
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+            value: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            value
+                .as_ref()
+                .map(|value| Hex(value.as_ref()))
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, T: TryFrom<Vec<u8>>, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<T>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|hex| from_bytes(from_hex(&hex)?))
+                .transpose()
+        }
+    }
+}
+
+/// `BIT STRING`s are encoded as object with the hexadecimal `value` and the `length` in bits
+/// (ITU-T X.697, chapter 23), unless their size is fixed, see [`bit_string::fixed`]
+pub mod bit_string {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Json {
+        value: String,
+        length: u64,
+    }
+
+    impl From<&BitVec> for Json {
+        fn from(bits: &BitVec) -> Self {
+            Json {
+                value: to_hex(bits.as_byte_slice()),
+                length: bits.bit_len(),
+            }
+        }
+    }
+
+    fn from_json<E: Error>(json: Json) -> Result<BitVec, E> {
+        let bytes = from_hex::<E>(&json.value)?;
+        if bytes.len() as u64 != json.length.div_ceil(8) {
+            Err(E::custom(format!(
+                "{} octets do not hold {} bits",
+                bytes.len(),
+                json.length
+            )))
+        } else {
+            Ok(BitVec::from_bytes(bytes, json.length))
+        }
+    }
+
+    pub fn serialize<S: Serializer>(value: &BitVec, serializer: S) -> Result<S::Ok, S::Error> {
+        Json::from(value).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BitVec, D::Error> {
+        from_json(Json::deserialize(deserializer)?)
+    }
+
+    /// For optional `BIT STRING`s
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            value: &Option<BitVec>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            value.as_ref().map(Json::from).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<BitVec>, D::Error> {
+            Option::<Json>::deserialize(deserializer)?
+                .map(from_json)
+                .transpose()
+        }
+    }
+
+    /// `BIT STRING`s of a fixed size and without extension marker are encoded as string of
+    /// hexadecimal digits, where the last octet is padded with zero bits (ITU-T X.697, 23.2).
+    /// The fixed size is the number of `BITS` to read, such as in
+    /// `#[serde(deserialize_with = "::asn1rs::io::jer::bit_string::fixed::deserialize::<_, 4>")]`.
+    pub mod fixed {
+        use super::*;
+
+        fn from_hex_string<E: Error>(value: String, length: u64) -> Result<BitVec, E> {
+            from_json(Json { value, length })
+        }
+
+        pub fn serialize<S: Serializer>(value: &BitVec, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&to_hex(value.as_byte_slice()))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>, const BITS: u64>(
+            deserializer: D,
+        ) -> Result<BitVec, D::Error> {
+            from_hex_string(String::deserialize(deserializer)?, BITS)
+        }
+
+        /// For optional `BIT STRING`s of a fixed size
+        pub mod option {
+            use super::*;
+
+            pub fn serialize<S: Serializer>(
+                value: &Option<BitVec>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                value
+                    .as_ref()
+                    .map(|value| to_hex(value.as_byte_slice()))
+                    .serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>, const BITS: u64>(
+                deserializer: D,
+            ) -> Result<Option<BitVec>, D::Error> {
+                Option::<String>::deserialize(deserializer)?
+                    .map(|value| from_hex_string(value, BITS))
+                    .transpose()
+            }
+        }
+    }
+}
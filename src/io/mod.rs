@@ -3,6 +3,7 @@
 //!      ::io::per                  Generic Packed Encoding impls and traits
 //!      ::io::per::unaligned       UNALIGNED PER specialization
 //!      ::io::per::aligned         ALIGNED PER specialization
+//!      ::io::jer                  JSON Encoding Rules helpers for serde
 //!      ::io::...                  Other ASN.1 representations (e.g xer, ber, ...)
 //!
 //!      ::io::async_psql           Async PSQL io-utils
@@ -12,6 +13,7 @@
 //!      ::io::uper                 Deprecated UNALIGNED PER decoder/encoder
 //! ```
 
+pub mod jer;
 pub mod per;
 pub mod protobuf;
 
@@ -84,6 +84,7 @@ pub(crate) struct Inner {
 pub enum ErrorKind {
     FromUtf8Error(FromUtf8Error),
     InvalidString(Charset, char, usize),
+    NotInPermittedAlphabet(char, usize),
     UnsupportedOperation(String),
     InsufficientSpaceInDestinationBuffer(Backtrace),
     InsufficientDataInSourceBuffer(Backtrace),
@@ -101,6 +102,8 @@ pub enum ErrorKind {
     BitLenNotInRange(u64, u64, u64),
     OptFlagsExhausted,
     EndOfStream,
+    /// The read value cannot be converted to the type of the field, see [`crate::syn::mapped::Conversion`]
+    ConversionFailed(String),
 }
 
 impl Error {
@@ -113,6 +116,23 @@ impl Error {
         }
     }
 
+    #[cold]
+    #[inline(never)]
+    pub fn ensure_string_permitted(alphabet: Option<&str>, str: &str) -> Result<(), Self> {
+        let alphabet = match alphabet {
+            Some(alphabet) => alphabet,
+            None => return Ok(()),
+        };
+        match str
+            .chars()
+            .enumerate()
+            .find(|(_index, char)| !alphabet.contains(*char))
+        {
+            None => Ok(()),
+            Some((index, char)) => Err(ErrorKind::NotInPermittedAlphabet(char, index).into()),
+        }
+    }
+
     #[cold]
     #[inline(never)]
     pub fn insufficient_space_in_destination_buffer() -> Self {
@@ -151,6 +171,11 @@ impl std::fmt::Display for ErrorKind {
                     charset, index, char
                 )
             }
+            Self::NotInPermittedAlphabet(char, index) => write!(
+                f,
+                "The character at index {} is not within the permitted alphabet: {}",
+                index, char
+            ),
             Self::UnsupportedOperation(o) => write!(f, "The operation is not supported: {}", o),
             Self::InsufficientSpaceInDestinationBuffer(backtrace) => write!(
                 f,
@@ -226,6 +251,9 @@ impl std::fmt::Display for ErrorKind {
                 f,
                 "Can no longer read or write any bytes from the underlying dataset"
             ),
+            Self::ConversionFailed(error) => {
+                write!(f, "The read value cannot be converted: {}", error)
+            }
         }
     }
 }
@@ -237,6 +265,9 @@ impl PartialEq for ErrorKind {
             Self::InvalidString(a, b, c) => {
                 matches!(other, Self::InvalidString(oa, ob, oc) if (a, b, c) == (oa, ob, oc))
             }
+            Self::NotInPermittedAlphabet(a, b) => {
+                matches!(other, Self::NotInPermittedAlphabet(oa, ob) if (a, b) == (oa, ob))
+            }
             Self::UnsupportedOperation(a) => {
                 matches!(other, Self::UnsupportedOperation(oa) if a == oa)
             }
@@ -270,6 +301,7 @@ impl PartialEq for ErrorKind {
             }
             Self::OptFlagsExhausted => matches!(other, Self::OptFlagsExhausted),
             Self::EndOfStream => matches!(other, Self::EndOfStream),
+            Self::ConversionFailed(a) => matches!(other, Self::ConversionFailed(oa) if a == oa),
         }
     }
 }
@@ -13,7 +13,7 @@ const MAX_FRAGMENTS_SIZE: u64 = FRAGMENT_SIZE * MAX_FRAGMENTS as u64;
 
 const LENGTH_127: u64 = 127;
 const LENGTH_16K: u64 = 16 * 1024;
-const LENGTH_64K: u64 = 64 * 1024;
+pub(crate) const LENGTH_64K: u64 = 64 * 1024;
 
 const SMALL_NON_NEGATIVE_NUMBER: u64 = 64;
 
@@ -23,6 +23,11 @@ pub enum Error {
     InvalidVariant(Backtrace, u64),
     UnexpectedFormat(Backtrace, Format),
     UnexpectedTag(Backtrace, (u32, Format)),
+    /// The expected and the actual size of a value with a fixed size
+    UnexpectedSize(Backtrace, usize, usize),
+    /// The read value cannot be converted to the type of the field, see
+    /// [`crate::syn::mapped::Conversion`]
+    ConversionFailed(Backtrace, String),
 }
 
 impl Error {
@@ -50,6 +55,10 @@ impl Error {
     pub fn unexpected_tag(tag: (u32, Format)) -> Self {
         Error::UnexpectedTag(Backtrace::new(), tag)
     }
+
+    pub fn unexpected_size(expected: usize, actual: usize) -> Self {
+        Error::UnexpectedSize(Backtrace::new(), expected, actual)
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -69,6 +78,14 @@ impl std::fmt::Display for Error {
             Error::UnexpectedTag(b, (tag, format)) => {
                 write!(f, "Tag({}/{:?}) is unexpected\n{:?}", tag, format, b)
             }
+            Error::UnexpectedSize(b, expected, actual) => write!(
+                f,
+                "Size({}) is unexpected, expected {}\n{:?}",
+                actual, expected, b
+            ),
+            Error::ConversionFailed(b, error) => {
+                write!(f, "The read value cannot be converted: {}\n{:?}", error, b)
+            }
         }
     }
 }
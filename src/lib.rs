@@ -18,6 +18,8 @@ pub mod io;
 pub mod prelude;
 pub mod syn;
 
+#[cfg(feature = "model")]
+pub mod build;
 #[cfg(feature = "model")]
 pub mod converter;
 #[cfg(feature = "model")]
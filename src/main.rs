@@ -28,27 +28,46 @@ pub use asn1rs_model::parser;
 
 pub fn main() {
     let params = cli::parse_parameters();
-    let mut converter = Converter::default();
 
-    for source in &params.source_files {
-        if let Err(e) = converter.load_file(source) {
-            println!("Failed to load file {}: {:?}", source, e);
-            return;
-        }
+    if let Some(compat) = &params.compat {
+        check_compatibility(&params, compat);
+        return;
     }
 
+    if let Some(lint) = &params.lint {
+        lint_modules(&params, lint);
+        return;
+    }
+
+    let mut converter = match load(&params.search_paths, &params.source_files) {
+        Some(converter) => converter,
+        None => return,
+    };
+
     let result = match params.conversion_target.as_str() {
         cli::CONVERSION_TARGET_RUST => converter.to_rust(&params.destination_dir, |rust| {
             rust.set_fields_pub(!params.rust_fields_not_public);
             rust.set_fields_have_getter_and_setter(params.rust_getter_and_setter);
+            rust.set_nest_modules_by_oid(params.rust_nest_by_oid);
+            if let Some(root) = &params.rust_module_root {
+                rust.set_module_root(root.clone());
+            }
+            if let Some(file) = &params.rust_root_file {
+                rust.set_root_file(file.clone());
+            }
         }),
         cli::CONVERSION_TARGET_PROTO => converter.to_protobuf(&params.destination_dir),
         cli::CONVERSION_TARGET_SQL => converter.to_sql(&params.destination_dir),
+        cli::CONVERSION_TARGET_FMT => converter.to_asn(&params.destination_dir),
+        cli::CONVERSION_TARGET_JSON => converter.to_json(&params.destination_dir),
         e => panic!("Unexpected CONVERSION_TARGET={}", e),
     };
 
     match result {
-        Err(e) => println!("Failed to convert: {:?}", e),
+        Err(e) => {
+            print!("{}", converter.diagnostics(&e));
+            println!("Failed to convert");
+        }
         Ok(files) => {
             for (source, mut files) in files {
                 println!("Successfully converted {} => {}", source, files.remove(0));
@@ -59,3 +78,94 @@ pub fn main() {
         }
     }
 }
+
+/// Loads the source files and, if search paths are given, the modules they import
+fn load(search_paths: &[String], source_files: &[String]) -> Option<Converter> {
+    let mut converter = Converter::default();
+
+    for search_path in search_paths {
+        converter.add_search_path(search_path);
+    }
+
+    for source in source_files {
+        if let Err(e) = converter.load_file(source) {
+            print!("{}", converter.diagnostics(&e));
+            println!("Failed to load file {}", source);
+            return None;
+        }
+    }
+
+    if !search_paths.is_empty() {
+        let result = converter.load_imports();
+        for (file, errors) in converter.skipped_files() {
+            eprintln!(
+                "warning: skipping {}, it cannot be parsed: {}",
+                file.display(),
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        if let Err(e) = result {
+            print!("{}", converter.diagnostics(&e));
+            println!("Failed to load imported modules");
+            return None;
+        }
+    }
+
+    Some(converter)
+}
+
+/// Prints the changes between the old and new modules and exits with a non-zero status code if
+/// any of them is breaking
+fn check_compatibility(params: &cli::Parameters, compat: &cli::CompatParameters) {
+    let old = load(&params.search_paths, &compat.old_files);
+    let new = load(&params.search_paths, &compat.new_files);
+    let (old, new) = match (old, new) {
+        (Some(old), Some(new)) => (old, new),
+        _ => std::process::exit(2),
+    };
+
+    match old.check_compatibility(&new) {
+        Err(e) => {
+            print!("{}", old.diagnostics(&e));
+            println!("Failed to compare");
+            std::process::exit(2);
+        }
+        Ok(report) => {
+            print!("{}", report);
+            match report.compatibility() {
+                None => println!("No changes"),
+                Some(compatibility) => println!("Overall: {}", compatibility),
+            }
+            if report.is_breaking() {
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Prints the lints of the modules and exits with a non-zero status code if there are any
+fn lint_modules(params: &cli::Parameters, lint: &cli::LintParameters) {
+    let converter = match load(&params.search_paths, &lint.source_files) {
+        Some(converter) => converter,
+        None => std::process::exit(2),
+    };
+
+    match converter.lint() {
+        Err(e) => {
+            print!("{}", converter.diagnostics(&e));
+            println!("Failed to lint");
+            std::process::exit(2);
+        }
+        Ok(lints) => {
+            lints.iter().for_each(|lint| println!("{}", lint));
+            if !lints.is_empty() {
+                println!("{} warning(s)", lints.len());
+                std::process::exit(1);
+            }
+        }
+    }
+}
@@ -145,6 +145,20 @@ impl BitVec {
     }
 }
 
+impl core::iter::FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(bits: I) -> Self {
+        let mut bit_vec = BitVec::default();
+        for (index, bit) in bits.into_iter().enumerate() {
+            if bit {
+                bit_vec.set_bit(index as u64);
+            } else {
+                bit_vec.reset_bit(index as u64);
+            }
+        }
+        bit_vec
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -25,3 +25,18 @@ impl<V: Readable, C: Constraint> ReadableType for Complex<V, C> {
         V::read(reader)
     }
 }
+
+/// Recursive types are boxed, see `RustType::Box`
+impl<V: Writable> Writable for Box<V> {
+    #[inline]
+    fn write<W: Writer>(&self, writer: &mut W) -> Result<(), <W as Writer>::Error> {
+        V::write(self, writer)
+    }
+}
+
+impl<V: Readable> Readable for Box<V> {
+    #[inline]
+    fn read<R: Reader>(reader: &mut R) -> Result<Self, <R as Reader>::Error> {
+        V::read(reader).map(Box::new)
+    }
+}
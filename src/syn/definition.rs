@@ -0,0 +1,83 @@
+use crate::gen::asn::AsnDefGenerator;
+use crate::model::lor::Resolved;
+use crate::model::{Asn, Model};
+use std::fmt::{Display, Formatter};
+
+/// The ASN.1 definition of a type declared with the `#[asn(..)]` attribute, which is
+/// implemented by the attribute for every `SEQUENCE`, `SET`, `ENUMERATED`, `CHOICE` and
+/// transparent type.
+pub trait AsnDefinition {
+    /// The name of the definition in the ASN.1 module
+    const ASN_NAME: &'static str;
+    /// The ASN.1 notation of the definition, such as `Potato ::= SEQUENCE { .. }`
+    const ASN_DEFINITION: &'static str;
+
+    /// Adds the definitions of the types this definition refers to, see [`AsnModule::add`]
+    fn add_referenced_definitions(_module: &mut AsnModule) {}
+}
+
+/// Prints a complete ASN.1 module with the definitions of the given `#[asn(..)]` types:
+///
+/// ```ignore
+/// let module = AsnModule::new("Kitchen").with::<Potato>().with::<Topping>();
+/// std::fs::write("kitchen.asn1", module.to_string())?;
+/// ```
+///
+/// The types that the added types refer to are added as well, so that the module is complete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsnModule {
+    name: String,
+    definitions: Vec<(&'static str, &'static str)>,
+}
+
+impl AsnModule {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            definitions: Vec::default(),
+        }
+    }
+
+    /// Adds the definition of the given type and of the types it refers to, unless a
+    /// definition with that name was added before
+    pub fn with<T: AsnDefinition>(mut self) -> Self {
+        self.add::<T>();
+        self
+    }
+
+    /// See [`AsnModule::with`]
+    pub fn add<T: AsnDefinition>(&mut self) {
+        if !self
+            .definitions
+            .iter()
+            .any(|(name, _)| *name == T::ASN_NAME)
+        {
+            self.definitions.push((T::ASN_NAME, T::ASN_DEFINITION));
+            T::add_referenced_definitions(self);
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn definitions(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.definitions.iter().map(|(_, definition)| *definition)
+    }
+}
+
+impl Display for AsnModule {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let model = Model::<Asn> {
+            name: self.name.clone(),
+            ..Default::default()
+        };
+        AsnDefGenerator::<Resolved>::append_header(f, &model).map_err(|_| std::fmt::Error)?;
+        for definition in self.definitions() {
+            writeln!(f)?;
+            writeln!(f, "{}", definition)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "END")
+    }
+}
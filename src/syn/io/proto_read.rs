@@ -1,8 +1,10 @@
 use crate::io::protobuf::ProtoRead as _;
 use crate::io::protobuf::{Error, Format};
 use crate::syn::*;
+use backtrace::Backtrace;
 use std::borrow::Cow;
 use std::collections::VecDeque;
+use std::convert::TryFrom;
 use std::ops::Range;
 
 #[derive(Debug, Clone)]
@@ -203,6 +205,16 @@ impl<'a> ProtobufReader<'a> {
     }
 }
 
+impl ReadError for Error {
+    fn conversion_failed(error: String) -> Self {
+        Error::ConversionFailed(Backtrace::new(), error)
+    }
+
+    fn unexpected_size(expected: usize, actual: usize) -> Self {
+        Error::unexpected_size(expected, actual)
+    }
+}
+
 impl<'a> Reader for ProtobufReader<'a> {
     type Error = Error;
 
@@ -377,6 +389,14 @@ impl<'a> Reader for ProtobufReader<'a> {
         reader.read_bytes()
     }
 
+    #[inline]
+    fn read_octet_string_array<C: octetstring::Constraint, const N: usize>(
+        &mut self,
+    ) -> Result<[u8; N], Self::Error> {
+        let bytes = self.next_range_format_reader(Format::LengthDelimited);
+        <[u8; N]>::try_from(bytes).map_err(|_| Error::unexpected_size(N, bytes.len()))
+    }
+
     #[inline]
     fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
         let mut reader = self.next_range_format_reader(Format::LengthDelimited); // TODO Format::VarInt ??
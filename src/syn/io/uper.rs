@@ -3,10 +3,12 @@ use crate::io::per::err::ErrorKind;
 use crate::io::per::unaligned::buffer::BitBuffer;
 use crate::io::per::unaligned::BitWrite;
 use crate::io::per::unaligned::BYTE_LEN;
+use crate::io::per::unaligned::LENGTH_64K;
 use crate::io::per::PackedRead;
 use crate::io::per::PackedWrite;
 use crate::model::Charset;
 use crate::syn::*;
+use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::ops::Range;
 
@@ -364,6 +366,40 @@ impl UperWriter {
 
         Ok(out_of_range)
     }
+
+    /// Writes each character with as few bits as the permitted alphabet requires, expects the
+    /// characters to be within the permitted alphabet
+    ///
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.4
+    pub fn write_permitted_alphabet_chars(
+        &mut self,
+        alphabet: &str,
+        value: &str,
+    ) -> Result<(), Error> {
+        let (bits, by_value) = permitted_alphabet_encoding(alphabet);
+        for char in value.chars() {
+            let value = if by_value {
+                char as u32
+            } else {
+                alphabet.chars().position(|c| c == char).unwrap_or_default() as u32
+            };
+            if bits > 0 {
+                self.bits.write_bits_with_offset(&[value as u8], 8 - bits)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The number of bits per character and whether the characters are encoded by their value
+/// instead of their index in the sorted permitted alphabet
+///
+/// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.3 and 30.5.4
+fn permitted_alphabet_encoding(alphabet: &str) -> (usize, bool) {
+    let len = alphabet.chars().count();
+    let bits = (usize::BITS - len.saturating_sub(1).leading_zeros()) as usize;
+    let by_value = alphabet.chars().all(|char| (char as u32) < (1 << bits));
+    (bits, by_value)
 }
 
 impl Writer for UperWriter {
@@ -568,6 +604,7 @@ impl Writer for UperWriter {
     ) -> Result<(), Self::Error> {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
+            Error::ensure_string_permitted(C::PERMITTED_ALPHABET, value)?;
             if !C::EXTENSIBLE {
                 let chars = value.chars().count() as u64;
                 let min = const_unwrap_or!(C::MIN, 0);
@@ -592,6 +629,7 @@ impl Writer for UperWriter {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
             Error::ensure_string_valid(Charset::Ia5, value)?;
+            Error::ensure_string_permitted(C::PERMITTED_ALPHABET, value)?;
 
             w.write_extensible_bit_and_length_or_err(
                 C::EXTENSIBLE,
@@ -601,6 +639,10 @@ impl Writer for UperWriter {
                 value.chars().count() as u64,
             )?;
 
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return w.write_permitted_alphabet_chars(alphabet, value);
+            }
+
             for char in value.chars().map(|c| c as u8) {
                 // 7 bits
                 w.bits.write_bits_with_offset(&[char], 1)?;
@@ -618,6 +660,7 @@ impl Writer for UperWriter {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
             Error::ensure_string_valid(Charset::Numeric, value)?;
+            Error::ensure_string_permitted(C::PERMITTED_ALPHABET, value)?;
 
             w.write_extensible_bit_and_length_or_err(
                 C::EXTENSIBLE,
@@ -627,6 +670,10 @@ impl Writer for UperWriter {
                 value.chars().count() as u64,
             )?;
 
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return w.write_permitted_alphabet_chars(alphabet, value);
+            }
+
             for char in value.chars().map(|c| c as u8) {
                 let char = match char - 32 {
                     0 => 0,
@@ -647,6 +694,7 @@ impl Writer for UperWriter {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
             Error::ensure_string_valid(Charset::Printable, value)?;
+            Error::ensure_string_permitted(C::PERMITTED_ALPHABET, value)?;
 
             w.write_extensible_bit_and_length_or_err(
                 C::EXTENSIBLE,
@@ -656,6 +704,10 @@ impl Writer for UperWriter {
                 value.chars().count() as u64,
             )?;
 
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return w.write_permitted_alphabet_chars(alphabet, value);
+            }
+
             for char in value.chars() {
                 w.bits.write_bits_with_offset(&[char as u8], 1)?;
             }
@@ -672,6 +724,7 @@ impl Writer for UperWriter {
         self.write_bit_field_entry(false, true)?;
         self.with_buffer(|w| {
             Error::ensure_string_valid(Charset::Visible, value)?;
+            Error::ensure_string_permitted(C::PERMITTED_ALPHABET, value)?;
 
             w.write_extensible_bit_and_length_or_err(
                 C::EXTENSIBLE,
@@ -681,6 +734,10 @@ impl Writer for UperWriter {
                 value.chars().count() as u64,
             )?;
 
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return w.write_permitted_alphabet_chars(alphabet, value);
+            }
+
             for char in value.chars() {
                 w.bits.write_bits_with_offset(&[char as u8], 1)?;
             }
@@ -774,6 +831,29 @@ impl<B: ScopedBitRead> UperReader<B> {
         result
     }
 
+    /// Reads characters that are encoded with as few bits as the permitted alphabet requires
+    ///
+    /// ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.5.4
+    fn read_permitted_alphabet_chars(&mut self, alphabet: &str, len: u64) -> Result<String, Error> {
+        let (bits, by_value) = permitted_alphabet_encoding(alphabet);
+        (0..len as usize)
+            .map(|index| {
+                let mut value = [0_u8];
+                if bits > 0 {
+                    self.bits.read_bits_with_offset(&mut value, 8 - bits)?;
+                }
+                let char = if by_value {
+                    Some(char::from(value[0])).filter(|c| alphabet.contains(*c))
+                } else {
+                    alphabet.chars().nth(usize::from(value[0]))
+                };
+                char.ok_or_else(|| {
+                    ErrorKind::NotInPermittedAlphabet(char::from(value[0]), index).into()
+                })
+            })
+            .collect()
+    }
+
     #[inline]
     fn read_enumeration_index(
         &mut self,
@@ -872,6 +952,16 @@ impl<B: ScopedBitRead> UperReader<B> {
         result
     }
 
+    /// The number of elements of a `SEQUENCE OF`
+    #[inline]
+    fn read_sequence_of_len<C: sequenceof::Constraint>(&mut self) -> Result<u64, Error> {
+        if C::EXTENSIBLE && self.bits.read_bit()? {
+            self.read_length_determinant(None, None)
+        } else {
+            self.read_length_determinant(C::MIN, C::MAX)
+        }
+    }
+
     #[inline]
     pub fn read_bit_field_entry(&mut self, is_opt: bool) -> Result<Option<bool>, Error> {
         #[allow(clippy::let_and_return)]
@@ -914,6 +1004,16 @@ impl<B: ScopedBitRead> UperReader<B> {
     }
 }
 
+impl ReadError for Error {
+    fn conversion_failed(error: String) -> Self {
+        ErrorKind::ConversionFailed(error).into()
+    }
+
+    fn unexpected_size(expected: usize, actual: usize) -> Self {
+        ErrorKind::SizeNotInRange(actual as u64, expected as u64, expected as u64).into()
+    }
+}
+
 impl<B: ScopedBitRead> Reader for UperReader<B> {
     type Error = Error;
 
@@ -1002,16 +1102,7 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         let _ = self.read_bit_field_entry(false)?;
         #[allow(clippy::let_and_return)]
         self.with_buffer(|r| {
-            let len = if C::EXTENSIBLE {
-                let extensible = r.bits.read_bit()?;
-                if extensible {
-                    r.read_length_determinant(None, None)?
-                } else {
-                    r.read_length_determinant(C::MIN, C::MAX)?
-                }
-            } else {
-                r.read_length_determinant(C::MIN, C::MAX)?
-            };
+            let len = r.read_sequence_of_len::<C>()?;
 
             if len > 0 {
                 r.scope_stashed(|r| {
@@ -1027,6 +1118,24 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         })
     }
 
+    #[inline]
+    fn read_sequence_of_array<C: sequenceof::Constraint, T: ReadableType, const N: usize>(
+        &mut self,
+    ) -> Result<[T::Type; N], Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::sequence_of::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        self.with_buffer(|r| {
+            let len = r.read_sequence_of_len::<C>()?;
+            if len != N as u64 {
+                return Err(ErrorKind::SizeNotInRange(len, N as u64, N as u64).into());
+            }
+            r.scope_stashed(|r| sequenceof::try_array_from_fn(|| T::read_value(r)))
+        })
+    }
+
     #[inline]
     fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
         &mut self,
@@ -1192,7 +1301,9 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
             // ITU-T X.691 | ISO/IEC 8825-2:2015, chapter 30.3
             // For 'known-multiplier character string types' there is no min/max in the encoding
             let octets = r.bits.read_octetstring(None, None, false)?;
-            String::from_utf8(octets).map_err(|e| ErrorKind::FromUtf8Error(e).into())
+            let string = String::from_utf8(octets).map_err(ErrorKind::FromUtf8Error)?;
+            Error::ensure_string_permitted(C::PERMITTED_ALPHABET, &string)?;
+            Ok(string)
         });
 
         #[cfg(feature = "descriptive-deserialize-errors")]
@@ -1217,6 +1328,10 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
                 r.read_length_determinant(C::MIN, C::MAX)?
             };
 
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_chars(alphabet, len);
+            }
+
             let mut buffer = vec![0u8; len as usize];
             for i in 0..len as usize {
                 r.bits.read_bits_with_offset(&mut buffer[i..i + 1], 1)?;
@@ -1247,6 +1362,10 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
                 r.read_length_determinant(C::MIN, C::MAX)?
             };
 
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_chars(alphabet, len);
+            }
+
             let mut buffer = vec![0u8; len as usize];
             for i in 0..len as usize {
                 r.bits.read_bits_with_offset(&mut buffer[i..i + 1], 4)?;
@@ -1283,6 +1402,10 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
                 r.read_length_determinant(C::MIN, C::MAX)?
             };
 
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_chars(alphabet, len);
+            }
+
             let mut buffer = vec![0u8; len as usize];
             buffer
                 .chunks_exact_mut(1)
@@ -1313,6 +1436,10 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
                 r.read_length_determinant(C::MIN, C::MAX)?
             };
 
+            if let Some(alphabet) = C::PERMITTED_ALPHABET {
+                return r.read_permitted_alphabet_chars(alphabet, len);
+            }
+
             let mut buffer = vec![0u8; len as usize];
             buffer
                 .chunks_exact_mut(1)
@@ -1354,6 +1481,47 @@ impl<B: ScopedBitRead> Reader for UperReader<B> {
         result
     }
 
+    #[inline]
+    fn read_octet_string_array<C: octetstring::Constraint, const N: usize>(
+        &mut self,
+    ) -> Result<[u8; N], Self::Error> {
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description
+            .push(ScopeDescription::octet_string::<C>());
+
+        let _ = self.read_bit_field_entry(false)?;
+        #[allow(clippy::let_and_return)]
+        let result = self.with_buffer(|r| {
+            let size = Some(N as u64);
+            if !C::EXTENSIBLE && C::MIN == size && C::MAX == size && (N as u64) < LENGTH_64K {
+                // the size is not encoded, read the octets in place (ITU-T X.691 | ISO/IEC 8825-2, 17.7)
+                let mut array = [0u8; N];
+                r.bits.read_bits(&mut array[..])?;
+                Ok(array)
+            } else {
+                let vec = r.bits.read_octetstring(C::MIN, C::MAX, C::EXTENSIBLE)?;
+                <[u8; N]>::try_from(&vec[..]).map_err(|_| {
+                    ErrorKind::SizeNotInRange(vec.len() as u64, N as u64, N as u64).into()
+                })
+            }
+        });
+
+        #[cfg(feature = "descriptive-deserialize-errors")]
+        self.scope_description.push(ScopeDescription::Result(
+            result
+                .as_ref()
+                .map(|s| {
+                    s.iter()
+                        .map(|v| format!("{v:02x}"))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .map_err(|e| e.clone()),
+        ));
+
+        result
+    }
+
     #[inline]
     fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error> {
         #[cfg(feature = "descriptive-deserialize-errors")]
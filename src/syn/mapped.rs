@@ -0,0 +1,65 @@
+use crate::syn::{ReadError, ReadableType, Reader, WritableType, Writer};
+use core::convert::TryFrom;
+use core::fmt::Display;
+use core::marker::PhantomData;
+
+/// Converts between the type `T` that is read and written and the user type `U` that replaces
+/// it in the generated code, see `asn1rs_model::model::rust::TypeOverride`. Implement it on a
+/// type of your own crate if neither `T` nor `U` are defined in your crate.
+pub trait Conversion<T, U> {
+    /// The reason why a read value cannot be converted, which is reported as
+    /// [`ReadError::conversion_failed`]
+    type Error: Display;
+
+    fn from_asn(value: T) -> Result<U, Self::Error>;
+
+    fn to_asn(value: &U) -> T;
+}
+
+/// The default [`Conversion`], which requires `U: TryFrom<T>` (or `U: From<T>`) and
+/// `T: From<&U>`
+pub struct FromInto;
+
+impl<T, U> Conversion<T, U> for FromInto
+where
+    U: TryFrom<T>,
+    <U as TryFrom<T>>::Error: Display,
+    for<'a> T: From<&'a U>,
+{
+    type Error = <U as TryFrom<T>>::Error;
+
+    #[inline]
+    fn from_asn(value: T) -> Result<U, Self::Error> {
+        U::try_from(value)
+    }
+
+    #[inline]
+    fn to_asn(value: &U) -> T {
+        T::from(value)
+    }
+}
+
+/// Reads and writes the user type `U` as `T` by converting it with `C`
+pub struct Mapped<T, U, C = FromInto>(PhantomData<T>, PhantomData<U>, PhantomData<C>);
+
+impl<T: WritableType, U, C: Conversion<T::Type, U>> WritableType for Mapped<T, U, C> {
+    type Type = U;
+
+    #[inline]
+    fn write_value<W: Writer>(
+        writer: &mut W,
+        value: &Self::Type,
+    ) -> Result<(), <W as Writer>::Error> {
+        T::write_value(writer, &C::to_asn(value))
+    }
+}
+
+impl<T: ReadableType, U, C: Conversion<T::Type, U>> ReadableType for Mapped<T, U, C> {
+    type Type = U;
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        C::from_asn(T::read_value(reader)?)
+            .map_err(|e| <R::Error as ReadError>::conversion_failed(e.to_string()))
+    }
+}
@@ -4,9 +4,11 @@ pub mod choice;
 pub mod common;
 pub mod complex;
 pub mod default;
+pub mod definition;
 pub mod enumerated;
 pub mod ia5string;
 pub mod io;
+pub mod mapped;
 pub mod null;
 pub mod numbers;
 pub mod numericstring;
@@ -27,23 +29,32 @@ pub use boolean::Boolean;
 pub use choice::Choice;
 pub use complex::Complex;
 pub use default::DefaultValue;
+pub use definition::AsnDefinition;
+pub use definition::AsnModule;
 pub use enumerated::Enumerated;
 pub use ia5string::Ia5String;
+pub use mapped::Mapped;
 pub use null::NullT;
 pub use numbers::Integer;
 pub use numericstring::NumericString;
+pub use octetstring::FixedOctetString;
 pub use octetstring::OctetString;
 pub use printablestring::PrintableString;
 pub use sequence::Sequence;
+pub use sequenceof::FixedSequenceOf;
 pub use sequenceof::SequenceOf;
 pub use set::Set;
 pub use setof::SetOf;
 pub use utf8string::Utf8String;
 pub use visiblestring::VisibleString;
 
+use std::convert::TryFrom;
+
 pub mod prelude {
     pub use super::bitstring::BitVec;
+    pub use super::AsnDefinition;
     pub use super::Null;
+    pub use super::ReadError;
     pub use super::Readable;
     pub use super::ReadableType;
     pub use super::Reader;
@@ -52,8 +63,17 @@ pub mod prelude {
     pub use super::Writer;
 }
 
+/// The errors every [`Reader`] has to be able to report, independent of the encoding
+pub trait ReadError {
+    /// The read value cannot be converted to the type of the field, see [`mapped::Conversion`]
+    fn conversion_failed(error: String) -> Self;
+
+    /// A value with the fixed size `expected` was read with the size `actual`
+    fn unexpected_size(expected: usize, actual: usize) -> Self;
+}
+
 pub trait Reader {
-    type Error;
+    type Error: ReadError;
 
     #[inline]
     fn read<T: Readable>(&mut self) -> Result<T, Self::Error>
@@ -76,6 +96,18 @@ pub trait Reader {
         &mut self,
     ) -> Result<Vec<T::Type>, Self::Error>;
 
+    /// Reads a `SEQUENCE OF` with the fixed size `N` into an array, fails if the size read
+    /// differs from `N`. The default implementation reads a `Vec` with
+    /// [`Reader::read_sequence_of`], readers can avoid that allocation.
+    fn read_sequence_of_array<C: sequenceof::Constraint, T: ReadableType, const N: usize>(
+        &mut self,
+    ) -> Result<[T::Type; N], Self::Error> {
+        let vec = self.read_sequence_of::<C, T>()?;
+        let len = vec.len();
+        <[T::Type; N]>::try_from(vec)
+            .map_err(|_| <Self::Error as ReadError>::unexpected_size(N, len))
+    }
+
     fn read_set<C: set::Constraint, S: Sized, F: Fn(&mut Self) -> Result<S, Self::Error>>(
         &mut self,
         f: F,
@@ -113,6 +145,17 @@ pub trait Reader {
 
     fn read_octet_string<C: octetstring::Constraint>(&mut self) -> Result<Vec<u8>, Self::Error>;
 
+    /// Reads an `OCTET STRING` with the fixed size `N` into an array, fails if the size read
+    /// differs from `N`. The default implementation reads a `Vec` with
+    /// [`Reader::read_octet_string`], readers can avoid that allocation.
+    fn read_octet_string_array<C: octetstring::Constraint, const N: usize>(
+        &mut self,
+    ) -> Result<[u8; N], Self::Error> {
+        let vec = self.read_octet_string::<C>()?;
+        <[u8; N]>::try_from(&vec[..])
+            .map_err(|_| <Self::Error as ReadError>::unexpected_size(N, vec.len()))
+    }
+
     fn read_bit_string<C: bitstring::Constraint>(&mut self) -> Result<(Vec<u8>, u64), Self::Error>;
 
     fn read_boolean<C: boolean::Constraint>(&mut self) -> Result<bool, Self::Error>;
@@ -160,6 +203,15 @@ pub trait Writer {
         slice: &[T::Type],
     ) -> Result<(), Self::Error>;
 
+    /// Writes a `SEQUENCE OF` with the fixed size `N`, see [`Reader::read_sequence_of_array`]
+    #[inline]
+    fn write_sequence_of_array<C: sequenceof::Constraint, T: WritableType, const N: usize>(
+        &mut self,
+        array: &[T::Type; N],
+    ) -> Result<(), Self::Error> {
+        self.write_sequence_of::<C, T>(&array[..])
+    }
+
     fn write_set<C: set::Constraint, F: Fn(&mut Self) -> Result<(), Self::Error>>(
         &mut self,
         f: F,
@@ -217,6 +269,15 @@ pub trait Writer {
         value: &[u8],
     ) -> Result<(), Self::Error>;
 
+    /// Writes an `OCTET STRING` with the fixed size `N`, see [`Reader::read_octet_string_array`]
+    #[inline]
+    fn write_octet_string_array<C: octetstring::Constraint, const N: usize>(
+        &mut self,
+        value: &[u8; N],
+    ) -> Result<(), Self::Error> {
+        self.write_octet_string::<C>(&value[..])
+    }
+
     fn write_bit_string<C: bitstring::Constraint>(
         &mut self,
         value: &[u8],
@@ -1,6 +1,7 @@
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
 use asn1rs_model::model::Tag;
 use core::marker::PhantomData;
+use std::fmt::{Debug, Display, Formatter};
 
 pub struct Integer<T: Number = u64, C: Constraint<T> = NoConstraint>(
     PhantomData<T>,
@@ -69,6 +70,28 @@ macro_rules! impl_number {
 impl_number!(u8, u16, u32, u64);
 impl_number!(i8, i16, i32, i64);
 
+/// A value outside of the range of a constrained `INTEGER`, which is returned by the checked
+/// constructors of the generated newtypes, see
+/// `asn1rs_model::gen::rust::RustCodeGenerator::set_checked_integers`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange<T> {
+    pub value: T,
+    pub min: T,
+    pub max: T,
+}
+
+impl<T: Display> Display for OutOfRange<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "The value {} is not in the range {}..={}",
+            self.value, self.min, self.max
+        )
+    }
+}
+
+impl<T: Debug + Display> std::error::Error for OutOfRange<T> {}
+
 /*
 macro_rules! read_write {
     ( $($T:ident),+ ) => {$(
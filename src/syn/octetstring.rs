@@ -4,6 +4,9 @@ use core::marker::PhantomData;
 
 pub struct OctetString<C: Constraint = NoConstraint>(PhantomData<C>);
 
+/// An `OCTET STRING` of the fixed size `N`, which is read and written as `[u8; N]`
+pub struct FixedOctetString<const N: usize, C: Constraint = NoConstraint>(PhantomData<C>);
+
 pub trait Constraint: super::common::Constraint {
     const MIN: Option<u64> = None;
     const MAX: Option<u64> = None;
@@ -34,3 +37,21 @@ impl<C: Constraint> ReadableType for OctetString<C> {
         reader.read_octet_string::<C>()
     }
 }
+
+impl<const N: usize, C: Constraint> WritableType for FixedOctetString<N, C> {
+    type Type = [u8; N];
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_octet_string_array::<C, N>(value)
+    }
+}
+
+impl<const N: usize, C: Constraint> ReadableType for FixedOctetString<N, C> {
+    type Type = [u8; N];
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_octet_string_array::<C, N>()
+    }
+}
@@ -8,6 +8,8 @@ pub trait Constraint: super::common::Constraint {
     const MIN: Option<u64> = None;
     const MAX: Option<u64> = None;
     const EXTENSIBLE: bool = false;
+    /// The sorted characters of the effective permitted alphabet, if constrained by `FROM`
+    const PERMITTED_ALPHABET: Option<&'static str> = None;
 }
 
 #[derive(Default)]
@@ -1,5 +1,6 @@
 use crate::syn::{ReadableType, Reader, WritableType, Writer};
 use core::marker::PhantomData;
+use std::fmt::{Display, Formatter};
 
 pub struct Sequence<T: Constraint>(PhantomData<T>);
 
@@ -39,3 +40,25 @@ where
         reader.read_sequence::<C, Self::Type, _>(C::read_seq)
     }
 }
+
+/// The mandatory fields that were not set before building a `SEQUENCE` or `SET`, which is
+/// returned by the generated builders, see
+/// `asn1rs_model::gen::rust::RustCodeGenerator::set_builders`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingFields {
+    pub r#type: &'static str,
+    pub fields: Vec<&'static str>,
+}
+
+impl Display for MissingFields {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Unable to build {}, the mandatory field(s) {} are not set",
+            self.r#type,
+            self.fields.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for MissingFields {}
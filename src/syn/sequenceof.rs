@@ -4,6 +4,12 @@ use core::marker::PhantomData;
 
 pub struct SequenceOf<T, C: Constraint = NoConstraint>(PhantomData<T>, PhantomData<C>);
 
+/// A `SEQUENCE OF` of the fixed size `N`, which is read and written as `[T; N]`
+pub struct FixedSequenceOf<T, const N: usize, C: Constraint = NoConstraint>(
+    PhantomData<T>,
+    PhantomData<C>,
+);
+
 pub trait Constraint: super::common::Constraint {
     const MIN: Option<u64> = None;
     const MAX: Option<u64> = None;
@@ -34,3 +40,45 @@ impl<T: ReadableType, C: Constraint> ReadableType for SequenceOf<T, C> {
         reader.read_sequence_of::<C, T>()
     }
 }
+
+impl<T: WritableType, const N: usize, C: Constraint> WritableType for FixedSequenceOf<T, N, C> {
+    type Type = [T::Type; N];
+
+    #[inline]
+    fn write_value<W: Writer>(writer: &mut W, value: &Self::Type) -> Result<(), W::Error> {
+        writer.write_sequence_of_array::<C, T, N>(value)
+    }
+}
+
+impl<T: ReadableType, const N: usize, C: Constraint> ReadableType for FixedSequenceOf<T, N, C> {
+    type Type = [T::Type; N];
+
+    #[inline]
+    fn read_value<R: Reader>(reader: &mut R) -> Result<Self::Type, <R as Reader>::Error> {
+        reader.read_sequence_of_array::<C, T, N>()
+    }
+}
+
+/// Creates an array by calling `f` for each element, without allocating. Stops at and returns
+/// the first error.
+pub(crate) fn try_array_from_fn<T, E, const N: usize>(
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<[T; N], E> {
+    let mut error = None;
+    let array = [(); N].map(|_| {
+        if error.is_some() {
+            return None;
+        }
+        match f() {
+            Ok(value) => Some(value),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }
+    });
+    match error {
+        Some(e) => Err(e),
+        None => Ok(array.map(|value| value.expect("every element was read"))),
+    }
+}
@@ -0,0 +1,82 @@
+//! The `Arbitrary` implementations generated with `ArbitrarySupplement::proptest().with_arbitrary()`
+
+use asn1rs::gen::rust::arbitrary::ArbitrarySupplement;
+use proptest::prelude::*;
+
+mod test_utils;
+use test_utils::*;
+
+const FUZZING: &str = r#"Fuzzing DEFINITIONS AUTOMATIC TAGS ::=
+BEGIN
+
+Kind ::= ENUMERATED { car, truck, ... }
+
+Node ::= SEQUENCE {
+    speed INTEGER (-10..250),
+    load INTEGER (0..100, ...),
+    any INTEGER,
+    kind Kind DEFAULT truck,
+    plate NumericString (SIZE(2..8)),
+    label PrintableString (SIZE(0..4, ...)),
+    note UTF8String OPTIONAL,
+    raw OCTET STRING (SIZE(1..32)),
+    flags BIT STRING (SIZE(3)),
+    marks BIT STRING (SIZE(2, ...)),
+    valid BOOLEAN,
+    kinds SEQUENCE (SIZE(1..2, ...)) OF Kind,
+    children SEQUENCE (SIZE(0..3)) OF Node,
+    next Node OPTIONAL
+}
+
+Payload ::= CHOICE {
+    node Node,
+    text IA5String,
+    data OCTET STRING (SIZE(4, ...)),
+    ...,
+    nothing NULL
+}
+
+END"#;
+
+#[allow(clippy::large_enum_variant)] // Payload holds the whole Node
+pub mod fuzzing {
+    include!("generated/arbitrary.rs");
+}
+use fuzzing::*;
+
+#[test]
+fn test_generated_code_is_up_to_date() {
+    assert_generated(
+        FUZZING,
+        &[],
+        &ArbitrarySupplement::proptest().with_arbitrary(),
+        "generated/arbitrary.rs",
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_proptest_values_roundtrip(payload in any::<Payload>()) {
+        let (bits, bytes) = serialize_uper(&payload);
+        prop_assert_eq!(payload, deserialize_uper::<Payload>(&bytes, bits));
+    }
+
+    #[test]
+    fn test_proptest_values_are_valid(node in any::<Node>()) {
+        prop_assert!((-10..=250).contains(&node.speed));
+        prop_assert!((2..=8).contains(&node.plate.len()));
+        prop_assert!(node.plate.chars().all(|c| c == ' ' || c.is_ascii_digit()));
+        prop_assert!(node.children.len() <= 3);
+        prop_assert_eq!(3, node.flags.bit_len());
+    }
+
+    #[test]
+    fn test_arbitrary_values_roundtrip(data in proptest::collection::vec(any::<u8>(), 0..512)) {
+        let mut unstructured = arbitrary::Unstructured::new(&data);
+        let payload = <Payload as arbitrary::Arbitrary>::arbitrary(&mut unstructured).unwrap();
+        let (bits, bytes) = serialize_uper(&payload);
+        prop_assert_eq!(payload, deserialize_uper::<Payload>(&bytes, bits));
+    }
+}
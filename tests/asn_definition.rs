@@ -0,0 +1,140 @@
+use asn1rs::model::{Model, Type};
+use asn1rs::parser::Tokenizer;
+use asn1rs::prelude::*;
+use asn1rs::syn::AsnModule;
+
+#[asn(sequence)]
+#[derive(Debug, Default, PartialOrd, PartialEq)]
+pub struct Potato {
+    #[asn(integer(0..255))]
+    size: u8,
+    #[asn(utf8string, tag(APPLICATION(4)))]
+    some_name: String,
+    #[asn(optional(complex(Topping, tag(UNIVERSAL(10)))))]
+    topping: Option<Topping>,
+    #[asn(sequence_of(complex(Topping, tag(UNIVERSAL(10)))))]
+    toppings: Vec<Topping>,
+}
+
+#[asn(enumerated, extensible_after(EvenLessPineapple))]
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum Topping {
+    NotPineapple,
+    EvenLessPineapple,
+    NoPineappleAtAll,
+}
+
+#[asn(choice)]
+#[derive(Debug, PartialOrd, PartialEq)]
+pub enum Dish {
+    #[asn(complex(Potato, tag(UNIVERSAL(16))))]
+    Potato(Box<Potato>),
+    #[asn(octet_string(size(4)))]
+    RawBytes(Vec<u8>),
+}
+
+#[asn(transparent)]
+#[derive(Debug, Default, PartialOrd, PartialEq)]
+pub struct Weight(#[asn(integer(0..1000))] u16);
+
+#[test]
+fn test_definitions_are_registered() {
+    assert_eq!("Potato", Potato::ASN_NAME);
+    assert_eq!(
+        r"Potato ::= SEQUENCE {
+    size INTEGER (0..255),
+    some-name [APPLICATION 4] UTF8String,
+    topping Topping OPTIONAL,
+    toppings SEQUENCE OF Topping
+}",
+        Potato::ASN_DEFINITION
+    );
+    assert_eq!(
+        r"Topping ::= ENUMERATED {
+    notPineapple,
+    evenLessPineapple,
+    ...,
+    noPineappleAtAll
+}",
+        Topping::ASN_DEFINITION
+    );
+    assert_eq!(
+        r"Dish ::= CHOICE {
+    potato Potato,
+    rawBytes OCTET STRING (SIZE(4))
+}",
+        Dish::ASN_DEFINITION
+    );
+    assert_eq!("Weight ::= INTEGER (0..1000)", Weight::ASN_DEFINITION);
+}
+
+#[test]
+fn test_module_is_printed_and_parsable() {
+    let module = AsnModule::new("Kitchen")
+        .with::<Potato>()
+        .with::<Topping>()
+        .with::<Dish>()
+        .with::<Weight>()
+        .with::<Potato>();
+
+    assert_eq!(4, module.definitions().count());
+
+    let text = module.to_string();
+    assert!(text.starts_with("Kitchen DEFINITIONS AUTOMATIC TAGS ::=\nBEGIN\n\nPotato ::= "));
+    assert!(text.ends_with("Weight ::= INTEGER (0..1000)\n\nEND\n"));
+
+    let model = Model::try_from(Tokenizer::default().parse(&text))
+        .unwrap()
+        .try_resolve()
+        .unwrap();
+    assert_eq!("Kitchen", model.name);
+    assert_eq!(
+        vec!["Potato", "Topping", "Dish", "Weight"],
+        model
+            .definitions
+            .iter()
+            .map(|definition| definition.0.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert!(matches!(model.definitions[2].1.r#type, Type::Choice(_)));
+}
+
+#[asn(sequence)]
+#[derive(Debug, Default, PartialOrd, PartialEq)]
+#[allow(non_snake_case)]
+pub struct Menu {
+    #[asn(sequence_of(complex(Dish, tag(UNIVERSAL(16)))))]
+    main__dishes: Vec<Dish>,
+    #[asn(complex(Weight, tag(UNIVERSAL(2))))]
+    total_weight_: Weight,
+}
+
+#[test]
+fn test_module_adds_referenced_definitions() {
+    let module = AsnModule::new("Restaurant").with::<Menu>();
+    assert_eq!(
+        vec![
+            Menu::ASN_DEFINITION,
+            Dish::ASN_DEFINITION,
+            Potato::ASN_DEFINITION,
+            Topping::ASN_DEFINITION,
+            Weight::ASN_DEFINITION,
+        ],
+        module.definitions().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        r"Menu ::= SEQUENCE {
+    main-dishes SEQUENCE OF Dish,
+    total-weight Weight
+}",
+        Menu::ASN_DEFINITION
+    );
+
+    let text = module.to_string();
+    assert!(text.starts_with("Restaurant DEFINITIONS AUTOMATIC TAGS ::=\nBEGIN\n\nMenu ::= "));
+    let model = Model::try_from(Tokenizer::default().parse(&text))
+        .unwrap()
+        .try_resolve()
+        .unwrap();
+    assert_eq!(5, model.definitions.len());
+}
@@ -0,0 +1,26 @@
+use asn1rs::prelude::*;
+
+asn_include!("tests/asn/include_root.asn1");
+
+#[test]
+fn test_included_module_is_reexported() {
+    let message = Message {
+        station: include_container::Station(42),
+        text: "hello".to_string(),
+    };
+
+    let mut writer = UperWriter::default();
+    writer.write(&message).unwrap();
+
+    let mut reader = writer.as_reader();
+    assert_eq!(message, reader.read::<Message>().unwrap());
+}
+
+#[test]
+fn test_modules_are_generated_as_siblings() {
+    let message = include_root::Message {
+        station: include_container::Station(7),
+        text: String::default(),
+    };
+    assert_eq!(7, message.station.0);
+}
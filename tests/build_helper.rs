@@ -0,0 +1,130 @@
+use asn1rs::build::{Builder, Error};
+
+mod test_utils;
+use test_utils::*;
+
+const ROOT: &str = r"Root DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    IMPORTS Station FROM CommonTypes;
+    Message ::= SEQUENCE {
+        station Station
+    }
+END";
+
+const COMMON: &str = r"CommonTypes DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    Station ::= INTEGER (0..255)
+END";
+
+/// Creates a fresh directory with the given files
+#[test]
+fn test_root_declares_all_modules() {
+    let asn = directory("asn", &[("root.asn1", ROOT), ("ignored.txt", "")]);
+    let common = directory("common", &[("common.asn1", COMMON)]);
+    let out = directory("out", &[]);
+
+    let root = Builder::new()
+        .include_dir(&asn)
+        .search_path(&common)
+        .derive("Hash")
+        .out_dir(&out)
+        .compile()
+        .unwrap();
+
+    assert_eq!(out.join("asn1rs.rs"), root);
+    assert_eq!(
+        format!(
+            "#[path = {:?}]\npub mod common_types;\n#[path = {:?}]\npub mod root;\n",
+            out.join("common_types.rs").to_string_lossy(),
+            out.join("root.rs").to_string_lossy(),
+        ),
+        std::fs::read_to_string(&root).unwrap()
+    );
+
+    let code = std::fs::read_to_string(out.join("root.rs")).unwrap();
+    assert!(code.contains("use super::common_types::Station;"));
+    assert!(code.contains("Hash"));
+
+    for directory in &[asn, common, out] {
+        let _ = std::fs::remove_dir_all(directory);
+    }
+}
+
+#[test]
+fn test_root_file_name_is_configurable() {
+    let asn = directory("named", &[("common.asn1", COMMON)]);
+    let root = Builder::new()
+        .file(asn.join("common.asn1"))
+        .out_dir(&asn)
+        .root_file("generated.rs")
+        .compile()
+        .unwrap();
+
+    assert_eq!(asn.join("generated.rs"), root);
+    assert!(asn.join("common_types.rs").is_file());
+
+    let _ = std::fs::remove_dir_all(&asn);
+}
+
+#[test]
+fn test_modules_are_nested_by_oid() {
+    let asn = directory(
+        "nested",
+        &[
+            (
+                "common-v1.asn1",
+                "CommonTypes { version (1) } DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Station ::= INTEGER (0..255)
+                END",
+            ),
+            (
+                "common-v2.asn1",
+                "CommonTypes { version (2) } DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    Station ::= INTEGER (0..65535)
+                END",
+            ),
+            (
+                "root.asn1",
+                "Root DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+                    IMPORTS Station FROM CommonTypes { version (2) };
+                    Message ::= SEQUENCE { station Station }
+                END",
+            ),
+        ],
+    );
+    let out = directory("nested-out", &[]);
+
+    let root = Builder::new()
+        .include_dir(&asn)
+        .out_dir(&out)
+        .compile()
+        .unwrap();
+
+    assert_eq!(
+        format!(
+            "#[path = {:?}]\npub mod root;\npub mod version_1 {{\n    #[path = {:?}]\n    pub mod common_types;\n}}\npub mod version_2 {{\n    #[path = {:?}]\n    pub mod common_types;\n}}\n",
+            out.join("root.rs").to_string_lossy(),
+            out.join("version_1/common_types.rs").to_string_lossy(),
+            out.join("version_2/common_types.rs").to_string_lossy(),
+        ),
+        std::fs::read_to_string(&root).unwrap()
+    );
+
+    let code = std::fs::read_to_string(out.join("root.rs")).unwrap();
+    assert!(code.contains("use super::version_2::common_types::Station;"));
+
+    for directory in &[asn, out] {
+        let _ = std::fs::remove_dir_all(directory);
+    }
+}
+
+#[test]
+fn test_missing_import_is_reported() {
+    let asn = directory("missing", &[("root.asn1", ROOT)]);
+    let result = Builder::new().include_dir(&asn).out_dir(&asn).compile();
+
+    match result {
+        Err(e @ Error::Converter { .. }) => assert!(e.to_string().contains("CommonTypes")),
+        r => panic!("Unexpected {:?}", r),
+    }
+
+    let _ = std::fs::remove_dir_all(&asn);
+}
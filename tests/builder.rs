@@ -0,0 +1,148 @@
+//! The code generated for a `SEQUENCE` with `RustCodeGenerator::set_builders`
+
+use asn1rs::syn::sequence::MissingFields;
+
+mod test_utils;
+use test_utils::*;
+
+#[asn(sequence)]
+#[derive(Default, Debug, Clone, PartialEq, Hash)]
+pub struct Station {
+    #[asn(integer(0..255))]
+    pub id: u8,
+    #[asn(boolean)]
+    pub mobile: bool,
+    #[asn(optional(utf8string))]
+    pub name: Option<String>,
+    #[asn(default(integer(0..7), 3))]
+    pub type_: u8,
+}
+
+/// Builder for [`Station`], see [`Station::builder`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StationBuilder {
+    id: Option<u8>,
+    mobile: Option<bool>,
+    name: Option<String>,
+    type_: u8,
+}
+
+impl StationBuilder {
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            mobile: None,
+            name: None,
+            type_: 3,
+        }
+    }
+
+    pub fn id(self, value: u8) -> Self {
+        Self {
+            id: Some(value),
+            ..self
+        }
+    }
+
+    pub fn mobile(self, value: bool) -> Self {
+        Self {
+            mobile: Some(value),
+            ..self
+        }
+    }
+
+    pub fn name(self, value: String) -> Self {
+        Self {
+            name: Some(value),
+            ..self
+        }
+    }
+
+    pub fn type_(self, value: u8) -> Self {
+        Self {
+            type_: value,
+            ..self
+        }
+    }
+
+    /// Fails if a mandatory field is not set
+    pub fn build(self) -> Result<Station, ::asn1rs::syn::sequence::MissingFields> {
+        let mut missing = Vec::new();
+        if self.id.is_none() {
+            missing.push("id");
+        }
+        if self.mobile.is_none() {
+            missing.push("mobile");
+        }
+        if let (Some(id), Some(mobile)) = (self.id, self.mobile) {
+            Ok(Station {
+                id,
+                mobile,
+                name: self.name,
+                type_: self.type_,
+            })
+        } else {
+            Err(::asn1rs::syn::sequence::MissingFields {
+                r#type: "Station",
+                fields: missing,
+            })
+        }
+    }
+}
+
+impl Default for StationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Station {
+    pub fn builder() -> StationBuilder {
+        StationBuilder::new()
+    }
+}
+
+#[test]
+fn test_builder_applies_defaults() {
+    let station = Station::builder().mobile(true).id(5).build().unwrap();
+    assert_eq!(
+        Station {
+            id: 5,
+            mobile: true,
+            name: None,
+            type_: 3,
+        },
+        station
+    );
+    let (bits, bytes) = serialize_uper(&station);
+    assert_eq!(station, deserialize_uper::<Station>(&bytes, bits));
+}
+
+#[test]
+fn test_builder_sets_optional_and_default_fields() {
+    let station = Station::builder()
+        .id(1)
+        .mobile(false)
+        .name("RSU".to_string())
+        .type_(7)
+        .build()
+        .unwrap();
+    assert_eq!(Some("RSU"), station.name.as_deref());
+    assert_eq!(7, station.type_);
+}
+
+#[test]
+fn test_builder_names_missing_fields() {
+    let error = StationBuilder::default().mobile(true).build().unwrap_err();
+    assert_eq!(
+        MissingFields {
+            r#type: "Station",
+            fields: vec!["id"],
+        },
+        error
+    );
+    assert_eq!(
+        "Unable to build Station, the mandatory field(s) id, mobile are not set",
+        Station::builder().build().unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,151 @@
+//! The code generated for `SpeedValue ::= INTEGER { standstill(0), unavailable(16383) } (0..16383)`
+//! with `RustCodeGenerator::set_checked_integers`
+
+use asn1rs::syn::numbers::OutOfRange;
+use std::convert::TryFrom;
+
+mod test_utils;
+use test_utils::*;
+
+#[asn(transparent)]
+#[derive(Default, Debug, Clone, PartialEq, Hash)]
+pub struct SpeedValue(#[asn(integer(0..16383), const(STANDSTILL(0), UNAVAILABLE(16383)))] u16);
+
+impl SpeedValue {
+    pub const MIN: Self = Self(0);
+    pub const MAX: Self = Self(16_383);
+    pub const STANDSTILL: Self = Self(0);
+    pub const UNAVAILABLE: Self = Self(16383);
+}
+
+impl SpeedValue {
+    pub const fn value_min() -> u16 {
+        0
+    }
+
+    pub const fn value_max() -> u16 {
+        16_383
+    }
+}
+
+impl SpeedValue {
+    /// Fails if the value is not within `MIN..=MAX`
+    pub const fn new(value: u16) -> Result<Self, ::asn1rs::syn::numbers::OutOfRange<u16>> {
+        if value > Self::MAX.0 {
+            Err(::asn1rs::syn::numbers::OutOfRange {
+                value,
+                min: Self::MIN.0,
+                max: Self::MAX.0,
+            })
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Does not validate the value, a value outside of `MIN..=MAX` fails to be written
+    pub const fn new_unchecked(value: u16) -> Self {
+        Self(value)
+    }
+
+    pub const fn value(&self) -> u16 {
+        self.0
+    }
+
+    /// `None` on overflow or if the result is not within `MIN..=MAX`
+    pub fn checked_add(&self, rhs: u16) -> Option<Self> {
+        self.0
+            .checked_add(rhs)
+            .and_then(|value| Self::new(value).ok())
+    }
+
+    /// Saturates at `MIN` and `MAX`
+    pub fn saturating_add(&self, rhs: u16) -> Self {
+        Self(self.0.saturating_add(rhs).clamp(Self::MIN.0, Self::MAX.0))
+    }
+
+    /// `None` on overflow or if the result is not within `MIN..=MAX`
+    pub fn checked_sub(&self, rhs: u16) -> Option<Self> {
+        self.0
+            .checked_sub(rhs)
+            .and_then(|value| Self::new(value).ok())
+    }
+
+    /// Saturates at `MIN` and `MAX`
+    pub fn saturating_sub(&self, rhs: u16) -> Self {
+        Self(self.0.saturating_sub(rhs).clamp(Self::MIN.0, Self::MAX.0))
+    }
+
+    /// `None` on overflow or if the result is not within `MIN..=MAX`
+    pub fn checked_mul(&self, rhs: u16) -> Option<Self> {
+        self.0
+            .checked_mul(rhs)
+            .and_then(|value| Self::new(value).ok())
+    }
+
+    /// Saturates at `MIN` and `MAX`
+    pub fn saturating_mul(&self, rhs: u16) -> Self {
+        Self(self.0.saturating_mul(rhs).clamp(Self::MIN.0, Self::MAX.0))
+    }
+}
+
+impl ::core::ops::Deref for SpeedValue {
+    type Target = u16;
+
+    fn deref(&self) -> &u16 {
+        &self.0
+    }
+}
+
+impl ::core::convert::TryFrom<u16> for SpeedValue {
+    type Error = ::asn1rs::syn::numbers::OutOfRange<u16>;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl ::core::convert::From<SpeedValue> for u16 {
+    fn from(value: SpeedValue) -> Self {
+        value.0
+    }
+}
+
+pub const SLOW: SpeedValue = SpeedValue::new_unchecked(5);
+
+#[test]
+fn test_values_are_range_checked() {
+    assert_eq!(Ok(SpeedValue::UNAVAILABLE), SpeedValue::try_from(16383));
+    assert_eq!(
+        Err(OutOfRange {
+            value: 16384,
+            min: 0,
+            max: 16383
+        }),
+        SpeedValue::new(16384)
+    );
+    assert_eq!(
+        "The value 16384 is not in the range 0..=16383",
+        SpeedValue::new(16384).unwrap_err().to_string()
+    );
+    assert_eq!(5, *SLOW);
+    assert_eq!(5_u16, u16::from(SLOW));
+}
+
+#[test]
+fn test_arithmetic_stays_in_range() {
+    let speed = SpeedValue::new(16000).unwrap();
+    assert_eq!(Some(SpeedValue::MAX), speed.checked_add(383));
+    assert_eq!(None, speed.checked_add(384));
+    assert_eq!(None, SpeedValue::STANDSTILL.checked_sub(1));
+    assert_eq!(SpeedValue::MAX, speed.saturating_mul(2));
+    assert_eq!(SpeedValue::MIN, SLOW.saturating_sub(6));
+    assert_eq!(16100, speed.saturating_add(100).value());
+}
+
+#[test]
+fn test_checked_newtype_roundtrip() {
+    let speed = SpeedValue::new(1234).unwrap();
+    let (bits, bytes) = serialize_uper(&speed);
+    assert_eq!(14, bits);
+    assert_eq!(speed, deserialize_uper::<SpeedValue>(&bytes, bits));
+}
@@ -0,0 +1,18 @@
+use asn1rs::prelude::*;
+
+asn_include!("tests/asn/colliding_root.asn1");
+
+#[test]
+fn test_colliding_imports_are_qualified() {
+    let report = Report {
+        station: colliding_station::StationId(42),
+        vehicle: colliding_vehicle::StationId(4242),
+        history: vec![colliding_vehicle::StationId(1)],
+    };
+
+    let mut writer = UperWriter::default();
+    writer.write(&report).unwrap();
+
+    let mut reader = writer.as_reader();
+    assert_eq!(report, reader.read::<Report>().unwrap());
+}
@@ -0,0 +1,35 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"ComponentsOf DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Base ::= SEQUENCE {
+        abc INTEGER (0..255),
+        def BOOLEAN,
+        ...,
+        ghi UTF8String
+    }
+
+    Extended ::= SEQUENCE {
+        first BOOLEAN,
+        COMPONENTS OF Base
+    }
+
+    END"
+);
+
+#[test]
+pub fn test_components_are_inlined() {
+    serialize_and_deserialize_uper(
+        10,
+        &[0x82, 0x80],
+        &Extended {
+            first: true,
+            abc: 5,
+            def: false,
+        },
+    );
+}
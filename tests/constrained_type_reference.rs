@@ -0,0 +1,88 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r#"ConstrainedTypeReference DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Name ::= UTF8String (SIZE(1..32))
+
+    ShortName ::= Name (SIZE(1..8))
+
+    Percent ::= INTEGER (0..100)
+
+    Small ::= Percent (0..7)
+
+    Code ::= IA5String (SIZE(1..8))
+
+    Hex ::= Code (FROM("0".."9" | "A".."F"))
+
+    Upper ::= Name (FROM("A".."Z"))
+
+    Entry ::= SEQUENCE {
+        name ShortName,
+        value Small,
+        other Percent (10..13) OPTIONAL
+    }
+
+    END"#
+);
+
+#[test]
+pub fn test_constraints_are_applied() {
+    assert_eq!(0, Small::value_min());
+    assert_eq!(7, Small::value_max());
+    assert_eq!(0, Percent::value_min());
+    assert_eq!(100, Percent::value_max());
+
+    let mut writer = UperWriter::default();
+    assert!(writer.write(&ShortName("too long!".to_string())).is_err());
+    assert!(writer.write(&Name("too long!".to_string())).is_ok());
+}
+
+#[test]
+pub fn test_entry() {
+    serialize_and_deserialize_uper(
+        36,
+        &[0x01, 0xB0, 0xB1, 0x31, 0xD0],
+        &Entry {
+            name: ShortName("abc".to_string()),
+            value: Small(5),
+            other: None,
+        },
+    );
+}
+
+#[test]
+pub fn test_entry_with_inline_constrained_reference() {
+    serialize_and_deserialize_uper(
+        38,
+        &[0x81, 0xB0, 0xB1, 0x31, 0xD8],
+        &Entry {
+            name: ShortName("abc".to_string()),
+            value: Small(5),
+            other: Some(12),
+        },
+    );
+}
+
+#[test]
+pub fn test_permitted_alphabet_reduces_the_bits_per_character() {
+    // 3 bits for the length and 4 bits for the index of each character in "0123456789ABCDEF"
+    serialize_and_deserialize_uper(11, &[0x23, 0xE0], &Hex("1F".to_string()));
+}
+
+#[test]
+pub fn test_permitted_alphabet_is_checked() {
+    let mut writer = UperWriter::default();
+    assert_eq!(
+        Err(asn1rs::io::per::ErrorKind::NotInPermittedAlphabet('G', 1).into()),
+        writer.write(&Hex("1G".to_string()))
+    );
+    assert_eq!(
+        Err(asn1rs::io::per::ErrorKind::NotInPermittedAlphabet('b', 1).into()),
+        writer.write(&Upper("Ab".to_string()))
+    );
+    assert!(writer.write(&Upper("AB".to_string())).is_ok());
+}
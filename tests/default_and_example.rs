@@ -0,0 +1,57 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r#"DefaultAndExample DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Percent ::= INTEGER (1..100)
+
+    Kind ::= ENUMERATED { car, truck }
+
+    Vehicle ::= SEQUENCE {
+        speed INTEGER (-10..250),
+        load Percent OPTIONAL,
+        kind Kind DEFAULT truck,
+        plate NumericString (SIZE(2..8)),
+        axles SEQUENCE (SIZE(2..4)) OF Percent,
+        flags BIT STRING (SIZE(3)),
+        trailer Vehicle OPTIONAL
+    }
+
+    Payload ::= CHOICE {
+        vehicle Vehicle,
+        raw OCTET STRING
+    }
+
+    END"#
+);
+
+#[test]
+fn test_default_is_the_smallest_valid_value() {
+    let vehicle = Vehicle::default();
+    assert_eq!(-10, vehicle.speed);
+    assert_eq!(None, vehicle.load);
+    assert_eq!(Kind::Truck, vehicle.kind);
+    assert_eq!("00", vehicle.plate);
+    assert_eq!(vec![Percent(1), Percent(1)], vehicle.axles);
+    assert_eq!(3, vehicle.flags.bit_len());
+    assert_eq!(None, vehicle.trailer);
+
+    let (bits, bytes) = serialize_uper(&vehicle);
+    assert_eq!(vehicle, deserialize_uper::<Vehicle>(&bytes, bits));
+}
+
+#[test]
+fn test_example_sets_optional_fields() {
+    let vehicle = Vehicle::example();
+    assert_eq!(Some(Percent(1)), vehicle.load);
+    assert_eq!(None, vehicle.trailer);
+
+    let payload = Payload::example();
+    assert_eq!(Payload::Vehicle(vehicle), payload);
+
+    let (bits, bytes) = serialize_uper(&payload);
+    assert_eq!(payload, deserialize_uper::<Payload>(&bytes, bits));
+}
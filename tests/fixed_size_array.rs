@@ -0,0 +1,165 @@
+use asn1rs::io::per::err::ErrorKind;
+use asn1rs::syn::octetstring::NoConstraint as NoOctetStringConstraint;
+use asn1rs::syn::sequenceof::NoConstraint as NoSequenceOfConstraint;
+use asn1rs::syn::{FixedOctetString, FixedSequenceOf, Integer, OctetString, SequenceOf};
+
+mod test_utils;
+use test_utils::*;
+
+#[asn(sequence)]
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct Frame {
+    #[asn(octet_string(size(8)))]
+    id: [u8; 8],
+    #[asn(optional(octet_string(size(2))))]
+    checksum: Option<[u8; 2]>,
+    #[asn(sequence_of(size(3), integer(-100..100)))]
+    position: [i8; 3],
+    #[asn(sequence_of(size(2), octet_string(size(2))))]
+    pairs: [[u8; 2]; 2],
+    #[asn(sequence_of(octet_string(size(4))))]
+    addresses: Vec<[u8; 4]>,
+}
+
+#[asn(sequence)]
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct PlainFrame {
+    #[asn(octet_string(size(8)))]
+    id: Vec<u8>,
+    #[asn(optional(octet_string(size(2))))]
+    checksum: Option<Vec<u8>>,
+    #[asn(sequence_of(size(3), integer(-100..100)))]
+    position: Vec<i8>,
+    #[asn(sequence_of(size(2), octet_string(size(2))))]
+    pairs: Vec<Vec<u8>>,
+    #[asn(sequence_of(octet_string(size(4))))]
+    addresses: Vec<Vec<u8>>,
+}
+
+#[asn(transparent)]
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct Digest(#[asn(octet_string(size(4)))] [u8; 4]);
+
+#[asn(choice)]
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum Key {
+    #[asn(octet_string(size(2)))]
+    Short([u8; 2]),
+    #[asn(sequence_of(size(2), complex(Digest, tag(UNIVERSAL(4)))))]
+    Long([Digest; 2]),
+}
+
+fn frame() -> Frame {
+    Frame {
+        id: [1, 2, 3, 4, 5, 6, 7, 8],
+        checksum: Some([0xAB, 0xCD]),
+        position: [-100, 0, 99],
+        pairs: [[1, 2], [3, 4]],
+        addresses: vec![[192, 168, 0, 1], [10, 0, 0, 1]],
+    }
+}
+
+#[test]
+fn test_arrays_are_encoded_like_vecs() {
+    let plain = PlainFrame {
+        id: vec![1, 2, 3, 4, 5, 6, 7, 8],
+        checksum: Some(vec![0xAB, 0xCD]),
+        position: vec![-100, 0, 99],
+        pairs: vec![vec![1, 2], vec![3, 4]],
+        addresses: vec![vec![192, 168, 0, 1], vec![10, 0, 0, 1]],
+    };
+    let frame = frame();
+    assert_eq!(serialize_uper(&plain), serialize_uper(&frame));
+    let (bits, bytes) = serialize_uper(&plain);
+    assert_eq!(frame, deserialize_uper::<Frame>(&bytes, bits));
+}
+
+#[test]
+fn test_arrays_in_transparent_and_choice() {
+    let digest = Digest([0xDE, 0xAD, 0xBE, 0xEF]);
+    let (bits, bytes) = serialize_uper(&digest);
+    assert_eq!((32, vec![0xDE, 0xAD, 0xBE, 0xEF]), (bits, bytes.clone()));
+    assert_eq!(digest, deserialize_uper::<Digest>(&bytes, bits));
+
+    for key in [
+        Key::Short([1, 2]),
+        Key::Long([digest.clone(), Digest([1; 4])]),
+    ] {
+        let (bits, bytes) = serialize_uper(&key);
+        assert_eq!(key, deserialize_uper::<Key>(&bytes, bits));
+    }
+}
+
+#[test]
+fn test_wrong_octet_string_size_is_rejected() {
+    let mut writer = UperWriter::default();
+    writer
+        .write_octet_string::<NoOctetStringConstraint>(&[1, 2, 3])
+        .unwrap();
+    let mut reader = writer.as_reader();
+    let error = reader
+        .read_octet_string_array::<NoOctetStringConstraint, 2>()
+        .unwrap_err();
+    assert_eq!(&ErrorKind::SizeNotInRange(3, 2, 2), error.kind());
+
+    let mut writer = UperWriter::default();
+    writer
+        .write_octet_string::<NoOctetStringConstraint>(&[1, 2])
+        .unwrap();
+    assert_eq!(
+        [1, 2],
+        writer
+            .as_reader()
+            .read_octet_string_array::<NoOctetStringConstraint, 2>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_wrong_sequence_of_size_is_rejected() {
+    type Element = Integer<u8>;
+    let mut writer = UperWriter::default();
+    SequenceOf::<Element, NoSequenceOfConstraint>::write_value(&mut writer, &vec![1, 2, 3])
+        .unwrap();
+    let mut reader = writer.as_reader();
+    let error =
+        FixedSequenceOf::<Element, 2, NoSequenceOfConstraint>::read_value(&mut reader).unwrap_err();
+    assert_eq!(&ErrorKind::SizeNotInRange(3, 2, 2), error.kind());
+
+    let mut writer = UperWriter::default();
+    OctetString::<NoOctetStringConstraint>::write_value(&mut writer, &vec![7; 3]).unwrap();
+    assert_eq!(
+        [7; 3],
+        FixedOctetString::<3, NoOctetStringConstraint>::read_value(&mut writer.as_reader())
+            .unwrap()
+    );
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_arrays_protobuf() {
+    let frame = frame();
+    let bytes = serialize_protobuf(&frame);
+    assert_eq!(frame, deserialize_protobuf::<Frame>(&bytes));
+}
+
+#[test]
+#[cfg(feature = "protobuf")]
+fn test_wrong_sequence_of_size_is_rejected_protobuf() {
+    let plain = PlainFrame {
+        id: vec![1; 8],
+        checksum: None,
+        position: vec![1, 2],
+        pairs: vec![vec![1, 2], vec![3, 4]],
+        addresses: Vec::default(),
+    };
+    let bytes = serialize_protobuf(&plain);
+    let error = ProtobufReader::from(&bytes[..])
+        .read::<Frame>()
+        .unwrap_err();
+    assert!(
+        matches!(error, asn1rs::io::protobuf::Error::UnexpectedSize(_, 3, 2)),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,212 @@
+use asn1rs::prelude::*;
+
+#[asn(enumerated, extensible_after(Truck))]
+
+#[derive(Debug, Clone, PartialEq, Hash, Copy, PartialOrd, Eq, Default)]
+pub enum Kind {
+    #[default] Car,
+    Truck,
+}
+
+impl Kind {
+    pub fn variant(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Kind::Car),
+            1 => Some(Kind::Truck),
+            _ => None,
+        }
+    }
+
+    pub const fn variants() -> [Self; 2] {
+        [
+        Kind::Car,
+        Kind::Truck,
+        ]
+    }
+
+    pub fn value_index(self) -> usize {
+        match self {
+            Kind::Car => 0,
+            Kind::Truck => 1,
+        }
+    }
+}
+
+impl Kind {
+    /// A valid value with all optional fields set
+    pub fn example() -> Self {
+        Self::Car
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for Kind {
+    type Parameters = u32;
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(_depth: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy;
+        ::proptest::sample::select(Self::variants().to_vec()).boxed()
+    }
+}
+
+impl<'a> ::arbitrary::Arbitrary<'a> for Kind {
+    fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        Ok(*u.choose(&Self::variants())?)
+    }
+}
+
+#[asn(sequence)]
+
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct Node {
+    #[asn(integer(-10..250))] pub speed: i16,
+    #[asn(integer(0..100,...))] pub load: u64,
+    #[asn(integer(min..max))] pub any: u64,
+    #[asn(default(complex(Kind, tag(UNIVERSAL(10))), Kind::Truck))] pub kind: Kind,
+    #[asn(numericstring(size(2..8)))] pub plate: String,
+    #[asn(printablestring(size(0..4,...)))] pub label: String,
+    #[asn(optional(utf8string))] pub note: Option<String>,
+    #[asn(octet_string(size(1..32)))] pub raw: Vec<u8>,
+    #[asn(bit_string(size(3)))] pub flags: BitVec,
+    #[asn(bit_string(size(2,...)))] pub marks: BitVec,
+    #[asn(boolean)] pub valid: bool,
+    #[asn(sequence_of(size(1..2,...), complex(Kind, tag(UNIVERSAL(10)))))] pub kinds: Vec<Kind>,
+    #[asn(sequence_of(size(0..3), complex(Node, tag(UNIVERSAL(16)))))] pub children: Vec<Node>,
+    #[asn(optional(complex(Node, tag(UNIVERSAL(16)))))] pub next: Option<Box<Node>>,
+}
+
+impl Node {
+    pub const fn speed_min() -> i16 {
+        -10
+    }
+
+    pub const fn speed_max() -> i16 {
+        250
+    }
+
+    pub const fn load_min() -> u64 {
+        0
+    }
+
+    pub const fn load_max() -> u64 {
+        100
+    }
+
+    pub const fn any_min() -> u64 {
+        0
+    }
+
+    pub const fn any_max() -> u64 {
+        9_223_372_036_854_775_807
+    }
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self { speed: -10, load: 0, any: 0, kind: Kind::Truck, plate: String::from("00"), label: String::new(), note: None, raw: vec![0; 1], flags: BitVec::with_len(3), marks: BitVec::with_len(2), valid: false, kinds: vec![Default::default(); 1], children: Vec::new(), next: None }
+    }
+}
+
+impl Node {
+    /// A valid value with all optional fields set
+    pub fn example() -> Self {
+        Self { speed: -10, load: 0, any: 0, kind: Kind::Truck, plate: String::from("00"), label: String::new(), note: Some(String::new()), raw: vec![0; 1], flags: BitVec::with_len(3), marks: BitVec::with_len(2), valid: false, kinds: vec![Kind::example(); 1], children: Vec::new(), next: None }
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for Node {
+    type Parameters = u32;
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(depth: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy;
+        let nested = depth < 8;
+        ((-10_i16..=250_i16, ::proptest::strategy::Union::new(vec![0_u64..=100_u64, 0_u64..=9223372036854775807_u64]), 0_u64..=9223372036854775807_u64, ::proptest::arbitrary::any_with::<Kind>(depth + 1), ::proptest::string::string_regex("[ 0-9]{2,8}").unwrap(), ::proptest::strategy::Union::new(vec![::proptest::string::string_regex("[ '()+,\\-./0-9:=?A-Za-z]{0,4}").unwrap(), ::proptest::string::string_regex("[ '()+,\\-./0-9:=?A-Za-z]{0,20}").unwrap()]), if nested { ::proptest::option::of(::proptest::string::string_regex(".{0,16}").unwrap()).boxed() } else { ::proptest::strategy::Just(None).boxed() }, ::proptest::collection::vec(::proptest::arbitrary::any::<u8>(), 1..=32), ::proptest::collection::vec(::proptest::arbitrary::any::<bool>(), 3..=3).prop_map(|bits| bits.into_iter().collect::<BitVec>()), ::proptest::strategy::Union::new(vec![::proptest::collection::vec(::proptest::arbitrary::any::<bool>(), 2..=2), ::proptest::collection::vec(::proptest::arbitrary::any::<bool>(), 0..=18)]).prop_map(|bits| bits.into_iter().collect::<BitVec>()),), (::proptest::arbitrary::any::<bool>(), ::proptest::strategy::Union::new(vec![::proptest::collection::vec(::proptest::arbitrary::any_with::<Kind>(depth + 1), 1..=if nested { 2 } else { 1 }), ::proptest::collection::vec(::proptest::arbitrary::any_with::<Kind>(depth + 1), 0..=if nested { 18 } else { 0 })]), if nested { ::proptest::collection::vec(::proptest::arbitrary::any_with::<Node>(depth + 1), 0..=3).boxed() } else { ::proptest::strategy::Just(Vec::new()).boxed() }, if nested { ::proptest::option::of(::proptest::arbitrary::any_with::<Node>(depth + 1).prop_map(Box::new)).boxed() } else { ::proptest::strategy::Just(None).boxed() },),).prop_map(|((speed, load, any, kind, plate, label, note, raw, flags, marks,), (valid, kinds, children, next,),)| Self { speed, load, any, kind, plate, label, note, raw, flags, marks, valid, kinds, children, next }).boxed()
+    }
+}
+
+impl<'a> ::arbitrary::Arbitrary<'a> for Node {
+    fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        Ok(Self {
+            speed: u.int_in_range(-10_i16..=250_i16)?,
+            load: { let range = if u.arbitrary::<bool>()? { 0_u64..=100_u64 } else { 0_u64..=9223372036854775807_u64 }; u.int_in_range(range)? },
+            any: u.int_in_range(0_u64..=9223372036854775807_u64)?,
+            kind: u.arbitrary::<Kind>()?,
+            plate: { let len = u.int_in_range(2_usize..=8)?; (0..len).map(|_| u.choose(b" 0123456789").map(|c| char::from(*c))).collect::<::arbitrary::Result<String>>()? },
+            label: { let len = { let range = if u.arbitrary::<bool>()? { 0_usize..=4 } else { 0_usize..=20 }; u.int_in_range(range)? }; (0..len).map(|_| u.choose(b" '()+,-./0123456789:=?ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz").map(|c| char::from(*c))).collect::<::arbitrary::Result<String>>()? },
+            note: if u.arbitrary::<bool>()? { Some({ let len = u.int_in_range(0_usize..=16)?; (0..len).map(|_| u.arbitrary::<char>()).collect::<::arbitrary::Result<String>>()? }) } else { None },
+            raw: { let len = u.int_in_range(1_usize..=32)?; (0..len).map(|_| u.arbitrary::<u8>()).collect::<::arbitrary::Result<Vec<u8>>>()? },
+            flags: { let len = u.int_in_range(3_usize..=3)?; (0..len).map(|_| u.arbitrary::<bool>()).collect::<::arbitrary::Result<BitVec>>()? },
+            marks: { let len = { let range = if u.arbitrary::<bool>()? { 2_usize..=2 } else { 0_usize..=18 }; u.int_in_range(range)? }; (0..len).map(|_| u.arbitrary::<bool>()).collect::<::arbitrary::Result<BitVec>>()? },
+            valid: u.arbitrary::<bool>()?,
+            kinds: { let len = { let range = if u.arbitrary::<bool>()? { 1_usize..=2 } else { 0_usize..=18 }; u.int_in_range(range)? }; (0..len).map(|_| u.arbitrary::<Kind>()).collect::<::arbitrary::Result<Vec<_>>>()? },
+            children: { let len = u.int_in_range(0_usize..=3)?; (0..len).map(|_| u.arbitrary::<Node>()).collect::<::arbitrary::Result<Vec<_>>>()? },
+            next: if u.arbitrary::<bool>()? { Some(Box::new(u.arbitrary::<Node>()?)) } else { None },
+        })
+    }
+}
+
+#[asn(choice, extensible_after(Data))]
+
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum Payload {
+    #[asn(complex(Node, tag(UNIVERSAL(16))))] Node(Node),
+    #[asn(ia5string)] Text(String),
+    #[asn(octet_string(size(4,...)))] Data(Vec<u8>),
+    #[asn(null)] Nothing(Null),
+}
+
+impl Payload {
+    pub fn variants() -> [Self; 4] {
+        [
+        Payload::Node(Default::default()),
+        Payload::Text(Default::default()),
+        Payload::Data(Default::default()),
+        Payload::Nothing(Default::default()),
+        ]
+    }
+
+    pub fn value_index(&self) -> usize {
+        match self {
+            Payload::Node(_) => 0,
+            Payload::Text(_) => 1,
+            Payload::Data(_) => 2,
+            Payload::Nothing(_) => 3,
+        }
+    }
+}
+
+impl Default for Payload {
+    fn default() -> Self {
+        Self::Node(Default::default())
+    }
+}
+
+impl Payload {
+    /// A valid value with all optional fields set
+    pub fn example() -> Self {
+        Self::Node(Node::example())
+    }
+}
+
+impl ::proptest::arbitrary::Arbitrary for Payload {
+    type Parameters = u32;
+    type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with(depth: Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy;
+        let nested = depth < 8;
+        ::proptest::strategy::Union::new(if nested { vec![::proptest::arbitrary::any_with::<Node>(depth + 1).prop_map(Self::Node).boxed(), ::proptest::string::string_regex("[\\x00-\\x7F]{0,16}").unwrap().prop_map(Self::Text).boxed(), ::proptest::strategy::Union::new(vec![::proptest::collection::vec(::proptest::arbitrary::any::<u8>(), 4..=4), ::proptest::collection::vec(::proptest::arbitrary::any::<u8>(), 0..=20)]).prop_map(Self::Data).boxed(), ::proptest::strategy::Just(Null).prop_map(Self::Nothing).boxed()] } else { vec![::proptest::string::string_regex("[\\x00-\\x7F]{0,16}").unwrap().prop_map(Self::Text).boxed(), ::proptest::strategy::Union::new(vec![::proptest::collection::vec(::proptest::arbitrary::any::<u8>(), 4..=4), ::proptest::collection::vec(::proptest::arbitrary::any::<u8>(), 0..=20)]).prop_map(Self::Data).boxed(), ::proptest::strategy::Just(Null).prop_map(Self::Nothing).boxed()] }).boxed()
+    }
+}
+
+impl<'a> ::arbitrary::Arbitrary<'a> for Payload {
+    fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+        match u.int_in_range(0..=3)? {
+            0 => Ok(Self::Node(u.arbitrary::<Node>()?)),
+            1 => Ok(Self::Text({ let len = u.int_in_range(0_usize..=16)?; (0..len).map(|_| u.int_in_range(0x00..=0x7F_u8).map(char::from)).collect::<::arbitrary::Result<String>>()? })),
+            2 => Ok(Self::Data({ let len = { let range = if u.arbitrary::<bool>()? { 4_usize..=4 } else { 0_usize..=20 }; u.int_in_range(range)? }; (0..len).map(|_| u.arbitrary::<u8>()).collect::<::arbitrary::Result<Vec<u8>>>()? })),
+            _ => Ok(Self::Nothing(Null)),
+        }
+    }
+}
\ No newline at end of file
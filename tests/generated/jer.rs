@@ -0,0 +1,165 @@
+use asn1rs::prelude::*;
+
+#[asn(enumerated)]
+
+#[derive(Debug, Clone, PartialEq, Hash, Copy, PartialOrd, Eq, Serialize, Deserialize, Default)]
+pub enum Kind {
+    #[default] #[serde(rename = "passenger-car")] PassengerCar,
+    #[serde(rename = "truck")] Truck,
+}
+
+impl Kind {
+    pub fn variant(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Kind::PassengerCar),
+            1 => Some(Kind::Truck),
+            _ => None,
+        }
+    }
+
+    pub const fn variants() -> [Self; 2] {
+        [
+        Kind::PassengerCar,
+        Kind::Truck,
+        ]
+    }
+
+    pub fn value_index(self) -> usize {
+        match self {
+            Kind::PassengerCar => 0,
+            Kind::Truck => 1,
+        }
+    }
+}
+
+impl Kind {
+    /// A valid value with all optional fields set
+    pub fn example() -> Self {
+        Self::PassengerCar
+    }
+}
+
+#[asn(transparent)]
+
+#[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Mac(#[asn(octet_string(size(6)))] #[serde(with = "::asn1rs::io::jer::octet_string")] pub Vec<u8>);
+
+impl Mac {
+}
+
+impl Mac {
+    pub const fn new(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl ::core::ops::Deref for Mac {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        &self.0
+    }
+}
+
+impl ::core::ops::DerefMut for Mac {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.0
+    }
+}
+
+impl ::core::convert::From<Vec<u8>> for Mac {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl ::core::convert::From<Mac> for Vec<u8> {
+    fn from(value: Mac) -> Self {
+        value.0
+    }
+}
+
+impl Default for Mac {
+    fn default() -> Self {
+        Self(vec![0; 6])
+    }
+}
+
+impl Mac {
+    /// A valid value with all optional fields set
+    pub fn example() -> Self {
+        Self(vec![0; 6])
+    }
+}
+
+#[asn(sequence, extensible_after(flags))]
+
+#[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Vehicle {
+    #[asn(integer(0..255))] #[serde(rename = "vehicleId")] pub vehicle_id: u8,
+    #[asn(complex(Kind, tag(UNIVERSAL(10))))] #[serde(rename = "type")] pub type_: Kind,
+    #[asn(complex(Mac, tag(UNIVERSAL(4))))] pub mac: Mac,
+    #[asn(optional(octet_string))] #[serde(rename = "serial-number", default, skip_serializing_if = "Option::is_none", with = "::asn1rs::io::jer::octet_string::option")] pub serial_number: Option<Vec<u8>>,
+    #[asn(bit_string(size(4)))] #[serde(serialize_with = "::asn1rs::io::jer::bit_string::fixed::serialize", deserialize_with = "::asn1rs::io::jer::bit_string::fixed::deserialize::<_, 4>")] pub flags: BitVec,
+    #[asn(optional(bit_string()))] #[serde(default, skip_serializing_if = "Option::is_none", with = "::asn1rs::io::jer::bit_string::option")] pub lights: Option<BitVec>,
+}
+
+impl Vehicle {
+    pub const fn vehicle_id_min() -> u8 {
+        0
+    }
+
+    pub const fn vehicle_id_max() -> u8 {
+        255
+    }
+}
+
+impl Default for Vehicle {
+    fn default() -> Self {
+        Self { vehicle_id: 0, type_: Default::default(), mac: Default::default(), serial_number: None, flags: BitVec::with_len(4), lights: None }
+    }
+}
+
+impl Vehicle {
+    /// A valid value with all optional fields set
+    pub fn example() -> Self {
+        Self { vehicle_id: 0, type_: Kind::example(), mac: Mac::example(), serial_number: Some(Vec::new()), flags: BitVec::with_len(4), lights: Some(BitVec::with_len(0)) }
+    }
+}
+
+#[asn(choice)]
+
+#[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Payload {
+    #[asn(complex(Vehicle, tag(UNIVERSAL(16))))] #[serde(rename = "vehicle")] Vehicle(Vehicle),
+    #[asn(octet_string)] #[serde(rename = "raw-data", with = "::asn1rs::io::jer::octet_string")] RawData(Vec<u8>),
+}
+
+impl Payload {
+    pub fn variants() -> [Self; 2] {
+        [
+        Payload::Vehicle(Default::default()),
+        Payload::RawData(Default::default()),
+        ]
+    }
+
+    pub fn value_index(&self) -> usize {
+        match self {
+            Payload::Vehicle(_) => 0,
+            Payload::RawData(_) => 1,
+        }
+    }
+}
+
+impl Default for Payload {
+    fn default() -> Self {
+        Self::Vehicle(Default::default())
+    }
+}
+
+impl Payload {
+    /// A valid value with all optional fields set
+    pub fn example() -> Self {
+        Self::Vehicle(Vehicle::example())
+    }
+}
\ No newline at end of file
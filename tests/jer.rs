@@ -0,0 +1,119 @@
+//! The code generated with the `SerdeSupplement` and the global derives `Serialize` and
+//! `Deserialize`, which is (de-)serialized as described by the JSON Encoding Rules
+
+use asn1rs::gen::rust::serde::SerdeSupplement;
+
+mod test_utils;
+use test_utils::*;
+
+const JER: &str = r#"Jer DEFINITIONS AUTOMATIC TAGS ::=
+BEGIN
+
+Kind ::= ENUMERATED { passenger-car, truck }
+
+Mac ::= OCTET STRING (SIZE(6))
+
+Vehicle ::= SEQUENCE {
+    vehicleId INTEGER (0..255),
+    type Kind,
+    mac Mac,
+    serial-number OCTET STRING OPTIONAL,
+    flags BIT STRING (SIZE(4)),
+    ...,
+    lights BIT STRING OPTIONAL
+}
+
+Payload ::= CHOICE {
+    vehicle Vehicle,
+    raw-data OCTET STRING
+}
+
+END"#;
+
+pub mod jer {
+    use serde_derive::{Deserialize, Serialize};
+
+    include!("generated/jer.rs");
+}
+use jer::*;
+
+#[test]
+fn test_generated_code_is_up_to_date() {
+    assert_generated(
+        JER,
+        &["Serialize", "Deserialize"],
+        &SerdeSupplement,
+        "generated/jer.rs",
+    );
+}
+
+fn vehicle() -> Vehicle {
+    Vehicle {
+        vehicle_id: 42,
+        type_: Kind::PassengerCar,
+        mac: Mac(vec![0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]),
+        serial_number: None,
+        flags: BitVec::from_bytes(vec![0b1010_0000], 4),
+        lights: None,
+    }
+}
+
+#[test]
+fn test_sequence_with_absent_optional_fields() {
+    let json = serde_json::to_string(&vehicle()).unwrap();
+    assert_eq!(
+        r#"{"vehicleId":42,"type":"passenger-car","mac":"001A2B3C4D5E","flags":"A0"}"#,
+        json
+    );
+    assert_eq!(vehicle(), serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn test_sequence_with_present_optional_fields() {
+    let vehicle = Vehicle {
+        type_: Kind::Truck,
+        serial_number: Some(vec![0xFF, 0x01]),
+        lights: Some(BitVec::from_bytes(vec![0b1100_0000], 2)),
+        ..vehicle()
+    };
+    let json = serde_json::to_string(&vehicle).unwrap();
+    assert_eq!(
+        r#"{"vehicleId":42,"type":"truck","mac":"001A2B3C4D5E","serial-number":"FF01","flags":"A0","lights":{"value":"C0","length":2}}"#,
+        json
+    );
+    assert_eq!(vehicle, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn test_choice_is_externally_tagged() {
+    let payload = Payload::RawData(vec![0xCA, 0xFE]);
+    let json = serde_json::to_string(&payload).unwrap();
+    assert_eq!(r#"{"raw-data":"CAFE"}"#, json);
+    assert_eq!(payload, serde_json::from_str(&json).unwrap());
+
+    let payload = Payload::Vehicle(vehicle());
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(json.starts_with(r#"{"vehicle":{"vehicleId":42,"#));
+    assert_eq!(payload, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn test_invalid_hex_strings_are_rejected() {
+    assert!(serde_json::from_str::<Payload>(r#"{"raw-data":"CAF"}"#).is_err());
+    assert!(serde_json::from_str::<Payload>(r#"{"raw-data":"XY"}"#).is_err());
+    assert!(serde_json::from_str::<Vehicle>(
+        r#"{"vehicleId":42,"type":"truck","mac":"001A2B3C4D5E","flags":"A0A0"}"#
+    )
+    .is_err());
+    assert!(serde_json::from_str::<Vehicle>(
+        r#"{"vehicleId":42,"type":"truck","mac":"001A2B3C4D5E","flags":{"value":"A0","length":4}}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn test_uper_is_not_affected() {
+    let payload = Payload::Vehicle(vehicle());
+    let (bits, bytes) = serialize_uper(&payload);
+    assert_eq!(payload, deserialize_uper::<Payload>(&bytes, bits));
+}
@@ -0,0 +1,65 @@
+use asn1rs::converter::Converter;
+use asn1rs::model::{Asn, Model, Rust};
+use asn1rs::parser::Tokenizer;
+
+mod test_utils;
+use test_utils::*;
+
+const MODULE: &str = r"Json { iso(1) 2 } DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    -- The color of something
+    Color ::= ENUMERATED { red, green, ..., blue }
+
+    Item ::= SEQUENCE {
+        name UTF8String (SIZE(1..32)),
+        amount INTEGER (0..max-amount) DEFAULT 1,
+        color Color OPTIONAL,
+        data CHOICE {
+            raw [5] OCTET STRING,
+            bits BIT STRING (SIZE(8)),
+            ...
+        }
+    }
+
+    Items ::= SEQUENCE (SIZE(0..16)) OF Item
+
+    max-amount INTEGER ::= 255
+END";
+
+fn resolved() -> Model<Asn> {
+    Model::try_from(Tokenizer::default().parse_with_comments(MODULE))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+}
+
+#[test]
+fn test_asn_model_json_round_trip() {
+    let model = resolved();
+    let json = serde_json::to_string(&model).unwrap();
+    let parsed: Model<Asn> = serde_json::from_str(&json).unwrap();
+    assert_eq!(model, parsed);
+}
+
+#[test]
+fn test_rust_model_json_round_trip() {
+    let model = resolved().to_rust();
+    let json = serde_json::to_string(&model).unwrap();
+    let parsed: Model<Rust> = serde_json::from_str(&json).unwrap();
+    assert_eq!(model, parsed);
+}
+
+#[test]
+fn test_converter_dumps_resolved_model() {
+    let directory = directory("model-json", &[("json.asn1", MODULE)]);
+
+    let mut converter = Converter::default();
+    converter.load_file(directory.join("json.asn1")).unwrap();
+    let files = converter.to_json(&directory).unwrap();
+    assert_eq!(Some(&vec!["Json.json".to_string()]), files.get("Json"));
+
+    let json = std::fs::read_to_string(directory.join("Json.json")).unwrap();
+    let dumped: Model<Asn> = serde_json::from_str(&json).unwrap();
+    assert_eq!(resolved(), dumped);
+
+    let _ = std::fs::remove_dir_all(&directory);
+}
@@ -0,0 +1,73 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r"RecursiveTypes DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Node ::= SEQUENCE {
+        value INTEGER (0..255),
+        children SEQUENCE OF Node
+    }
+
+    List ::= SEQUENCE {
+        value INTEGER (0..255),
+        next List OPTIONAL
+    }
+
+    Expression ::= CHOICE {
+        literal INTEGER (0..255),
+        negated Expression,
+        sum Sum
+    }
+
+    Sum ::= SEQUENCE {
+        left Expression,
+        right Expression
+    }
+
+    END"
+);
+
+#[test]
+pub fn test_sequence_of_self_is_not_boxed() {
+    serialize_and_deserialize_uper(
+        32,
+        &[0x01, 0x01, 0x02, 0x00],
+        &Node {
+            value: 1,
+            children: vec![Node {
+                value: 2,
+                children: Vec::default(),
+            }],
+        },
+    );
+}
+
+#[test]
+pub fn test_optional_self_is_boxed() {
+    serialize_and_deserialize_uper(
+        18,
+        &[0x80, 0x80, 0x80],
+        &List {
+            value: 1,
+            next: Some(Box::new(List {
+                value: 2,
+                next: None,
+            })),
+        },
+    );
+}
+
+#[test]
+pub fn test_mutually_recursive_choice_is_boxed() {
+    serialize_and_deserialize_uper(
+        24,
+        &[0x80, 0x14, 0x02],
+        &Expression::Sum(Sum {
+            left: Box::new(Expression::Literal(1)),
+            right: Box::new(Expression::Negated(Box::new(Expression::Literal(2)))),
+        }),
+    );
+}
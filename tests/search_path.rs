@@ -0,0 +1,143 @@
+use asn1rs::converter::{Converter, Error};
+use std::path::Path;
+
+mod test_utils;
+use test_utils::*;
+
+const ROOT: &str = r"Root DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    IMPORTS Station FROM Container { 0 4 2 };
+    Message ::= SEQUENCE {
+        station Station
+    }
+END";
+
+fn container(version: u64) -> String {
+    format!(
+        r"Container {{ itu-t(0) identified-organization(4) {} }} DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+            Station ::= INTEGER (0..{})
+        END",
+        version,
+        version * 100,
+    )
+}
+
+/// Creates a fresh directory with the given files
+fn convert(directory: &Path) -> Result<Vec<String>, Error> {
+    let mut converter = Converter::default();
+    converter.add_search_path(directory);
+    converter.load_file_with_imports(directory.join("root.asn1"))?;
+    let mut files = converter
+        .to_rust(directory, |_| {})?
+        .into_values()
+        .flatten()
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+#[test]
+fn test_import_found_by_oid() {
+    let v1 = container(1);
+    let v2 = container(2);
+    let directory = directory(
+        "by-oid",
+        &[("root.asn1", ROOT), ("v1.asn", &v1), ("v2.asn", &v2)],
+    );
+    assert_eq!(
+        vec!["container.rs".to_string(), "root.rs".to_string()],
+        convert(&directory).unwrap()
+    );
+    let container = std::fs::read_to_string(directory.join("container.rs")).unwrap();
+    assert!(container.contains("integer(0..200)"), "{}", container);
+}
+
+#[test]
+fn test_unrelated_broken_file_is_skipped() {
+    let v2 = container(2);
+    let directory = directory(
+        "broken",
+        &[
+            ("root.asn1", ROOT),
+            ("v2.asn", &v2),
+            ("broken.asn", "Broken DEFINITIONS ::= BEGIN Nope ::= END"),
+        ],
+    );
+    assert_eq!(
+        vec!["container.rs".to_string(), "root.rs".to_string()],
+        convert(&directory).unwrap()
+    );
+
+    let mut converter = Converter::default();
+    converter.add_search_path(&directory);
+    converter
+        .load_file_with_imports(directory.join("root.asn1"))
+        .unwrap();
+    let skipped = converter.skipped_files();
+    assert_eq!(1, skipped.len());
+    assert_eq!(directory.join("broken.asn"), skipped[0].0);
+    assert!(!skipped[0].1.is_empty());
+}
+
+#[test]
+fn test_import_not_found() {
+    let directory = directory("not-found", &[("root.asn1", ROOT)]);
+    assert!(matches!(
+        convert(&directory),
+        Err(Error::ModuleNotFound(import)) if import.from == "Container"
+    ));
+}
+
+#[test]
+fn test_import_version_mismatch() {
+    let v1 = container(1);
+    let directory = directory("mismatch", &[("root.asn1", ROOT), ("v1.asn", &v1)]);
+    assert!(matches!(
+        convert(&directory),
+        Err(Error::ModuleVersionMismatch(import, paths))
+            if import.from == "Container" && paths == vec![directory.join("v1.asn")]
+    ));
+}
+
+#[test]
+fn test_import_conflicting_modules() {
+    let v2 = container(2);
+    let directory = directory(
+        "conflict",
+        &[("root.asn1", ROOT), ("a.asn", &v2), ("b.asn", &v2)],
+    );
+    assert!(matches!(
+        convert(&directory),
+        Err(Error::ConflictingModules(import, paths))
+            if import.from == "Container" && paths.len() == 2
+    ));
+}
+
+#[test]
+fn test_resolve_error_shows_the_source_line() {
+    let directory = directory(
+        "resolve-error",
+        &[(
+            "root.asn1",
+            "Root DEFINITIONS AUTOMATIC TAGS ::= BEGIN\n    Speed ::= INTEGR\nEND",
+        )],
+    );
+    let mut converter = Converter::default();
+    converter
+        .load_file(directory.join("root.asn1"))
+        .expect("Failed to load module");
+    let error = converter.to_rust(&directory, |_| {}).unwrap_err();
+    let diagnostics = converter.diagnostics(&error);
+    assert!(
+        diagnostics.ends_with(&format!(
+            "error: Failed to resolve type with name: INTEGR\n \
+             --> {}:2:5\n  \
+             |\n\
+             2 |     Speed ::= INTEGR\n  \
+             |     ^\n  \
+             = help: did you mean `INTEGER`?\n",
+            directory.join("root.asn1").to_string_lossy()
+        )),
+        "{}",
+        diagnostics
+    );
+}
@@ -31,7 +31,7 @@ mod what_is_being_generated {
     use asn1rs::prelude::*;
 
     #[asn(sequence)]
-    #[derive(Default, Debug, Clone, PartialEq, Hash)]
+    #[derive(Debug, Clone, PartialEq, Hash)]
     pub struct Pizza {
         #[asn(integer(min..max))]
         pub price: u64,
@@ -41,6 +41,16 @@ mod what_is_being_generated {
         pub note: Option<String>,
     }
 
+    impl Default for Pizza {
+        fn default() -> Self {
+            Self {
+                price: 0,
+                size: 1,
+                note: None,
+            }
+        }
+    }
+
     #[asn(enumerated)]
     #[derive(Debug, Clone, PartialEq, Hash, Copy, PartialOrd, Eq)]
     pub enum Topping {
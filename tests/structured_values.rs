@@ -0,0 +1,91 @@
+mod test_utils;
+
+use test_utils::*;
+
+asn_to_rust!(
+    r#"StructuredValues DEFINITIONS AUTOMATIC TAGS ::=
+    BEGIN
+
+    Position ::= SEQUENCE {
+        lat INTEGER (-90..90),
+        lon INTEGER (-180..180),
+        label UTF8String OPTIONAL
+    }
+
+    Shape ::= CHOICE {
+        alt INTEGER (0..255),
+        name UTF8String
+    }
+
+    Track ::= SEQUENCE {
+        id INTEGER (0..255),
+        start Position DEFAULT { lat 1, lon 2 }
+    }
+
+    origin Position ::= { lat 0, lon 0 }
+
+    labeled Position ::= { lat 10, lon -20, label "home" }
+
+    altitude Shape ::= alt : 5
+
+    named Shape ::= name : "circle"
+
+    trackOid OBJECT IDENTIFIER ::= { iso identified-organization(3) 4 }
+
+    subTrackOid OBJECT IDENTIFIER ::= { trackOid 7 }
+
+    END"#
+);
+
+#[test]
+pub fn test_sequence_value() {
+    const ORIGIN_COPY: Position = ORIGIN;
+    assert_eq!(
+        Position {
+            lat: 0,
+            lon: 0,
+            label: None,
+        },
+        ORIGIN_COPY
+    );
+    assert_eq!(
+        Position {
+            lat: 10,
+            lon: -20,
+            label: Some("home".to_string()),
+        },
+        *LABELED
+    );
+}
+
+#[test]
+pub fn test_choice_value() {
+    assert_eq!(Shape::Alt(5), ALTITUDE);
+    assert_eq!(Shape::Name("circle".to_string()), *NAMED);
+}
+
+#[test]
+pub fn test_object_identifier_value() {
+    assert_eq!(&[1, 3, 4], TRACK_OID);
+    assert_eq!(&[1, 3, 4, 7], SUB_TRACK_OID);
+}
+
+#[test]
+pub fn test_sequence_default_value() {
+    assert_eq!(
+        Position {
+            lat: 1,
+            lon: 2,
+            label: None,
+        },
+        TRACK_START_DEFAULT
+    );
+    serialize_and_deserialize_uper(
+        9,
+        &[0x02, 0x80],
+        &Track {
+            id: 5,
+            start: TRACK_START_DEFAULT,
+        },
+    );
+}
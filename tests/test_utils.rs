@@ -1,6 +1,63 @@
 #![allow(unused)]
 
+use asn1rs::gen::rust::{GeneratorSupplement, RustCodeGenerator};
+use asn1rs::model::{Model, Rust};
+use asn1rs::parser::Tokenizer;
 pub use asn1rs::prelude::*;
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+
+/// Creates an empty temporary directory for the current test process and writes the given files
+pub fn directory(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let directory =
+        std::env::temp_dir().join(format!("asn1rs-test-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&directory);
+    std::fs::create_dir_all(&directory).unwrap();
+    for (file, content) in files {
+        std::fs::write(directory.join(file), content).unwrap();
+    }
+    directory
+}
+
+/// Asserts that the given file below `tests/` contains the code that is generated with the given
+/// global derives and only the given supplement for the given ASN.1 module. The file is
+/// overwritten instead, if the environment variable `ASN1RS_UPDATE_GENERATED` is set.
+pub fn assert_generated(
+    asn: &str,
+    derives: &[&str],
+    supplement: &dyn GeneratorSupplement<Rust>,
+    file: &str,
+) {
+    let model = Model::try_from(Tokenizer.parse(asn))
+        .unwrap()
+        .try_resolve()
+        .unwrap()
+        .to_rust();
+    let mut generator = RustCodeGenerator::from(model).without_additional_global_derives();
+    for derive in derives {
+        generator.add_global_derive(*derive);
+    }
+    // without the supplements of the enabled features, such as psql
+    let (_file_name, generated) = generator
+        .to_string_with_generators(&[supplement])
+        .into_iter()
+        .next()
+        .unwrap();
+
+    let file = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(file);
+    if std::env::var_os("ASN1RS_UPDATE_GENERATED").is_some() {
+        std::fs::write(&file, generated).unwrap();
+    } else {
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            generated,
+            "{} is outdated, regenerate it with ASN1RS_UPDATE_GENERATED=1",
+            file.display()
+        );
+    }
+}
 
 pub fn serialize_uper(to_uper: &impl Writable) -> (usize, Vec<u8>) {
     let mut writer = UperWriter::default();
@@ -0,0 +1,111 @@
+use asn1rs::converter::{Converter, Pass};
+use asn1rs::model::rust::visit::{self as rust_visit, VisitMut as RustVisitMut};
+use asn1rs::model::visit::{self, VisitMut};
+use asn1rs::model::{Asn, Charset, Definition, Model, RustType, Type};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+mod test_utils;
+use test_utils::*;
+
+const MODULE: &str = r"Passes DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    VendorData ::= SEQUENCE { raw OCTET STRING }
+    Message ::= SEQUENCE {
+        text OCTET STRING,
+        amount INTEGER (0..255)
+    }
+END";
+
+/// Drops all `Vendor*` definitions
+struct DropVendor;
+
+impl VisitMut for DropVendor {
+    fn visit_model_mut(&mut self, model: &mut Model<Asn>) {
+        model
+            .definitions
+            .retain(|Definition(name, _)| !name.starts_with("Vendor"));
+        visit::visit_model_mut(self, model)
+    }
+}
+
+/// Renames all definitions to `Msg*`
+struct Rename;
+
+impl VisitMut for Rename {
+    fn visit_definition_mut(&mut self, definition: &mut Definition<Asn>) {
+        definition.0 = definition.0.replace("Message", "Msg");
+        visit::visit_definition_mut(self, definition)
+    }
+}
+
+/// Replaces all `Vec<u8>` with `String`s
+struct BytesToString;
+
+impl RustVisitMut for BytesToString {
+    fn visit_rust_type_mut(&mut self, r#type: &mut RustType) {
+        if let RustType::VecU8(size) = r#type {
+            *r#type = RustType::String(size.clone(), Charset::Utf8, None);
+        }
+        rust_visit::visit_rust_type_mut(self, r#type)
+    }
+}
+
+/// Counts how often the passes have been applied
+struct Count(Arc<AtomicUsize>);
+
+impl VisitMut for Count {
+    fn visit_type_mut(&mut self, r#type: &mut Type) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        visit::visit_type_mut(self, r#type)
+    }
+}
+
+#[test]
+fn test_passes_run_before_generating_rust() {
+    let directory = directory("passes-rust", &[("passes.asn1", MODULE)]);
+    let mut converter = Converter::default();
+    converter.load_file(directory.join("passes.asn1")).unwrap();
+    converter.add_pass(Pass::asn(DropVendor));
+    converter.add_pass(Pass::asn(Rename));
+    converter.add_pass(Pass::rust(BytesToString));
+
+    let files = converter.to_rust(&directory, |_| {}).unwrap();
+    let code = std::fs::read_to_string(directory.join(&files["Passes"][0])).unwrap();
+
+    assert!(!code.contains("VendorData"));
+    assert!(code.contains("pub struct Msg {"));
+    assert!(code.contains("pub text: String,"));
+    assert!(code.contains("pub amount: u8,"));
+
+    let _ = std::fs::remove_dir_all(&directory);
+}
+
+#[test]
+fn test_passes_run_for_every_conversion() {
+    let directory = directory("passes-count", &[("passes.asn1", MODULE)]);
+    let count = Arc::new(AtomicUsize::new(0));
+    let mut converter = Converter::default();
+    converter.load_file(directory.join("passes.asn1")).unwrap();
+    converter.add_pass(Pass::asn(Count(count.clone())));
+
+    converter.to_json(&directory).unwrap();
+    let once = count.load(Ordering::Relaxed);
+    assert!(once > 0);
+
+    converter.to_rust(&directory, |_| {}).unwrap();
+    assert_eq!(2 * once, count.load(Ordering::Relaxed));
+
+    // formatting prints the modules as they are written
+    converter.to_asn(&directory).unwrap();
+    assert_eq!(2 * once, count.load(Ordering::Relaxed));
+
+    let _ = std::fs::remove_dir_all(&directory);
+}
+
+#[test]
+fn test_converter_with_passes_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    let mut converter = Converter::default();
+    converter.add_pass(Pass::asn(Rename));
+    assert_send_sync(&converter);
+}
@@ -0,0 +1,162 @@
+use asn1rs::prelude::*;
+use asn1rs::syn::mapped::Conversion;
+use std::convert::TryFrom;
+
+mod test_utils;
+use test_utils::*;
+
+#[asn(transparent)]
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct TimestampIts(#[asn(integer(0..4398046511103))] u64);
+
+/// Milliseconds since 2004-01-01, like `TimestampIts`
+#[derive(Debug, Default, Clone, Copy, PartialOrd, PartialEq)]
+pub struct Time(std::time::Duration);
+
+impl From<TimestampIts> for Time {
+    fn from(timestamp: TimestampIts) -> Self {
+        Time(std::time::Duration::from_millis(timestamp.0))
+    }
+}
+
+impl From<&Time> for TimestampIts {
+    fn from(time: &Time) -> Self {
+        TimestampIts(time.0.as_millis() as u64)
+    }
+}
+
+/// In degrees, encoded in 1/10 micro degrees
+#[derive(Debug, Default, Clone, Copy, PartialOrd, PartialEq)]
+pub struct Latitude(f64);
+
+impl From<i32> for Latitude {
+    fn from(value: i32) -> Self {
+        Latitude(f64::from(value) / 10_000_000.0)
+    }
+}
+
+impl From<&Latitude> for i32 {
+    fn from(latitude: &Latitude) -> Self {
+        (latitude.0 * 10_000_000.0).round() as i32
+    }
+}
+
+/// Neither `[u8; 6]` nor `Vec<u8>` are defined in this crate
+pub struct MacAddress;
+
+impl Conversion<Vec<u8>, [u8; 6]> for MacAddress {
+    type Error = String;
+
+    fn from_asn(value: Vec<u8>) -> Result<[u8; 6], String> {
+        <[u8; 6]>::try_from(&value[..])
+            .map_err(|_| format!("expected 6 octets for a MAC address, got {}", value.len()))
+    }
+
+    fn to_asn(value: &[u8; 6]) -> Vec<u8> {
+        value.to_vec()
+    }
+}
+
+#[asn(sequence)]
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct Station {
+    #[asn(integer(-900000000..900000001), mapped)]
+    latitude: Latitude,
+    #[asn(octet_string(size(6)), mapped(MacAddress))]
+    mac: [u8; 6],
+    #[asn(optional(complex(TimestampIts, tag(UNIVERSAL(2)))), mapped)]
+    seen: Option<Time>,
+    #[asn(sequence_of(complex(TimestampIts, tag(UNIVERSAL(2)))), mapped)]
+    history: Vec<Time>,
+}
+
+#[asn(sequence)]
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct PlainStation {
+    #[asn(integer(-900000000..900000001))]
+    latitude: i32,
+    #[asn(octet_string(size(6)))]
+    mac: Vec<u8>,
+    #[asn(optional(complex(TimestampIts, tag(UNIVERSAL(2)))))]
+    seen: Option<TimestampIts>,
+    #[asn(sequence_of(complex(TimestampIts, tag(UNIVERSAL(2)))))]
+    history: Vec<TimestampIts>,
+}
+
+#[asn(choice)]
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+pub enum Reading {
+    #[asn(integer(-900000000..900000001), mapped)]
+    Latitude(Latitude),
+    #[asn(complex(TimestampIts, tag(UNIVERSAL(2))), mapped)]
+    Time(Time),
+}
+
+#[test]
+fn test_overridden_types_are_converted() {
+    let station = Station {
+        latitude: Latitude(48.1234567),
+        mac: [0x00, 0x1b, 0x44, 0x11, 0x3a, 0xb7],
+        seen: Some(Time(std::time::Duration::from_millis(123_456))),
+        history: vec![Time(std::time::Duration::from_millis(42))],
+    };
+    let (bits, bytes) = serialize_uper(&station);
+    assert_eq!(station, deserialize_uper::<Station>(&bytes, bits));
+}
+
+#[test]
+fn test_encoding_is_unchanged() {
+    let station = Station {
+        latitude: Latitude(-12.5),
+        mac: [1, 2, 3, 4, 5, 6],
+        seen: None,
+        history: vec![
+            Time(std::time::Duration::from_millis(1)),
+            Time(std::time::Duration::from_millis(2)),
+        ],
+    };
+    let plain = PlainStation {
+        latitude: -125_000_000,
+        mac: vec![1, 2, 3, 4, 5, 6],
+        seen: None,
+        history: vec![TimestampIts(1), TimestampIts(2)],
+    };
+    assert_eq!(serialize_uper(&plain), serialize_uper(&station));
+    let (bits, bytes) = serialize_uper(&plain);
+    assert_eq!(station, deserialize_uper::<Station>(&bytes, bits));
+}
+
+#[test]
+fn test_overridden_choice_variants() {
+    let reading = Reading::Time(Time(std::time::Duration::from_millis(7)));
+    let (bits, bytes) = serialize_uper(&reading);
+    assert_eq!(reading, deserialize_uper::<Reading>(&bytes, bits));
+}
+
+#[asn(sequence)]
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct Device {
+    #[asn(octet_string, mapped(MacAddress))]
+    mac: [u8; 6],
+}
+
+#[asn(sequence)]
+#[derive(Debug, Default, Clone, PartialOrd, PartialEq)]
+pub struct PlainDevice {
+    #[asn(octet_string)]
+    mac: Vec<u8>,
+}
+
+#[test]
+fn test_failed_conversion_is_a_read_error() {
+    let (bits, bytes) = serialize_uper(&PlainDevice {
+        mac: vec![1, 2, 3, 4, 5],
+    });
+    let mut reader = UperReader::from((&bytes[..], bits));
+    assert_eq!(
+        &asn1rs::io::per::ErrorKind::ConversionFailed(
+            "expected 6 octets for a MAC address, got 5".to_string()
+        ),
+        reader.read::<Device>().unwrap_err().kind()
+    );
+}